@@ -1,19 +1,70 @@
 pub use squads_mpl::errors;
+pub use squads_mpl::events;
 pub use squads_mpl::ID;
 pub use squads_mpl::program::SquadsMpl;
 
 
 pub mod state {
     pub use squads_mpl::state::{
-       IncomingInstruction, MsTransaction, MsAccountMeta, Ms, MsTransactionStatus 
+       IncomingInstruction, MsTransaction, MsAccountMeta, Ms, MsTransactionStatus, Vote, VoteChange, MemberWeight, MemberPermissions, MsMetadata, RecoveryConfig, RecoveryProposal, Authority, AuthorityPolicy, ProgramPolicy, ProgramPolicyMode, SpendingLimit, MsInstructionBatch,
+        MsLookupInstruction, MsLookupAccountMeta, IncomingLookupInstruction, ExecutionAccounts, ExecutionAccountsPage, MsInstructionBuffer
     };
 }
 
+/// Off-chain helpers for the instructions that return data, which are simulated
+/// against an RPC node rather than sent.
+pub mod client {
+    use anchor_lang::prelude::{AccountMeta, Pubkey};
+    use anchor_lang::solana_program::instruction::Instruction;
+    use anchor_lang::{AnchorDeserialize, InstructionData, ToAccountMetas};
+
+    /// Builds the get_execution_accounts instruction to simulate. The attached instruction
+    /// accounts of the transaction (and any lookup tables they use) are the remaining accounts.
+    pub fn get_execution_accounts(
+        multisig: Pubkey,
+        transaction: Pubkey,
+        remaining_accounts: Vec<AccountMeta>,
+        end_index: Option<u8>,
+        offset: u16,
+        limit: Option<u16>,
+    ) -> Instruction {
+        let mut accounts = squads_mpl::accounts::GetExecutionAccounts {
+            multisig,
+            transaction,
+        }
+        .to_account_metas(None);
+        accounts.extend(remaining_accounts);
+        Instruction {
+            program_id: squads_mpl::ID,
+            accounts,
+            data: squads_mpl::instruction::GetExecutionAccounts {
+                end_index,
+                offset,
+                limit,
+            }
+            .data(),
+        }
+    }
+
+    /// Decodes the page of execution accounts from the (base64 decoded) return data
+    /// of a simulated get_execution_accounts instruction.
+    pub fn decode_execution_accounts(
+        return_data: &[u8],
+    ) -> std::io::Result<crate::state::ExecutionAccountsPage> {
+        crate::state::ExecutionAccountsPage::try_from_slice(return_data)
+    }
+}
+
 pub mod cpi {
     use anchor_lang::prelude::{CpiContext, Result, Pubkey};
 
     pub use squads_mpl::cpi::accounts::{
-        ActivateTransaction, VoteTransaction, AddInstruction,CancelTransaction, Create, CreateTransaction, ExecuteInstruction, MsAuth, MsAuthRealloc, ExecuteTransaction
+        ActivateTransaction, VoteTransaction, AddInstruction,CancelTransaction, Create, CreateTransaction, ExecuteInstruction, MsAuth, MsAuthRealloc, ExecuteTransaction, MigrateMultisig, ExpireTransaction, CloseTransaction, WithdrawTransaction,
+        RegisterAuthority, UpdateAuthority, AddAuthorityPolicy, ChangeAuthorityPolicy, RemoveAuthorityPolicy, AddProgramPolicy, ChangeProgramPolicy, RemoveProgramPolicy, InitMetadata, UpdateMetadata, RotateMemberKey,
+        ProposeRecovery, ApproveRecovery, CancelRecovery, ExecuteRecovery,
+        AddSpendingLimit, RemoveSpendingLimit, UseSpendingLimit, UseTokenSpendingLimit,
+        AddInstructions, AddLookupInstruction, ReplaceInstruction, RemoveInstruction, GetExecutionAccounts,
+        CreateInstructionBuffer, AppendInstructionData, FinalizeInstruction, CloseInstructionBuffer
     };
     
     pub fn create_multisig<'info>(
@@ -33,18 +84,52 @@ pub mod cpi {
         squads_mpl::cpi::create_transaction(ctx, authority_index)
     }
 
+    pub fn create_transaction_with_options<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, CreateTransaction<'info>>,
+        authority_index: u32,
+        expires_at: Option<i64>,
+        ephemeral_signers: Option<u8>,
+    ) -> Result<()> {
+        squads_mpl::cpi::create_transaction_with_options(ctx, authority_index, expires_at, ephemeral_signers)
+    }
+
     pub fn activate_transaction<'info>(
         ctx: CpiContext<'_, '_, '_, 'info, ActivateTransaction<'info>>,
     ) -> Result<()> {
         squads_mpl::cpi::activate_transaction(ctx)
     }
 
+    pub fn abstain_transaction<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, VoteTransaction<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::abstain_transaction(ctx)
+    }
+
     pub fn cancel_transaction<'info>(
         ctx: CpiContext<'_, '_, '_, 'info, CancelTransaction<'info>>,
     ) -> Result<()> {
         squads_mpl::cpi::cancel_transaction(ctx)
     }
 
+    pub fn expire_transaction<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, ExpireTransaction<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::expire_transaction(ctx)
+    }
+
+    pub fn close_transaction<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, CloseTransaction<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::close_transaction(ctx)
+    }
+
+    pub fn withdraw_transaction<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, WithdrawTransaction<'info>>,
+        close: bool,
+    ) -> Result<()> {
+        squads_mpl::cpi::withdraw_transaction(ctx, close)
+    }
+
     pub fn execute_instruction<'info>(
         ctx: CpiContext<'_, '_, '_, 'info, ExecuteInstruction<'info>>,
     ) -> Result<()> {
@@ -58,6 +143,61 @@ pub mod cpi {
         squads_mpl::cpi::add_instruction(ctx, incoming_instruction)
     }
 
+    pub fn add_instructions<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, AddInstructions<'info>>,
+        incoming_instructions: Vec<crate::state::IncomingInstruction>,
+    ) -> Result<()> {
+        squads_mpl::cpi::add_instructions(ctx, incoming_instructions)
+    }
+
+    pub fn create_instruction_buffer<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, CreateInstructionBuffer<'info>>,
+        instruction_program_id: Pubkey,
+        keys: Vec<crate::state::MsAccountMeta>,
+        data_len: u32,
+    ) -> Result<()> {
+        squads_mpl::cpi::create_instruction_buffer(ctx, instruction_program_id, keys, data_len)
+    }
+
+    pub fn append_instruction_data<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, AppendInstructionData<'info>>,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        squads_mpl::cpi::append_instruction_data(ctx, data)
+    }
+
+    pub fn finalize_instruction<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, FinalizeInstruction<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::finalize_instruction(ctx)
+    }
+
+    pub fn close_instruction_buffer<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, CloseInstructionBuffer<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::close_instruction_buffer(ctx)
+    }
+
+    pub fn replace_instruction<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, ReplaceInstruction<'info>>,
+        incoming_instruction: crate::state::IncomingInstruction,
+    ) -> Result<()> {
+        squads_mpl::cpi::replace_instruction(ctx, incoming_instruction)
+    }
+
+    pub fn remove_instruction<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, RemoveInstruction<'info>>,
+        instruction_index: u8,
+    ) -> Result<()> {
+        squads_mpl::cpi::remove_instruction(ctx, instruction_index)
+    }
+
+    pub fn add_lookup_instruction<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, AddLookupInstruction<'info>>,
+        incoming_instruction: crate::state::IncomingLookupInstruction,
+    ) -> Result<()> {
+        squads_mpl::cpi::add_lookup_instruction(ctx, incoming_instruction)
+    }
 
     pub fn approve_transaction<'info>(
         ctx: CpiContext<'_, '_, '_, 'info, VoteTransaction<'info>>,
@@ -77,6 +217,77 @@ pub mod cpi {
         squads_mpl::cpi::add_authority(ctx)
     }
 
+    pub fn register_authority<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, RegisterAuthority<'info>>,
+        authority_index: u32,
+        label: String,
+    ) -> Result<()> {
+        squads_mpl::cpi::register_authority(ctx, authority_index, label)
+    }
+
+    pub fn update_authority<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, UpdateAuthority<'info>>,
+        label: String,
+        enabled: bool,
+    ) -> Result<()> {
+        squads_mpl::cpi::update_authority(ctx, label, enabled)
+    }
+
+    pub fn set_recovery_config<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MsAuthRealloc<'info>>,
+        guardians: Vec<Pubkey>,
+        threshold: u16,
+        delay: u32,
+    ) -> Result<()> {
+        squads_mpl::cpi::set_recovery_config(ctx, guardians, threshold, delay)
+    }
+
+    pub fn remove_recovery_config<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MsAuth<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::remove_recovery_config(ctx)
+    }
+
+    pub fn propose_recovery<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, ProposeRecovery<'info>>,
+        new_members: Vec<Pubkey>,
+        new_threshold: u16,
+    ) -> Result<()> {
+        squads_mpl::cpi::propose_recovery(ctx, new_members, new_threshold)
+    }
+
+    pub fn approve_recovery<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, ApproveRecovery<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::approve_recovery(ctx)
+    }
+
+    pub fn cancel_recovery<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, CancelRecovery<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::cancel_recovery(ctx)
+    }
+
+    pub fn execute_recovery<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, ExecuteRecovery<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::execute_recovery(ctx)
+    }
+
+    pub fn init_metadata<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, InitMetadata<'info>>,
+        meta: String,
+    ) -> Result<()> {
+        squads_mpl::cpi::init_metadata(ctx, meta)
+    }
+
+    pub fn update_metadata<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, UpdateMetadata<'info>>,
+        meta: String,
+    ) -> Result<()> {
+        squads_mpl::cpi::update_metadata(ctx, meta)
+    }
+
     pub fn add_member<'info>(
         ctx: CpiContext<'_, '_, '_, 'info, MsAuthRealloc<'info>>,
         new_member: Pubkey,
@@ -84,6 +295,43 @@ pub mod cpi {
         squads_mpl::cpi::add_member(ctx, new_member)
     }
 
+    pub fn add_member_with_weight<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MsAuthRealloc<'info>>,
+        new_member: Pubkey,
+        weight: u16,
+    ) -> Result<()> {
+        squads_mpl::cpi::add_member_with_weight(ctx, new_member, weight)
+    }
+
+    pub fn change_member_weight<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MsAuthRealloc<'info>>,
+        member: Pubkey,
+        weight: u16,
+    ) -> Result<()> {
+        squads_mpl::cpi::change_member_weight(ctx, member, weight)
+    }
+
+    pub fn change_member_permissions<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MsAuthRealloc<'info>>,
+        member: Pubkey,
+        permissions: u8,
+    ) -> Result<()> {
+        squads_mpl::cpi::change_member_permissions(ctx, member, permissions)
+    }
+
+    pub fn rotate_member_key<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, RotateMemberKey<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::rotate_member_key(ctx)
+    }
+
+    pub fn change_reject_threshold<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MsAuth<'info>>,
+        reject_threshold: u16,
+    ) -> Result<()> {
+        squads_mpl::cpi::change_reject_threshold(ctx, reject_threshold)
+    }
+
     pub fn add_member_and_change_threshold<'info>(
         ctx: CpiContext<'_, '_, '_, 'info, MsAuthRealloc<'info>>,
         new_member: Pubkey,
@@ -99,6 +347,101 @@ pub mod cpi {
         squads_mpl::cpi::change_threshold(ctx, new_threshold)
     }
 
+    pub fn add_authority_policy<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, AddAuthorityPolicy<'info>>,
+        authority_index: u32,
+        threshold: u16,
+    ) -> Result<()> {
+        squads_mpl::cpi::add_authority_policy(ctx, authority_index, threshold)
+    }
+
+    pub fn change_authority_policy<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, ChangeAuthorityPolicy<'info>>,
+        threshold: u16,
+    ) -> Result<()> {
+        squads_mpl::cpi::change_authority_policy(ctx, threshold)
+    }
+
+    pub fn remove_authority_policy<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, RemoveAuthorityPolicy<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::remove_authority_policy(ctx)
+    }
+
+    pub fn add_program_policy<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, AddProgramPolicy<'info>>,
+        authority_index: u32,
+        mode: crate::state::ProgramPolicyMode,
+        programs: Vec<Pubkey>,
+    ) -> Result<()> {
+        squads_mpl::cpi::add_program_policy(ctx, authority_index, mode, programs)
+    }
+
+    pub fn change_program_policy<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, ChangeProgramPolicy<'info>>,
+        mode: crate::state::ProgramPolicyMode,
+        programs: Vec<Pubkey>,
+    ) -> Result<()> {
+        squads_mpl::cpi::change_program_policy(ctx, mode, programs)
+    }
+
+    pub fn remove_program_policy<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, RemoveProgramPolicy<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::remove_program_policy(ctx)
+    }
+
+    pub fn add_spending_limit<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, AddSpendingLimit<'info>>,
+        authority_index: u32,
+        mint: Pubkey,
+        amount: u64,
+        period: i64,
+        members: Vec<Pubkey>,
+    ) -> Result<()> {
+        squads_mpl::cpi::add_spending_limit(ctx, authority_index, mint, amount, period, members)
+    }
+
+    pub fn remove_spending_limit<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, RemoveSpendingLimit<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::remove_spending_limit(ctx)
+    }
+
+    pub fn use_spending_limit<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, UseSpendingLimit<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        squads_mpl::cpi::use_spending_limit(ctx, amount)
+    }
+
+    pub fn use_token_spending_limit<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, UseTokenSpendingLimit<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        squads_mpl::cpi::use_token_spending_limit(ctx, amount)
+    }
+
+    pub fn change_time_lock<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MsAuth<'info>>,
+        time_lock: u32,
+    ) -> Result<()> {
+        squads_mpl::cpi::change_time_lock(ctx, time_lock)
+    }
+
+    pub fn change_default_ttl<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MsAuth<'info>>,
+        default_ttl: u32,
+    ) -> Result<()> {
+        squads_mpl::cpi::change_default_ttl(ctx, default_ttl)
+    }
+
+    pub fn migrate_multisig<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, MigrateMultisig<'info>>,
+    ) -> Result<()> {
+        squads_mpl::cpi::migrate_multisig(ctx)
+    }
+
     pub fn execute_transaction<'info>(
         ctx: CpiContext<'_, '_, '_, 'info, ExecuteTransaction<'info>>,
         account_list: Vec<u8>,
@@ -106,6 +449,25 @@ pub mod cpi {
         squads_mpl::cpi::execute_transaction(ctx, account_list)
     }
 
+    pub fn execute_transaction_chunk<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, ExecuteTransaction<'info>>,
+        account_list: Vec<u8>,
+        end_index: u8,
+    ) -> Result<()> {
+        squads_mpl::cpi::execute_transaction_chunk(ctx, account_list, end_index)
+    }
+
+    /// Gets the execution accounts from another program through CPI. Off-chain, build the
+    /// instruction with `client::get_execution_accounts` and simulate it instead.
+    pub fn get_execution_accounts<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, GetExecutionAccounts<'info>>,
+        end_index: Option<u8>,
+        offset: u16,
+        limit: Option<u16>,
+    ) -> Result<crate::state::ExecutionAccountsPage> {
+        Ok(squads_mpl::cpi::get_execution_accounts(ctx, end_index, offset, limit)?.get())
+    }
+
     pub fn remove_member<'info>(
         ctx: CpiContext<'_, '_, '_, 'info, MsAuth<'info>>,
         member: Pubkey,
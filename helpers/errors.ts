@@ -0,0 +1,13 @@
+import { expect } from "chai";
+
+// expects the promise to fail with the given program error code
+export const expectProgramError = async (promise: Promise<any>, code: string) => {
+  try {
+    await promise;
+  } catch (e) {
+    const logs = (e.logs ?? []).join("\n");
+    expect(`${e.message}\n${logs}`).to.contain(code);
+    return;
+  }
+  expect.fail(`expected the transaction to fail with ${code}`);
+};
@@ -1,5 +1,5 @@
 export type SquadsMpl = {
-  "version": "1.3.0",
+  "version": "1.3.1",
   "name": "squads_mpl",
   "instructions": [
     {
@@ -13,6 +13,11 @@ export type SquadsMpl = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "metadata",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "creator",
           "isMut": true,
@@ -77,10 +82,117 @@ export type SquadsMpl = {
         }
       ]
     },
+    {
+      "name": "addMemberWithWeight",
+      "docs": [
+        "The instruction to add a new member with a voting weight other than the default of 1.",
+        "If the member is already in the multisig, only their weight is changed."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "newMember",
+          "type": "publicKey"
+        },
+        {
+          "name": "weight",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "changeMemberWeight",
+      "docs": [
+        "The instruction to change the voting weight of a member.",
+        "Approvals, rejections and cancellations are counted by the combined weight",
+        "of the members, and the threshold is lowered if it can no longer be reached."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "member",
+          "type": "publicKey"
+        },
+        {
+          "name": "weight",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "changeMemberPermissions",
+      "docs": [
+        "The instruction to change the permissions of a member (Initiate, Vote, Execute).",
+        "Only members with the vote permission count towards the threshold, and the",
+        "threshold is lowered if it can no longer be reached."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "member",
+          "type": "publicKey"
+        },
+        {
+          "name": "permissions",
+          "type": "u8"
+        }
+      ]
+    },
     {
       "name": "removeMember",
       "docs": [
-        "The instruction to remove a member from the multisig"
+        "The instruction to remove a member from the multisig.",
+        "All of the AuthorityPolicy accounts of the multisig must be passed as writable",
+        "remaining accounts, in order of their authority index, and policy thresholds",
+        "that can no longer be reached are lowered along with the multisig threshold."
       ],
       "accounts": [
         {
@@ -96,6 +208,35 @@ export type SquadsMpl = {
         }
       ]
     },
+    {
+      "name": "rotateMemberKey",
+      "docs": [
+        "The instruction for a member to rotate their key, signed by both the old and new key.",
+        "The key is replaced in place (keeping its weight and permissions), and unlike",
+        "removing and adding a member, the change index is not updated. Pending transactions",
+        "passed as remaining accounts have the votes (and creator) of the old key moved to the new key,",
+        "and SpendingLimit accounts passed along with them have the old key replaced in their members.",
+        "The creator of a TransactionMeta (txmeta program) isn't changed, its rent still goes to the old key."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "oldMember",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "newMember",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
     {
       "name": "removeMemberAndChangeThreshold",
       "docs": [
@@ -172,13 +313,11 @@ export type SquadsMpl = {
       ]
     },
     {
-      "name": "addAuthority",
+      "name": "changeRejectThreshold",
       "docs": [
-        "instruction to increase the authority value tracked in the multisig",
-        "This is optional, as authorities are simply PDAs, however it may be helpful",
-        "to keep track of commonly used authorities in a UI.",
-        "This has no functional impact on the multisig or its functionality, but",
-        "can be used to track commonly used authorities (ie, vault 1, vault 2, etc.)"
+        "The instruction to change the reject threshold of the multisig, the combined weight",
+        "of rejections that rejects a transaction (ie. a minority veto). A value of 0 disables it,",
+        "so transactions are only rejected once the threshold can no longer be reached."
       ],
       "accounts": [
         {
@@ -187,97 +326,108 @@ export type SquadsMpl = {
           "isSigner": true
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "rejectThreshold",
+          "type": "u16"
+        }
+      ]
     },
     {
-      "name": "createTransaction",
+      "name": "changeTimeLock",
       "docs": [
-        "Instruction to create a multisig transaction.",
-        "Each transaction is tied to a single authority, and must be specified when",
-        "creating the instruction below. authority 0 is reserved for internal",
-        "instructions, whereas authorities 1 or greater refer to a vault,",
-        "upgrade authority, or other."
+        "The instruction to change the time lock of the multisig.",
+        "Transactions that become ExecuteReady will need to wait the time lock",
+        "(in seconds) before they can be executed, giving members a window to cancel."
       ],
       "accounts": [
         {
           "name": "multisig",
           "isMut": true,
-          "isSigner": false
-        },
+          "isSigner": true
+        }
+      ],
+      "args": [
         {
-          "name": "transaction",
-          "isMut": true,
-          "isSigner": false
-        },
+          "name": "timeLock",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "changeDefaultTtl",
+      "docs": [
+        "The instruction to change the default time to live (in seconds) of new transactions.",
+        "A value of 0 means transactions will not expire unless created with an explicit expiry."
+      ],
+      "accounts": [
         {
-          "name": "creator",
+          "name": "multisig",
           "isMut": true,
           "isSigner": true
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "authorityIndex",
+          "name": "defaultTtl",
           "type": "u32"
         }
       ]
     },
     {
-      "name": "activateTransaction",
+      "name": "initMetadata",
       "docs": [
-        "Instruction to set the state of a transaction \"active\".",
-        "\"active\" transactions can then be signed off by multisig members"
+        "The instruction to create the metadata of a multisig created before the MsMetadata",
+        "account existed. The payer funds the metadata account, and needs to sign the execution."
       ],
       "accounts": [
         {
           "name": "multisig",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "transaction",
+          "name": "metadata",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "creator",
+          "name": "payer",
           "isMut": true,
           "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "meta",
+          "type": "string"
+        }
+      ]
     },
     {
-      "name": "addInstruction",
+      "name": "updateMetadata",
       "docs": [
-        "Instruction to attach an instruction to a transaction.",
-        "Transactions must be in the \"draft\" status, and any",
-        "signer (aside from execution payer) specified in an instruction",
-        "must match the authority PDA specified during the transaction creation."
+        "The instruction to update the metadata of the multisig (ie. name and description).",
+        "The metadata account is resized to fit, with the payer covering any additional rent."
       ],
       "accounts": [
         {
           "name": "multisig",
           "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "transaction",
-          "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "instruction",
+          "name": "metadata",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "creator",
+          "name": "payer",
           "isMut": true,
           "isSigner": true
         },
@@ -289,32 +439,60 @@ export type SquadsMpl = {
       ],
       "args": [
         {
-          "name": "incomingInstruction",
-          "type": {
-            "defined": "IncomingInstruction"
-          }
+          "name": "meta",
+          "type": "string"
         }
       ]
     },
     {
-      "name": "approveTransaction",
+      "name": "setRecoveryConfig",
       "docs": [
-        "Instruction to approve a transaction on behalf of a member.",
-        "The transaction must have an \"active\" status"
+        "The instruction to set the recovery config of the multisig. Guardians can propose",
+        "a replacement member set, which takes effect if the guardian threshold approves it",
+        "and no member objects within the delay (at least RecoveryConfig::MIN_DELAY seconds)."
       ],
       "accounts": [
         {
           "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "transaction",
-          "isMut": true,
+          "name": "systemProgram",
+          "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "guardians",
+          "type": {
+            "vec": "publicKey"
+          }
         },
         {
-          "name": "member",
+          "name": "threshold",
+          "type": "u16"
+        },
+        {
+          "name": "delay",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "removeRecoveryConfig",
+      "docs": [
+        "The instruction to remove the recovery config of the multisig."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
           "isMut": true,
           "isSigner": true
         }
@@ -322,24 +500,16 @@ export type SquadsMpl = {
       "args": []
     },
     {
-      "name": "rejectTransaction",
+      "name": "addAuthority",
       "docs": [
-        "Instruction to reject a transaction.",
-        "The transaction must have an \"active\" status."
+        "instruction to increase the authority value tracked in the multisig",
+        "Transactions can only be created for authorities up to the tracked index,",
+        "which can also be used to track commonly used authorities in a UI (ie, vault 1, vault 2, etc.)",
+        "An Authority account describing the new authority can be added with register_authority."
       ],
       "accounts": [
         {
           "name": "multisig",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "transaction",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "member",
           "isMut": true,
           "isSigner": true
         }
@@ -347,27 +517,26 @@ export type SquadsMpl = {
       "args": []
     },
     {
-      "name": "cancelTransaction",
+      "name": "registerAuthority",
       "docs": [
-        "Instruction to cancel a transaction.",
-        "Transactions must be in the \"executeReady\" status.",
-        "Transaction will only be cancelled if the number of",
-        "cancellations reaches the threshold. A cancelled",
-        "transaction will no longer be able to be executed."
+        "The instruction to register an authority (vault) of the multisig, creating its",
+        "Authority account with a label describing what it is used for. The authority",
+        "must already be tracked by the multisig (see add_authority), and is enabled.",
+        "The payer funds the Authority account, and needs to sign the execution."
       ],
       "accounts": [
         {
           "name": "multisig",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "transaction",
+          "name": "authority",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "member",
+          "name": "payer",
           "isMut": true,
           "isSigner": true
         },
@@ -377,401 +546,214 @@ export type SquadsMpl = {
           "isSigner": false
         }
       ],
-      "args": []
-    },
-    {
-      "name": "executeTransaction",
-      "docs": [
-        "Instruction to execute a transaction.",
-        "Transaction status must be \"executeReady\", and the account list must match",
-        "the unique indexed accounts in the following manner:",
-        "[ix_1_account, ix_1_program_account, ix_1_remaining_account_1, ix_1_remaining_account_2, ...]",
-        "",
-        "Refer to the README for more information on how to construct the account list."
+      "args": [
+        {
+          "name": "authorityIndex",
+          "type": "u32"
+        },
+        {
+          "name": "label",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "updateAuthority",
+      "docs": [
+        "The instruction to update the label of an authority, and enable or disable it.",
+        "Transactions can't be created for a disabled authority, existing transactions",
+        "are not affected."
       ],
       "accounts": [
         {
           "name": "multisig",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "transaction",
+          "name": "authority",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "member",
+          "name": "payer",
           "isMut": true,
           "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "accountList",
-          "type": "bytes"
+          "name": "label",
+          "type": "string"
+        },
+        {
+          "name": "enabled",
+          "type": "bool"
         }
       ]
     },
     {
-      "name": "executeInstruction",
+      "name": "addAuthorityPolicy",
       "docs": [
-        "Instruction to sequentially execute attached instructions.",
-        "Instructions executed in this matter must be executed in order,",
-        "this may be helpful for processing large batch transfers.",
-        "This instruction can only be used for transactions with an authority",
-        "index of 1 or greater.",
-        "",
-        "NOTE - do not use this instruction if there is not total clarity around",
-        "potential side effects, as this instruction implies that the approved",
-        "transaction will be executed partially, and potentially spread out over",
-        "a period of time. This could introduce problems with state and failed",
-        "transactions. For example: a program invoked in one of these instructions",
-        "may be upgraded between executions and potentially leave one of the",
-        "necessary accounts in an invalid state."
+        "The instruction to add a policy for an authority (vault), which overrides",
+        "the threshold of the multisig for transactions using that authority.",
+        "The payer funds the AuthorityPolicy account, and needs to sign the execution."
       ],
       "accounts": [
         {
           "name": "multisig",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "transaction",
+          "name": "policy",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "instruction",
+          "name": "payer",
           "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "authorityIndex",
+          "type": "u32"
         },
         {
-          "name": "member",
+          "name": "threshold",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "changeAuthorityPolicy",
+      "docs": [
+        "The instruction to change the threshold of an authority policy"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
           "isMut": true,
           "isSigner": true
+        },
+        {
+          "name": "policy",
+          "isMut": true,
+          "isSigner": false
         }
       ],
-      "args": []
-    }
-  ],
-  "accounts": [
+      "args": [
+        {
+          "name": "threshold",
+          "type": "u16"
+        }
+      ]
+    },
     {
-      "name": "ms",
+      "name": "removeAuthorityPolicy",
       "docs": [
-        "Ms is the basic state account for a multisig."
+        "The instruction to remove an authority policy, the authority will",
+        "use the multisig threshold again."
       ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "threshold",
-            "type": "u16"
-          },
-          {
-            "name": "authorityIndex",
-            "type": "u16"
-          },
-          {
-            "name": "transactionIndex",
-            "type": "u32"
-          },
-          {
-            "name": "msChangeIndex",
-            "type": "u32"
-          },
-          {
-            "name": "bump",
-            "type": "u8"
-          },
-          {
-            "name": "createKey",
-            "type": "publicKey"
-          },
-          {
-            "name": "allowExternalExecute",
-            "type": "bool"
-          },
-          {
-            "name": "keys",
-            "type": {
-              "vec": "publicKey"
-            }
-          }
-        ]
-      }
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "policy",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentCollector",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
     },
     {
-      "name": "msTransaction",
+      "name": "addProgramPolicy",
       "docs": [
-        "The MsTransaction is the state account for a multisig transaction"
+        "The instruction to add a program policy to an authority (vault), which limits the",
+        "programs that instructions of transactions using that authority can invoke, to the",
+        "listed programs (Allow) or to any but the listed programs (Deny).",
+        "The payer funds the ProgramPolicy account, and needs to sign the execution."
       ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "creator",
-            "type": "publicKey"
-          },
-          {
-            "name": "ms",
-            "type": "publicKey"
-          },
-          {
-            "name": "transactionIndex",
-            "type": "u32"
-          },
-          {
-            "name": "authorityIndex",
-            "type": "u32"
-          },
-          {
-            "name": "authorityBump",
-            "type": "u8"
-          },
-          {
-            "name": "status",
-            "type": {
-              "defined": "MsTransactionStatus"
-            }
-          },
-          {
-            "name": "instructionIndex",
-            "type": "u8"
-          },
-          {
-            "name": "bump",
-            "type": "u8"
-          },
-          {
-            "name": "approved",
-            "type": {
-              "vec": "publicKey"
-            }
-          },
-          {
-            "name": "rejected",
-            "type": {
-              "vec": "publicKey"
-            }
-          },
-          {
-            "name": "cancelled",
-            "type": {
-              "vec": "publicKey"
-            }
-          },
-          {
-            "name": "executedIndex",
-            "type": "u8"
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "policy",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "authorityIndex",
+          "type": "u32"
+        },
+        {
+          "name": "mode",
+          "type": {
+            "defined": "ProgramPolicyMode"
           }
-        ]
-      }
+        },
+        {
+          "name": "programs",
+          "type": {
+            "vec": "publicKey"
+          }
+        }
+      ]
     },
     {
-      "name": "msInstruction",
+      "name": "changeProgramPolicy",
       "docs": [
-        "The state account for an instruction that is attached to a transaction.",
-        "Almost analagous to the native Instruction struct for solana, but with an extra",
-        "field for the bump."
-      ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "programId",
-            "type": "publicKey"
-          },
-          {
-            "name": "keys",
-            "type": {
-              "vec": {
-                "defined": "MsAccountMeta"
-              }
-            }
-          },
-          {
-            "name": "data",
-            "type": "bytes"
-          },
-          {
-            "name": "instructionIndex",
-            "type": "u8"
-          },
-          {
-            "name": "bump",
-            "type": "u8"
-          },
-          {
-            "name": "executed",
-            "type": "bool"
-          }
-        ]
-      }
-    }
-  ],
-  "types": [
-    {
-      "name": "MsAccountMeta",
-      "docs": [
-        "Wrapper for our internal MsInstruction key serialization schema",
-        "MsAccount meta is identical to the AccountMeta struct, but defined",
-        "here for serialization purposes."
-      ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "pubkey",
-            "type": "publicKey"
-          },
-          {
-            "name": "isSigner",
-            "type": "bool"
-          },
-          {
-            "name": "isWritable",
-            "type": "bool"
-          }
-        ]
-      }
-    },
-    {
-      "name": "IncomingInstruction",
-      "docs": [
-        "Incoming instruction schema, used as an argument in the attach_instruction.",
-        "Identical to the solana struct for Instruction, but uses the MsAccountMeta.",
-        "Provided for de/serialization purposes."
-      ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "programId",
-            "type": "publicKey"
-          },
-          {
-            "name": "keys",
-            "type": {
-              "vec": {
-                "defined": "MsAccountMeta"
-              }
-            }
-          },
-          {
-            "name": "data",
-            "type": "bytes"
-          }
-        ]
-      }
-    },
-    {
-      "name": "MsTransactionStatus",
-      "docs": [
-        "MsTransactionStatus enum of the current status of the Multisig Transaction."
-      ],
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "Draft"
-          },
-          {
-            "name": "Active"
-          },
-          {
-            "name": "ExecuteReady"
-          },
-          {
-            "name": "Executed"
-          },
-          {
-            "name": "Rejected"
-          },
-          {
-            "name": "Cancelled"
-          }
-        ]
-      }
-    }
-  ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "KeyNotInMultisig"
-    },
-    {
-      "code": 6001,
-      "name": "InvalidTransactionState"
-    },
-    {
-      "code": 6002,
-      "name": "InvalidNumberOfAccounts"
-    },
-    {
-      "code": 6003,
-      "name": "InvalidInstructionAccount"
-    },
-    {
-      "code": 6004,
-      "name": "InvalidAuthorityIndex"
-    },
-    {
-      "code": 6005,
-      "name": "TransactionAlreadyExecuted"
-    },
-    {
-      "code": 6006,
-      "name": "CannotRemoveSoloMember"
-    },
-    {
-      "code": 6007,
-      "name": "InvalidThreshold"
-    },
-    {
-      "code": 6008,
-      "name": "DeprecatedTransaction"
-    },
-    {
-      "code": 6009,
-      "name": "InstructionFailed"
-    },
-    {
-      "code": 6010,
-      "name": "MaxMembersReached"
-    },
-    {
-      "code": 6011,
-      "name": "EmptyMembers"
-    },
-    {
-      "code": 6012,
-      "name": "PartialExecution"
-    },
-    {
-      "code": 6013,
-      "name": "NotEnoughLamports"
-    }
-  ]
-};
-
-export const IDL: SquadsMpl = {
-  "version": "1.3.0",
-  "name": "squads_mpl",
-  "instructions": [
-    {
-      "name": "create",
-      "docs": [
-        "Creates a new multisig account"
+        "The instruction to change the mode and programs of a program policy.",
+        "Attached instructions are checked against the policy again when executed."
       ],
       "accounts": [
         {
           "name": "multisig",
           "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "policy",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "creator",
+          "name": "payer",
           "isMut": true,
           "isSigner": true
         },
@@ -783,32 +765,24 @@ export const IDL: SquadsMpl = {
       ],
       "args": [
         {
-          "name": "threshold",
-          "type": "u16"
-        },
-        {
-          "name": "createKey",
-          "type": "publicKey"
+          "name": "mode",
+          "type": {
+            "defined": "ProgramPolicyMode"
+          }
         },
         {
-          "name": "members",
+          "name": "programs",
           "type": {
             "vec": "publicKey"
           }
-        },
-        {
-          "name": "meta",
-          "type": "string"
         }
       ]
     },
     {
-      "name": "addMember",
+      "name": "removeProgramPolicy",
       "docs": [
-        "The instruction to add a new member to the multisig.",
-        "Adds member/key to the multisig and reallocates space if neccessary",
-        "If the multisig needs to be reallocated, it must be prefunded with",
-        "enough lamports to cover the new size."
+        "The instruction to remove a program policy, the authority can",
+        "invoke any program again."
       ],
       "accounts": [
         {
@@ -817,81 +791,131 @@ export const IDL: SquadsMpl = {
           "isSigner": true
         },
         {
-          "name": "rent",
-          "isMut": false,
+          "name": "policy",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
+          "name": "rentCollector",
+          "isMut": true,
           "isSigner": false
         }
       ],
-      "args": [
-        {
-          "name": "newMember",
-          "type": "publicKey"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "removeMember",
+      "name": "addSpendingLimit",
       "docs": [
-        "The instruction to remove a member from the multisig"
+        "The instruction to add a spending limit to an authority (vault).",
+        "The members of the spending limit can transfer up to the amount of the mint",
+        "(or native SOL if the mint is the default pubkey) from the authority per period,",
+        "without creating a transaction. The payer funds the SpendingLimit account,",
+        "and needs to sign the execution."
       ],
       "accounts": [
         {
           "name": "multisig",
-          "isMut": true,
+          "isMut": false,
           "isSigner": true
-        }
-      ],
-      "args": [
+        },
         {
-          "name": "oldMember",
-          "type": "publicKey"
-        }
-      ]
-    },
-    {
-      "name": "removeMemberAndChangeThreshold",
-      "docs": [
-        "The instruction to change the threshold of the multisig and simultaneously remove a member"
-      ],
-      "accounts": [
+          "name": "spendingLimit",
+          "isMut": true,
+          "isSigner": false
+        },
         {
-          "name": "multisig",
+          "name": "payer",
           "isMut": true,
           "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "oldMember",
+          "name": "authorityIndex",
+          "type": "u32"
+        },
+        {
+          "name": "mint",
           "type": "publicKey"
         },
         {
-          "name": "newThreshold",
-          "type": "u16"
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "period",
+          "type": "i64"
+        },
+        {
+          "name": "members",
+          "type": {
+            "vec": "publicKey"
+          }
         }
       ]
     },
     {
-      "name": "addMemberAndChangeThreshold",
+      "name": "removeSpendingLimit",
       "docs": [
-        "The instruction to change the threshold of the multisig and simultaneously add a member"
+        "The instruction to remove a spending limit from the multisig"
       ],
       "accounts": [
         {
           "name": "multisig",
-          "isMut": true,
+          "isMut": false,
           "isSigner": true
         },
         {
-          "name": "rent",
+          "name": "spendingLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentCollector",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "useSpendingLimit",
+      "docs": [
+        "Instruction to transfer native SOL from an authority (vault) within a spending limit.",
+        "Can be used by any member of the spending limit without creating a transaction.",
+        "The authority can't be disabled."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "spendingLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": true
+        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -900,74 +924,116 @@ export const IDL: SquadsMpl = {
       ],
       "args": [
         {
-          "name": "newMember",
-          "type": "publicKey"
-        },
-        {
-          "name": "newThreshold",
-          "type": "u16"
+          "name": "amount",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "changeThreshold",
+      "name": "useTokenSpendingLimit",
       "docs": [
-        "The instruction to change the threshold of the multisig"
+        "Instruction to transfer tokens from an authority (vault) within a spending limit.",
+        "Can be used by any member of the spending limit without creating a transaction.",
+        "The authority can't be disabled."
       ],
       "accounts": [
         {
           "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "spendingLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authorityTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destinationTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
           "isMut": true,
           "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "newThreshold",
-          "type": "u16"
+          "name": "amount",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "addAuthority",
+      "name": "migrateMultisig",
       "docs": [
-        "instruction to increase the authority value tracked in the multisig",
-        "This is optional, as authorities are simply PDAs, however it may be helpful",
-        "to keep track of commonly used authorities in a UI.",
-        "This has no functional impact on the multisig or its functionality, but",
-        "can be used to track commonly used authorities (ie, vault 1, vault 2, etc.)"
+        "Instruction to migrate a multisig created with the legacy Ms layout.",
+        "Reallocates the account so the trailing settings fields fit, with the",
+        "member covering any additional rent, and clears any stale bytes after",
+        "the keys so the new settings default to zero values. This is a no-op if",
+        "the multisig already uses the current layout. Every other instruction requires",
+        "a migrated multisig, so no settings can be set before the migration."
       ],
       "accounts": [
         {
           "name": "multisig",
           "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
           "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": []
     },
     {
-      "name": "createTransaction",
+      "name": "proposeRecovery",
       "docs": [
-        "Instruction to create a multisig transaction.",
-        "Each transaction is tied to a single authority, and must be specified when",
-        "creating the instruction below. authority 0 is reserved for internal",
-        "instructions, whereas authorities 1 or greater refer to a vault,",
-        "upgrade authority, or other."
+        "Instruction for a guardian to propose a replacement member set and threshold.",
+        "The proposal needs to be approved by the guardian threshold, after which",
+        "the members have the recovery delay to object before it can be executed."
       ],
       "accounts": [
         {
           "name": "multisig",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "transaction",
+          "name": "proposal",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "creator",
+          "name": "guardian",
           "isMut": true,
           "isSigner": true
         },
@@ -979,16 +1045,22 @@ export const IDL: SquadsMpl = {
       ],
       "args": [
         {
-          "name": "authorityIndex",
-          "type": "u32"
+          "name": "newMembers",
+          "type": {
+            "vec": "publicKey"
+          }
+        },
+        {
+          "name": "newThreshold",
+          "type": "u16"
         }
       ]
     },
     {
-      "name": "activateTransaction",
+      "name": "approveRecovery",
       "docs": [
-        "Instruction to set the state of a transaction \"active\".",
-        "\"active\" transactions can then be signed off by multisig members"
+        "Instruction for a guardian to approve a recovery proposal. Once the guardian",
+        "threshold is reached, the recovery delay starts."
       ],
       "accounts": [
         {
@@ -997,25 +1069,22 @@ export const IDL: SquadsMpl = {
           "isSigner": false
         },
         {
-          "name": "transaction",
+          "name": "proposal",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "creator",
-          "isMut": true,
+          "name": "guardian",
+          "isMut": false,
           "isSigner": true
         }
       ],
       "args": []
     },
     {
-      "name": "addInstruction",
+      "name": "cancelRecovery",
       "docs": [
-        "Instruction to attach an instruction to a transaction.",
-        "Transactions must be in the \"draft\" status, and any",
-        "signer (aside from execution payer) specified in an instruction",
-        "must match the authority PDA specified during the transaction creation."
+        "Instruction for a member to object to a recovery proposal, which closes it."
       ],
       "accounts": [
         {
@@ -1024,70 +1093,79 @@ export const IDL: SquadsMpl = {
           "isSigner": false
         },
         {
-          "name": "transaction",
+          "name": "proposal",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "instruction",
+          "name": "proposer",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "creator",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "systemProgram",
+          "name": "member",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         }
       ],
-      "args": [
-        {
-          "name": "incomingInstruction",
-          "type": {
-            "defined": "IncomingInstruction"
-          }
-        }
-      ]
+      "args": []
     },
     {
-      "name": "approveTransaction",
+      "name": "executeRecovery",
       "docs": [
-        "Instruction to approve a transaction on behalf of a member.",
-        "The transaction must have an \"active\" status"
+        "Instruction to execute a recovery proposal once the delay has passed without",
+        "an objection. The members and threshold of the multisig are replaced, resetting",
+        "any weights and permissions, and the change index is updated to deprecate any",
+        "active transactions."
       ],
       "accounts": [
         {
           "name": "multisig",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "transaction",
+          "name": "proposal",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "member",
+          "name": "proposer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
           "isMut": true,
           "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": []
     },
     {
-      "name": "rejectTransaction",
+      "name": "createTransaction",
       "docs": [
-        "Instruction to reject a transaction.",
-        "The transaction must have an \"active\" status."
+        "Instruction to create a multisig transaction.",
+        "Each transaction is tied to a single authority, and must be specified when",
+        "creating the instruction below. authority 0 is reserved for internal",
+        "instructions, whereas authorities 1 or greater refer to a vault,",
+        "upgrade authority, or other. The authority can't be above the authority",
+        "index tracked by the multisig, or disabled."
       ],
       "accounts": [
         {
           "name": "multisig",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false
         },
         {
@@ -1096,21 +1174,33 @@ export const IDL: SquadsMpl = {
           "isSigner": false
         },
         {
-          "name": "member",
+          "name": "creator",
           "isMut": true,
           "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "authorityIndex",
+          "type": "u32"
+        }
+      ]
     },
     {
-      "name": "cancelTransaction",
+      "name": "createTransactionWithOptions",
       "docs": [
-        "Instruction to cancel a transaction.",
-        "Transactions must be in the \"executeReady\" status.",
-        "Transaction will only be cancelled if the number of",
-        "cancellations reaches the threshold. A cancelled",
-        "transaction will no longer be able to be executed."
+        "Instruction to create a multisig transaction with optional settings.",
+        "An explicit expiry must be in the future, and if the multisig has a default ttl",
+        "it can't be later than the default expiry.",
+        "Ephemeral signers are PDAs derived from [b\"squad\", transaction, index, b\"ephemeral_signer\"],",
+        "for index 0 up to the number declared (at most MAX_EPHEMERAL_SIGNERS), and are added as",
+        "signers when the transaction is executed - eg. for creating accounts that need to sign",
+        "for their own creation."
       ],
       "accounts": [
         {
@@ -1124,7 +1214,7 @@ export const IDL: SquadsMpl = {
           "isSigner": false
         },
         {
-          "name": "member",
+          "name": "creator",
           "isMut": true,
           "isSigner": true
         },
@@ -1134,22 +1224,35 @@ export const IDL: SquadsMpl = {
           "isSigner": false
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "authorityIndex",
+          "type": "u32"
+        },
+        {
+          "name": "expiresAt",
+          "type": {
+            "option": "i64"
+          }
+        },
+        {
+          "name": "ephemeralSigners",
+          "type": {
+            "option": "u8"
+          }
+        }
+      ]
     },
     {
-      "name": "executeTransaction",
+      "name": "activateTransaction",
       "docs": [
-        "Instruction to execute a transaction.",
-        "Transaction status must be \"executeReady\", and the account list must match",
-        "the unique indexed accounts in the following manner:",
-        "[ix_1_account, ix_1_program_account, ix_1_remaining_account_1, ix_1_remaining_account_2, ...]",
-        "",
-        "Refer to the README for more information on how to construct the account list."
+        "Instruction to set the state of a transaction \"active\".",
+        "\"active\" transactions can then be signed off by multisig members"
       ],
       "accounts": [
         {
           "name": "multisig",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -1158,39 +1261,25 @@ export const IDL: SquadsMpl = {
           "isSigner": false
         },
         {
-          "name": "member",
+          "name": "creator",
           "isMut": true,
           "isSigner": true
         }
       ],
-      "args": [
-        {
-          "name": "accountList",
-          "type": "bytes"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "executeInstruction",
+      "name": "addInstruction",
       "docs": [
-        "Instruction to sequentially execute attached instructions.",
-        "Instructions executed in this matter must be executed in order,",
-        "this may be helpful for processing large batch transfers.",
-        "This instruction can only be used for transactions with an authority",
-        "index of 1 or greater.",
-        "",
-        "NOTE - do not use this instruction if there is not total clarity around",
-        "potential side effects, as this instruction implies that the approved",
-        "transaction will be executed partially, and potentially spread out over",
-        "a period of time. This could introduce problems with state and failed",
-        "transactions. For example: a program invoked in one of these instructions",
-        "may be upgraded between executions and potentially leave one of the",
-        "necessary accounts in an invalid state."
+        "Instruction to attach an instruction to a transaction.",
+        "Transactions must be in the \"draft\" status, and any",
+        "signer (aside from execution payer) specified in an instruction",
+        "must match the authority PDA specified during the transaction creation."
       ],
       "accounts": [
         {
           "name": "multisig",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -1204,252 +1293,6677 @@ export const IDL: SquadsMpl = {
           "isSigner": false
         },
         {
-          "name": "member",
+          "name": "creator",
           "isMut": true,
           "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
         }
       ],
-      "args": []
-    }
-  ],
-  "accounts": [
-    {
-      "name": "ms",
-      "docs": [
-        "Ms is the basic state account for a multisig."
-      ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "threshold",
-            "type": "u16"
-          },
-          {
-            "name": "authorityIndex",
-            "type": "u16"
-          },
-          {
-            "name": "transactionIndex",
-            "type": "u32"
-          },
-          {
-            "name": "msChangeIndex",
-            "type": "u32"
-          },
-          {
-            "name": "bump",
-            "type": "u8"
-          },
-          {
-            "name": "createKey",
-            "type": "publicKey"
-          },
-          {
-            "name": "allowExternalExecute",
-            "type": "bool"
-          },
-          {
-            "name": "keys",
-            "type": {
-              "vec": "publicKey"
-            }
+      "args": [
+        {
+          "name": "incomingInstruction",
+          "type": {
+            "defined": "IncomingInstruction"
           }
-        ]
-      }
+        }
+      ]
     },
     {
-      "name": "msTransaction",
+      "name": "createInstructionBuffer",
       "docs": [
-        "The MsTransaction is the state account for a multisig transaction"
+        "Instruction to start uploading an instruction that is too large for add_instruction.",
+        "Creates the instruction buffer of the transaction, sized for data_len bytes of instruction data,",
+        "which is then uploaded with append_instruction_data. Only one buffer can be used at a time.",
+        "Transactions must be in the \"draft\" status."
       ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "creator",
-            "type": "publicKey"
-          },
-          {
-            "name": "ms",
-            "type": "publicKey"
-          },
-          {
-            "name": "transactionIndex",
-            "type": "u32"
-          },
-          {
-            "name": "authorityIndex",
-            "type": "u32"
-          },
-          {
-            "name": "authorityBump",
-            "type": "u8"
-          },
-          {
-            "name": "status",
-            "type": {
-              "defined": "MsTransactionStatus"
-            }
-          },
-          {
-            "name": "instructionIndex",
-            "type": "u8"
-          },
-          {
-            "name": "bump",
-            "type": "u8"
-          },
-          {
-            "name": "approved",
-            "type": {
-              "vec": "publicKey"
-            }
-          },
-          {
-            "name": "rejected",
-            "type": {
-              "vec": "publicKey"
-            }
-          },
-          {
-            "name": "cancelled",
-            "type": {
-              "vec": "publicKey"
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructionBuffer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "instructionProgramId",
+          "type": "publicKey"
+        },
+        {
+          "name": "keys",
+          "type": {
+            "vec": {
+              "defined": "MsAccountMeta"
             }
-          },
-          {
-            "name": "executedIndex",
-            "type": "u8"
           }
-        ]
-      }
+        },
+        {
+          "name": "dataLen",
+          "type": "u32"
+        }
+      ]
     },
     {
-      "name": "msInstruction",
+      "name": "appendInstructionData",
       "docs": [
-        "The state account for an instruction that is attached to a transaction.",
-        "Almost analagous to the native Instruction struct for solana, but with an extra",
-        "field for the bump."
+        "Instruction to append the next part of the instruction data to the instruction buffer.",
+        "Transactions must be in the \"draft\" status."
       ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "programId",
-            "type": "publicKey"
-          },
-          {
-            "name": "keys",
-            "type": {
-              "vec": {
-                "defined": "MsAccountMeta"
-              }
-            }
-          },
-          {
-            "name": "data",
-            "type": "bytes"
-          },
-          {
-            "name": "instructionIndex",
-            "type": "u8"
-          },
-          {
-            "name": "bump",
-            "type": "u8"
-          },
-          {
-            "name": "executed",
-            "type": "bool"
-          }
-        ]
-      }
-    }
-  ],
-  "types": [
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructionBuffer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "data",
+          "type": "bytes"
+        }
+      ]
+    },
     {
-      "name": "MsAccountMeta",
+      "name": "finalizeInstruction",
       "docs": [
-        "Wrapper for our internal MsInstruction key serialization schema",
-        "MsAccount meta is identical to the AccountMeta struct, but defined",
-        "here for serialization purposes."
+        "Instruction to attach the uploaded instruction to the transaction once all of its data",
+        "has been appended. The instruction is saved in an MsInstruction at the next instruction",
+        "index, the same as add_instruction, and the instruction buffer is closed.",
+        "Transactions must be in the \"draft\" status."
       ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "pubkey",
-            "type": "publicKey"
-          },
-          {
-            "name": "isSigner",
-            "type": "bool"
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "instructionBuffer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "instruction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeInstructionBuffer",
+      "docs": [
+        "Instruction for the creator of a transaction to close its instruction buffer without",
+        "finalizing it, returning the lamports to the creator. This works in any transaction status."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructionBuffer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "replaceInstruction",
+      "docs": [
+        "Instruction to replace an instruction attached to a transaction.",
+        "Transactions must be in the \"draft\" status, and the instruction keeps its",
+        "instruction_index, so the order of execution is unchanged.",
+        "Only MsInstruction accounts can be replaced, not batches or lookup instructions."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instruction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "incomingInstruction",
+          "type": {
+            "defined": "IncomingInstruction"
+          }
+        }
+      ]
+    },
+    {
+      "name": "removeInstruction",
+      "docs": [
+        "Instruction to remove an instruction attached to a transaction.",
+        "Transactions must be in the \"draft\" status. The MsInstruction accounts after the",
+        "removed one must be passed in order as remaining accounts, and each is moved down",
+        "one index so the instruction PDAs stay sequential. The last instruction account is",
+        "closed, and the instruction_index of the transaction is decreased.",
+        "Only MsInstruction accounts can be removed or moved, not batches or lookup instructions."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "instruction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "instructionIndex",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "addInstructions",
+      "docs": [
+        "Instruction to attach several instructions to a transaction at once.",
+        "The instructions are stored in a single MsInstructionBatch account, which uses",
+        "the PDA of the first instruction index it holds. The instruction_index of the",
+        "transaction is increased by the number of instructions in the batch.",
+        "Transactions must be in the \"draft\" status, and batched instructions can",
+        "only be executed with execute_transaction."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "instructionBatch",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "incomingInstructions",
+          "type": {
+            "vec": {
+              "defined": "IncomingInstruction"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "addLookupInstruction",
+      "docs": [
+        "Instruction to attach an instruction whose account keys reference addresses in",
+        "address lookup tables. The lookup tables must be controlled by the authority of the",
+        "transaction, and are checked and resolved when the transaction is executed.",
+        "Lookup instructions can only be executed with execute_transaction."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "instruction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "incomingInstruction",
+          "type": {
+            "defined": "IncomingLookupInstruction"
+          }
+        }
+      ]
+    },
+    {
+      "name": "approveTransaction",
+      "docs": [
+        "Instruction to approve a transaction on behalf of a member.",
+        "The transaction must have an \"active\" status. If the transaction authority",
+        "has an AuthorityPolicy, its threshold is used instead of the multisig threshold."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "rejectTransaction",
+      "docs": [
+        "Instruction to reject a transaction.",
+        "The transaction must have an \"active\" status. If the transaction authority",
+        "has an AuthorityPolicy, the rejection cutoff is based on its threshold. If the",
+        "multisig has a reject threshold, reaching it rejects the transaction as well."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "abstainTransaction",
+      "docs": [
+        "Instruction to abstain from voting on a transaction.",
+        "The transaction must have an \"active\" status. Abstaining counts toward the",
+        "participation on the transaction but not its approval, so the transaction is",
+        "rejected once the members that haven't rejected or abstained can't reach the threshold."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelTransaction",
+      "docs": [
+        "Instruction to cancel a transaction.",
+        "Transactions must be in the \"executeReady\" status.",
+        "Transaction will only be cancelled if the number of",
+        "cancellations reaches the threshold. A cancelled",
+        "transaction will no longer be able to be executed. If the transaction authority",
+        "has an AuthorityPolicy, its threshold is used instead of the multisig threshold."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "withdrawTransaction",
+      "docs": [
+        "Instruction for the creator to withdraw their own transaction.",
+        "Transactions must be in the \"draft\" or \"active\" status, and can't have any approvals yet.",
+        "The transaction is marked as \"cancelled\", and if close is set, the transaction and",
+        "its attached MsInstruction accounts (passed in order as remaining accounts, followed by",
+        "the instruction buffer if there is one) are closed in the same instruction,",
+        "returning the lamports to the creator."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "close",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "expireTransaction",
+      "docs": [
+        "Instruction to mark a transaction that has passed its expiry as \"expired\".",
+        "This is permissionless, and works for transactions that are in the",
+        "\"draft\", \"active\", or \"executeReady\" status."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeTransaction",
+      "docs": [
+        "Instruction to close a finished transaction and reclaim its rent.",
+        "The transaction must be \"executed\", \"rejected\", \"cancelled\" or \"expired\",",
+        "or deprecated by the multisig change index. All of the attached MsInstruction",
+        "(MsInstructionBatch and MsLookupInstruction) accounts must be passed in order as remaining accounts,",
+        "and are closed as well, along with the instruction buffer if it is passed after them.",
+        "The lamports are returned to the creator of the transaction."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executeTransaction",
+      "docs": [
+        "Instruction to execute a transaction.",
+        "Transaction status must be \"executeReady\", and the account list must match",
+        "the unique indexed accounts in the following manner:",
+        "[ix_1_account, ix_1_program_account, ix_1_remaining_account_1, ix_1_remaining_account_2, ...]",
+        "An MsInstructionBatch account is followed by the program and remaining accounts",
+        "of each of its instructions in order.",
+        "An MsLookupInstruction account is followed by its lookup table accounts, then the",
+        "program and the accounts resolved from the lookup tables.",
+        "",
+        "If the transaction was partially executed (with execute_transaction_chunk or",
+        "execute_instruction), the account list starts at the next instruction to execute.",
+        "",
+        "Refer to the README for more information on how to construct the account list."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "accountList",
+          "type": "bytes"
+        }
+      ]
+    },
+    {
+      "name": "executeTransactionChunk",
+      "docs": [
+        "Instruction to execute part of a transaction, for transactions that",
+        "don't fit in the compute or account limits of a single execution.",
+        "Executes the instructions from executed_index + 1 up to and including end_index,",
+        "and records the progress in the executed_index of the transaction. The transaction",
+        "is marked as executed once its last instruction has been executed.",
+        "The account list follows the same format as execute_transaction, starting at the",
+        "first instruction of the chunk. A chunk can't end in the middle of an MsInstructionBatch."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "accountList",
+          "type": "bytes"
+        },
+        {
+          "name": "endIndex",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "getExecutionAccounts",
+      "docs": [
+        "Read-only instruction that returns the accounts needed to execute a transaction,",
+        "meant to be used with a simulation. It walks the instructions from executed_index + 1",
+        "up to and including end_index (or the last instruction), and returns the deduplicated",
+        "account metas and the account_list for execute_transaction (or execute_transaction_chunk).",
+        "The instruction accounts of the transaction, and the lookup tables of any MsLookupInstruction,",
+        "are passed as remaining accounts in any order. The account metas are returned in pages",
+        "starting at offset, with at most limit accounts, as the return data is limited to 1024 bytes."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "endIndex",
+          "type": {
+            "option": "u8"
+          }
+        },
+        {
+          "name": "offset",
+          "type": "u16"
+        },
+        {
+          "name": "limit",
+          "type": {
+            "option": "u16"
+          }
+        }
+      ],
+      "returns": {
+        "defined": "ExecutionAccountsPage"
+      }
+    },
+    {
+      "name": "executeInstruction",
+      "docs": [
+        "Instruction to sequentially execute attached instructions.",
+        "Instructions executed in this matter must be executed in order,",
+        "this may be helpful for processing large batch transfers.",
+        "This instruction can only be used for transactions with an authority",
+        "index of 1 or greater, and not for instructions attached as a batch",
+        "or with lookup tables.",
+        "",
+        "NOTE - do not use this instruction if there is not total clarity around",
+        "potential side effects, as this instruction implies that the approved",
+        "transaction will be executed partially, and potentially spread out over",
+        "a period of time. This could introduce problems with state and failed",
+        "transactions. For example: a program invoked in one of these instructions",
+        "may be upgraded between executions and potentially leave one of the",
+        "necessary accounts in an invalid state."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "instruction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    }
+  ],
+  "accounts": [
+    {
+      "name": "ms",
+      "docs": [
+        "Ms is the basic state account for a multisig."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "threshold",
+            "type": "u16"
+          },
+          {
+            "name": "authorityIndex",
+            "type": "u16"
+          },
+          {
+            "name": "transactionIndex",
+            "type": "u32"
+          },
+          {
+            "name": "msChangeIndex",
+            "type": "u32"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "createKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "migrated",
+            "type": "bool"
+          },
+          {
+            "name": "keys",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "timeLock",
+            "type": "u32"
+          },
+          {
+            "name": "defaultTtl",
+            "type": "u32"
+          },
+          {
+            "name": "memberWeights",
+            "type": {
+              "vec": {
+                "defined": "MemberWeight"
+              }
+            }
+          },
+          {
+            "name": "authorityPolicyCount",
+            "type": "u16"
+          },
+          {
+            "name": "memberPermissions",
+            "type": {
+              "vec": {
+                "defined": "MemberPermissions"
+              }
+            }
+          },
+          {
+            "name": "recovery",
+            "type": {
+              "option": {
+                "defined": "RecoveryConfig"
+              }
+            }
+          },
+          {
+            "name": "rejectThreshold",
+            "type": "u16"
+          },
+          {
+            "name": "programPolicyCount",
+            "type": "u16"
+          },
+          {
+            "name": "disabledAuthorityCount",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "msMetadata",
+      "docs": [
+        "The MsMetadata stores the metadata (ie. name and description) of a multisig,",
+        "passed when creating it and updated with internal transactions."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "ms",
+            "type": "publicKey"
+          },
+          {
+            "name": "meta",
+            "type": "string"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "authorityPolicy",
+      "docs": [
+        "The AuthorityPolicy overrides the multisig threshold for transactions",
+        "that use the authority (vault) it was created for."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "ms",
+            "type": "publicKey"
+          },
+          {
+            "name": "authorityIndex",
+            "type": "u32"
+          },
+          {
+            "name": "threshold",
+            "type": "u16"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "authority",
+      "docs": [
+        "The Authority account describes an authority (vault) of the multisig,",
+        "seeded from [b\"squad\", multisig, authority_index, b\"authority_info\"]."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "ms",
+            "type": "publicKey"
+          },
+          {
+            "name": "authorityIndex",
+            "type": "u32"
+          },
+          {
+            "name": "label",
+            "type": "string"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "enabled",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "programPolicy",
+      "docs": [
+        "The ProgramPolicy restricts the programs that instructions of transactions using the",
+        "authority (vault) it was created for can invoke, to an allowlist or with a denylist."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "ms",
+            "type": "publicKey"
+          },
+          {
+            "name": "authorityIndex",
+            "type": "u32"
+          },
+          {
+            "name": "mode",
+            "type": {
+              "defined": "ProgramPolicyMode"
+            }
+          },
+          {
+            "name": "programs",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "spendingLimit",
+      "docs": [
+        "The SpendingLimit allows a set of members to transfer up to an amount of SOL or",
+        "tokens from an authority (vault) per period, without creating a transaction."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "ms",
+            "type": "publicKey"
+          },
+          {
+            "name": "authorityIndex",
+            "type": "u32"
+          },
+          {
+            "name": "authorityBump",
+            "type": "u8"
+          },
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "period",
+            "type": "i64"
+          },
+          {
+            "name": "remainingAmount",
+            "type": "u64"
+          },
+          {
+            "name": "lastReset",
+            "type": "i64"
+          },
+          {
+            "name": "members",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "recoveryProposal",
+      "docs": [
+        "The RecoveryProposal holds a replacement member set proposed by guardians, and is",
+        "seeded from [b\"squad\", multisig, b\"recovery\"]. Only one recovery can be proposed at a time."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "ms",
+            "type": "publicKey"
+          },
+          {
+            "name": "proposer",
+            "type": "publicKey"
+          },
+          {
+            "name": "newMembers",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "newThreshold",
+            "type": "u16"
+          },
+          {
+            "name": "approvals",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "readyAt",
+            "type": "i64"
+          },
+          {
+            "name": "configIndex",
+            "type": "u32"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "msTransaction",
+      "docs": [
+        "The MsTransaction is the state account for a multisig transaction"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "publicKey"
+          },
+          {
+            "name": "ms",
+            "type": "publicKey"
+          },
+          {
+            "name": "transactionIndex",
+            "type": "u32"
+          },
+          {
+            "name": "authorityIndex",
+            "type": "u32"
+          },
+          {
+            "name": "authorityBump",
+            "type": "u8"
+          },
+          {
+            "name": "status",
+            "type": {
+              "defined": "MsTransactionStatus"
+            }
+          },
+          {
+            "name": "instructionIndex",
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "approved",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "rejected",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "cancelled",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "executedIndex",
+            "type": "u8"
+          },
+          {
+            "name": "readyAt",
+            "type": "i64"
+          },
+          {
+            "name": "expiresAt",
+            "type": "i64"
+          },
+          {
+            "name": "ephemeralSigners",
+            "type": "u8"
+          },
+          {
+            "name": "abstained",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "voteChanges",
+            "type": {
+              "vec": {
+                "defined": "VoteChange"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "msInstruction",
+      "docs": [
+        "The state account for an instruction that is attached to a transaction.",
+        "Almost analagous to the native Instruction struct for solana, but with an extra",
+        "field for the bump."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "programId",
+            "type": "publicKey"
+          },
+          {
+            "name": "keys",
+            "type": {
+              "vec": {
+                "defined": "MsAccountMeta"
+              }
+            }
+          },
+          {
+            "name": "data",
+            "type": "bytes"
+          },
+          {
+            "name": "instructionIndex",
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "executed",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "msInstructionBatch",
+      "docs": [
+        "The state account for several consecutive instructions that are attached to a",
+        "transaction at once. Uses the PDA of the first instruction index it holds."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "instructionIndex",
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "instructions",
+            "type": {
+              "vec": {
+                "defined": "IncomingInstruction"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "msInstructionBuffer",
+      "docs": [
+        "The state account used to upload an instruction that is too large to be attached",
+        "with add_instruction in a single transaction. The account is sized for the full",
+        "instruction data when it's created, the data is then appended over several transactions,",
+        "and the account is turned into an MsInstruction by finalize_instruction.",
+        "Seeded from [b\"squad\", transaction, b\"instruction_buffer\"]."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "programId",
+            "type": "publicKey"
+          },
+          {
+            "name": "keys",
+            "type": {
+              "vec": {
+                "defined": "MsAccountMeta"
+              }
+            }
+          },
+          {
+            "name": "dataLen",
+            "type": "u32"
+          },
+          {
+            "name": "data",
+            "type": "bytes"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "msLookupInstruction",
+      "docs": [
+        "The state account for an instruction whose accounts are resolved from address",
+        "lookup tables controlled by the transaction authority when it's executed."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "programId",
+            "type": "publicKey"
+          },
+          {
+            "name": "lookupTables",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "keys",
+            "type": {
+              "vec": {
+                "defined": "MsLookupAccountMeta"
+              }
+            }
+          },
+          {
+            "name": "data",
+            "type": "bytes"
+          },
+          {
+            "name": "instructionIndex",
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "MemberWeight",
+      "docs": [
+        "The voting weight of a multisig member."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "key",
+            "type": "publicKey"
+          },
+          {
+            "name": "weight",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RecoveryConfig",
+      "docs": [
+        "The recovery configuration of a multisig. If enough guardians approve",
+        "a recovery proposal, and no member objects within the delay, the members",
+        "of the multisig are replaced."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "guardians",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "threshold",
+            "type": "u16"
+          },
+          {
+            "name": "delay",
+            "type": "u32"
+          },
+          {
+            "name": "changeIndex",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MemberPermissions",
+      "docs": [
+        "The permissions mask of a multisig member."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "key",
+            "type": "publicKey"
+          },
+          {
+            "name": "mask",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LegacyMs",
+      "docs": [
+        "The Ms layout prior to the trailing settings fields (time lock, etc.).",
+        "Multisigs created with this layout may not have the space needed to",
+        "deserialize into the current Ms, or may have stale bytes after the keys",
+        "left by removed members, and are read with this struct when migrating them."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "threshold",
+            "type": "u16"
+          },
+          {
+            "name": "authorityIndex",
+            "type": "u16"
+          },
+          {
+            "name": "transactionIndex",
+            "type": "u32"
+          },
+          {
+            "name": "msChangeIndex",
+            "type": "u32"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "createKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "migrated",
+            "type": "bool"
+          },
+          {
+            "name": "keys",
+            "type": {
+              "vec": "publicKey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "VoteChange",
+      "docs": [
+        "A change of a member's vote on a transaction, kept for auditing."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "member",
+            "type": "publicKey"
+          },
+          {
+            "name": "oldVote",
+            "type": {
+              "defined": "Vote"
+            }
+          },
+          {
+            "name": "newVote",
+            "type": {
+              "defined": "Vote"
+            }
+          },
+          {
+            "name": "slot",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MsLookupAccountMeta",
+      "docs": [
+        "Account meta for a MsLookupInstruction, referencing an address in one of its lookup tables"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tableIndex",
+            "type": "u8"
+          },
+          {
+            "name": "addressIndex",
+            "type": "u8"
+          },
+          {
+            "name": "isSigner",
+            "type": "bool"
+          },
+          {
+            "name": "isWritable",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "IncomingLookupInstruction",
+      "docs": [
+        "Incoming lookup instruction schema, used as an argument in add_lookup_instruction."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "programId",
+            "type": "publicKey"
+          },
+          {
+            "name": "lookupTables",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "keys",
+            "type": {
+              "vec": {
+                "defined": "MsLookupAccountMeta"
+              }
+            }
+          },
+          {
+            "name": "data",
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MsAccountMeta",
+      "docs": [
+        "Wrapper for our internal MsInstruction key serialization schema",
+        "MsAccount meta is identical to the AccountMeta struct, but defined",
+        "here for serialization purposes."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pubkey",
+            "type": "publicKey"
+          },
+          {
+            "name": "isSigner",
+            "type": "bool"
+          },
+          {
+            "name": "isWritable",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ExecutionAccounts",
+      "docs": [
+        "The accounts needed to execute a transaction, returned by get_execution_accounts.",
+        "The accounts are deduplicated and are passed as the remaining accounts of",
+        "execute_transaction, along with the account_list, which indexes into them."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "accounts",
+            "type": {
+              "vec": {
+                "defined": "MsAccountMeta"
+              }
+            }
+          },
+          {
+            "name": "accountList",
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ExecutionAccountsPage",
+      "docs": [
+        "A page of the ExecutionAccounts returned by get_execution_accounts. The return data",
+        "of an instruction is limited to 1024 bytes, so the accounts are fetched in pages by",
+        "passing the offset of the next page until all total_accounts have been returned."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "accounts",
+            "type": {
+              "vec": {
+                "defined": "MsAccountMeta"
+              }
+            }
+          },
+          {
+            "name": "accountList",
+            "type": "bytes"
+          },
+          {
+            "name": "totalAccounts",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "IncomingInstruction",
+      "docs": [
+        "Incoming instruction schema, used as an argument in the attach_instruction.",
+        "Identical to the solana struct for Instruction, but uses the MsAccountMeta.",
+        "Provided for de/serialization purposes."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "programId",
+            "type": "publicKey"
+          },
+          {
+            "name": "keys",
+            "type": {
+              "vec": {
+                "defined": "MsAccountMeta"
+              }
+            }
+          },
+          {
+            "name": "data",
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ProgramPolicyMode",
+      "docs": [
+        "Whether the programs of a ProgramPolicy are the only ones allowed, or the ones denied"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Allow"
+          },
+          {
+            "name": "Deny"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Vote",
+      "docs": [
+        "A vote of a member on a transaction."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Approve"
+          },
+          {
+            "name": "Reject"
+          },
+          {
+            "name": "Abstain"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MsTransactionStatus",
+      "docs": [
+        "MsTransactionStatus enum of the current status of the Multisig Transaction."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Draft"
+          },
+          {
+            "name": "Active"
+          },
+          {
+            "name": "ExecuteReady"
+          },
+          {
+            "name": "Executed"
+          },
+          {
+            "name": "Rejected"
+          },
+          {
+            "name": "Cancelled"
+          },
+          {
+            "name": "Expired"
+          }
+        ]
+      }
+    }
+  ],
+  "events": [
+    {
+      "name": "MultisigCreated",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "threshold",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "members",
+          "type": {
+            "vec": "publicKey"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MemberAdded",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "member",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "threshold",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "changeIndex",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MemberRemoved",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "member",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "threshold",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "changeIndex",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MemberKeyRotated",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "oldMember",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "newMember",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MemberWeightChanged",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "member",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "weight",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "changeIndex",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MemberPermissionsChanged",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "member",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "permissions",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "changeIndex",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RecoveryConfigSet",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "guardians",
+          "type": {
+            "vec": "publicKey"
+          },
+          "index": false
+        },
+        {
+          "name": "threshold",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "delay",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "changeIndex",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RecoveryConfigRemoved",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "changeIndex",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RecoveryProposed",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "guardian",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "newMembers",
+          "type": {
+            "vec": "publicKey"
+          },
+          "index": false
+        },
+        {
+          "name": "newThreshold",
+          "type": "u16",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RecoveryApproved",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "guardian",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "readyAt",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RecoveryCancelled",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "member",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RecoveryExecuted",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "newMembers",
+          "type": {
+            "vec": "publicKey"
+          },
+          "index": false
+        },
+        {
+          "name": "threshold",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "changeIndex",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ThresholdChanged",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "threshold",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "changeIndex",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RejectThresholdChanged",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "rejectThreshold",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "changeIndex",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TimeLockChanged",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "timeLock",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "changeIndex",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "DefaultTtlChanged",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "defaultTtl",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "changeIndex",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AuthorityAdded",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "authorityIndex",
+          "type": "u16",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AuthorityUpdated",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "authorityIndex",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "label",
+          "type": "string",
+          "index": false
+        },
+        {
+          "name": "enabled",
+          "type": "bool",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AuthorityPolicyUpdated",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "authorityIndex",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "threshold",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "changeIndex",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AuthorityPolicyRemoved",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "authorityIndex",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "changeIndex",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ProgramPolicyUpdated",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "authorityIndex",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "mode",
+          "type": {
+            "defined": "ProgramPolicyMode"
+          },
+          "index": false
+        },
+        {
+          "name": "programs",
+          "type": {
+            "vec": "publicKey"
+          },
+          "index": false
+        },
+        {
+          "name": "changeIndex",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ProgramPolicyRemoved",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "authorityIndex",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "changeIndex",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SpendingLimitAdded",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "spendingLimit",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "authorityIndex",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "period",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "members",
+          "type": {
+            "vec": "publicKey"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SpendingLimitRemoved",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "spendingLimit",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "authorityIndex",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SpendingLimitUsed",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "spendingLimit",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "member",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "destination",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "remainingAmount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TransactionCreated",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transactionIndex",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "authorityIndex",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "status",
+          "type": {
+            "defined": "MsTransactionStatus"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TransactionActivated",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transactionIndex",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "status",
+          "type": {
+            "defined": "MsTransactionStatus"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TransactionApproved",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transactionIndex",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "member",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "status",
+          "type": {
+            "defined": "MsTransactionStatus"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TransactionRejected",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transactionIndex",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "member",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "status",
+          "type": {
+            "defined": "MsTransactionStatus"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TransactionAbstained",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transactionIndex",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "member",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "status",
+          "type": {
+            "defined": "MsTransactionStatus"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TransactionCancelled",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transactionIndex",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "member",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "status",
+          "type": {
+            "defined": "MsTransactionStatus"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TransactionWithdrawn",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transactionIndex",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "closed",
+          "type": "bool",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TransactionExpired",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transactionIndex",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "status",
+          "type": {
+            "defined": "MsTransactionStatus"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TransactionExecuted",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transactionIndex",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "member",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "status",
+          "type": {
+            "defined": "MsTransactionStatus"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "InstructionExecuted",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transactionIndex",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "instructionIndex",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "member",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "status",
+          "type": {
+            "defined": "MsTransactionStatus"
+          },
+          "index": false
+        }
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "KeyNotInMultisig"
+    },
+    {
+      "code": 6001,
+      "name": "InvalidTransactionState"
+    },
+    {
+      "code": 6002,
+      "name": "InvalidNumberOfAccounts"
+    },
+    {
+      "code": 6003,
+      "name": "InvalidInstructionAccount"
+    },
+    {
+      "code": 6004,
+      "name": "InvalidAuthorityIndex"
+    },
+    {
+      "code": 6005,
+      "name": "TransactionAlreadyExecuted"
+    },
+    {
+      "code": 6006,
+      "name": "CannotRemoveSoloMember"
+    },
+    {
+      "code": 6007,
+      "name": "InvalidThreshold"
+    },
+    {
+      "code": 6008,
+      "name": "DeprecatedTransaction"
+    },
+    {
+      "code": 6009,
+      "name": "InstructionFailed"
+    },
+    {
+      "code": 6010,
+      "name": "MaxMembersReached"
+    },
+    {
+      "code": 6011,
+      "name": "EmptyMembers"
+    },
+    {
+      "code": 6012,
+      "name": "PartialExecution"
+    },
+    {
+      "code": 6013,
+      "name": "NotEnoughLamports"
+    },
+    {
+      "code": 6014,
+      "name": "TimeLockNotReleased"
+    },
+    {
+      "code": 6015,
+      "name": "TransactionExpired"
+    },
+    {
+      "code": 6016,
+      "name": "TransactionNotExpired"
+    },
+    {
+      "code": 6017,
+      "name": "InvalidExpiry"
+    },
+    {
+      "code": 6018,
+      "name": "InvalidMemberWeight"
+    },
+    {
+      "code": 6019,
+      "name": "InvalidAuthorityPolicy"
+    },
+    {
+      "code": 6020,
+      "name": "InvalidSpendingLimit"
+    },
+    {
+      "code": 6021,
+      "name": "SpendingLimitExceeded"
+    },
+    {
+      "code": 6022,
+      "name": "KeyNotInSpendingLimit"
+    },
+    {
+      "code": 6023,
+      "name": "MaxInstructionsReached"
+    },
+    {
+      "code": 6024,
+      "name": "InvalidLookupTable"
+    },
+    {
+      "code": 6025,
+      "name": "InvalidPermissions"
+    },
+    {
+      "code": 6026,
+      "name": "MissingPermission"
+    },
+    {
+      "code": 6027,
+      "name": "MetadataTooLong"
+    },
+    {
+      "code": 6028,
+      "name": "KeyAlreadyInMultisig"
+    },
+    {
+      "code": 6029,
+      "name": "InvalidRecoveryConfig"
+    },
+    {
+      "code": 6030,
+      "name": "KeyNotGuardian"
+    },
+    {
+      "code": 6031,
+      "name": "RecoveryNotReady"
+    },
+    {
+      "code": 6032,
+      "name": "TransactionAlreadyApproved"
+    },
+    {
+      "code": 6033,
+      "name": "InstructionBufferOverflow"
+    },
+    {
+      "code": 6034,
+      "name": "InstructionBufferIncomplete"
+    },
+    {
+      "code": 6035,
+      "name": "InstructionNotAllowed"
+    },
+    {
+      "code": 6036,
+      "name": "InvalidProgramPolicy"
+    },
+    {
+      "code": 6037,
+      "name": "ProgramNotAllowed"
+    },
+    {
+      "code": 6038,
+      "name": "InvalidAuthorityLabel"
+    },
+    {
+      "code": 6039,
+      "name": "AuthorityDisabled"
+    },
+    {
+      "code": 6040,
+      "name": "TooManyEphemeralSigners"
+    },
+    {
+      "code": 6041,
+      "name": "RecoveryConfigChanged"
+    },
+    {
+      "code": 6042,
+      "name": "MultisigNotMigrated"
+    }
+  ]
+};
+
+export const IDL: SquadsMpl = {
+  "version": "1.3.1",
+  "name": "squads_mpl",
+  "instructions": [
+    {
+      "name": "create",
+      "docs": [
+        "Creates a new multisig account"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "threshold",
+          "type": "u16"
+        },
+        {
+          "name": "createKey",
+          "type": "publicKey"
+        },
+        {
+          "name": "members",
+          "type": {
+            "vec": "publicKey"
+          }
+        },
+        {
+          "name": "meta",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "addMember",
+      "docs": [
+        "The instruction to add a new member to the multisig.",
+        "Adds member/key to the multisig and reallocates space if neccessary",
+        "If the multisig needs to be reallocated, it must be prefunded with",
+        "enough lamports to cover the new size."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "newMember",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "addMemberWithWeight",
+      "docs": [
+        "The instruction to add a new member with a voting weight other than the default of 1.",
+        "If the member is already in the multisig, only their weight is changed."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "newMember",
+          "type": "publicKey"
+        },
+        {
+          "name": "weight",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "changeMemberWeight",
+      "docs": [
+        "The instruction to change the voting weight of a member.",
+        "Approvals, rejections and cancellations are counted by the combined weight",
+        "of the members, and the threshold is lowered if it can no longer be reached."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "member",
+          "type": "publicKey"
+        },
+        {
+          "name": "weight",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "changeMemberPermissions",
+      "docs": [
+        "The instruction to change the permissions of a member (Initiate, Vote, Execute).",
+        "Only members with the vote permission count towards the threshold, and the",
+        "threshold is lowered if it can no longer be reached."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "member",
+          "type": "publicKey"
+        },
+        {
+          "name": "permissions",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "removeMember",
+      "docs": [
+        "The instruction to remove a member from the multisig.",
+        "All of the AuthorityPolicy accounts of the multisig must be passed as writable",
+        "remaining accounts, in order of their authority index, and policy thresholds",
+        "that can no longer be reached are lowered along with the multisig threshold."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "oldMember",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "rotateMemberKey",
+      "docs": [
+        "The instruction for a member to rotate their key, signed by both the old and new key.",
+        "The key is replaced in place (keeping its weight and permissions), and unlike",
+        "removing and adding a member, the change index is not updated. Pending transactions",
+        "passed as remaining accounts have the votes (and creator) of the old key moved to the new key,",
+        "and SpendingLimit accounts passed along with them have the old key replaced in their members.",
+        "The creator of a TransactionMeta (txmeta program) isn't changed, its rent still goes to the old key."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "oldMember",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "newMember",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "removeMemberAndChangeThreshold",
+      "docs": [
+        "The instruction to change the threshold of the multisig and simultaneously remove a member"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "oldMember",
+          "type": "publicKey"
+        },
+        {
+          "name": "newThreshold",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "addMemberAndChangeThreshold",
+      "docs": [
+        "The instruction to change the threshold of the multisig and simultaneously add a member"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "newMember",
+          "type": "publicKey"
+        },
+        {
+          "name": "newThreshold",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "changeThreshold",
+      "docs": [
+        "The instruction to change the threshold of the multisig"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "newThreshold",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "changeRejectThreshold",
+      "docs": [
+        "The instruction to change the reject threshold of the multisig, the combined weight",
+        "of rejections that rejects a transaction (ie. a minority veto). A value of 0 disables it,",
+        "so transactions are only rejected once the threshold can no longer be reached."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "rejectThreshold",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "changeTimeLock",
+      "docs": [
+        "The instruction to change the time lock of the multisig.",
+        "Transactions that become ExecuteReady will need to wait the time lock",
+        "(in seconds) before they can be executed, giving members a window to cancel."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "timeLock",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "changeDefaultTtl",
+      "docs": [
+        "The instruction to change the default time to live (in seconds) of new transactions.",
+        "A value of 0 means transactions will not expire unless created with an explicit expiry."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "defaultTtl",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "initMetadata",
+      "docs": [
+        "The instruction to create the metadata of a multisig created before the MsMetadata",
+        "account existed. The payer funds the metadata account, and needs to sign the execution."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "metadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "meta",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "updateMetadata",
+      "docs": [
+        "The instruction to update the metadata of the multisig (ie. name and description).",
+        "The metadata account is resized to fit, with the payer covering any additional rent."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "metadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "meta",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "setRecoveryConfig",
+      "docs": [
+        "The instruction to set the recovery config of the multisig. Guardians can propose",
+        "a replacement member set, which takes effect if the guardian threshold approves it",
+        "and no member objects within the delay (at least RecoveryConfig::MIN_DELAY seconds)."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "guardians",
+          "type": {
+            "vec": "publicKey"
+          }
+        },
+        {
+          "name": "threshold",
+          "type": "u16"
+        },
+        {
+          "name": "delay",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "removeRecoveryConfig",
+      "docs": [
+        "The instruction to remove the recovery config of the multisig."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "addAuthority",
+      "docs": [
+        "instruction to increase the authority value tracked in the multisig",
+        "Transactions can only be created for authorities up to the tracked index,",
+        "which can also be used to track commonly used authorities in a UI (ie, vault 1, vault 2, etc.)",
+        "An Authority account describing the new authority can be added with register_authority."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "registerAuthority",
+      "docs": [
+        "The instruction to register an authority (vault) of the multisig, creating its",
+        "Authority account with a label describing what it is used for. The authority",
+        "must already be tracked by the multisig (see add_authority), and is enabled.",
+        "The payer funds the Authority account, and needs to sign the execution."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "authorityIndex",
+          "type": "u32"
+        },
+        {
+          "name": "label",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "updateAuthority",
+      "docs": [
+        "The instruction to update the label of an authority, and enable or disable it.",
+        "Transactions can't be created for a disabled authority, existing transactions",
+        "are not affected."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "label",
+          "type": "string"
+        },
+        {
+          "name": "enabled",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "addAuthorityPolicy",
+      "docs": [
+        "The instruction to add a policy for an authority (vault), which overrides",
+        "the threshold of the multisig for transactions using that authority.",
+        "The payer funds the AuthorityPolicy account, and needs to sign the execution."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "policy",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "authorityIndex",
+          "type": "u32"
+        },
+        {
+          "name": "threshold",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "changeAuthorityPolicy",
+      "docs": [
+        "The instruction to change the threshold of an authority policy"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "policy",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "threshold",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "removeAuthorityPolicy",
+      "docs": [
+        "The instruction to remove an authority policy, the authority will",
+        "use the multisig threshold again."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "policy",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentCollector",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "addProgramPolicy",
+      "docs": [
+        "The instruction to add a program policy to an authority (vault), which limits the",
+        "programs that instructions of transactions using that authority can invoke, to the",
+        "listed programs (Allow) or to any but the listed programs (Deny).",
+        "The payer funds the ProgramPolicy account, and needs to sign the execution."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "policy",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "authorityIndex",
+          "type": "u32"
+        },
+        {
+          "name": "mode",
+          "type": {
+            "defined": "ProgramPolicyMode"
+          }
+        },
+        {
+          "name": "programs",
+          "type": {
+            "vec": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "changeProgramPolicy",
+      "docs": [
+        "The instruction to change the mode and programs of a program policy.",
+        "Attached instructions are checked against the policy again when executed."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "policy",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "mode",
+          "type": {
+            "defined": "ProgramPolicyMode"
+          }
+        },
+        {
+          "name": "programs",
+          "type": {
+            "vec": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "removeProgramPolicy",
+      "docs": [
+        "The instruction to remove a program policy, the authority can",
+        "invoke any program again."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "policy",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentCollector",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "addSpendingLimit",
+      "docs": [
+        "The instruction to add a spending limit to an authority (vault).",
+        "The members of the spending limit can transfer up to the amount of the mint",
+        "(or native SOL if the mint is the default pubkey) from the authority per period,",
+        "without creating a transaction. The payer funds the SpendingLimit account,",
+        "and needs to sign the execution."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "spendingLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "authorityIndex",
+          "type": "u32"
+        },
+        {
+          "name": "mint",
+          "type": "publicKey"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "period",
+          "type": "i64"
+        },
+        {
+          "name": "members",
+          "type": {
+            "vec": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "removeSpendingLimit",
+      "docs": [
+        "The instruction to remove a spending limit from the multisig"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "spendingLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentCollector",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "useSpendingLimit",
+      "docs": [
+        "Instruction to transfer native SOL from an authority (vault) within a spending limit.",
+        "Can be used by any member of the spending limit without creating a transaction.",
+        "The authority can't be disabled."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "spendingLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "useTokenSpendingLimit",
+      "docs": [
+        "Instruction to transfer tokens from an authority (vault) within a spending limit.",
+        "Can be used by any member of the spending limit without creating a transaction.",
+        "The authority can't be disabled."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "spendingLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authorityTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destinationTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "migrateMultisig",
+      "docs": [
+        "Instruction to migrate a multisig created with the legacy Ms layout.",
+        "Reallocates the account so the trailing settings fields fit, with the",
+        "member covering any additional rent, and clears any stale bytes after",
+        "the keys so the new settings default to zero values. This is a no-op if",
+        "the multisig already uses the current layout. Every other instruction requires",
+        "a migrated multisig, so no settings can be set before the migration."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "proposeRecovery",
+      "docs": [
+        "Instruction for a guardian to propose a replacement member set and threshold.",
+        "The proposal needs to be approved by the guardian threshold, after which",
+        "the members have the recovery delay to object before it can be executed."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "proposal",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "guardian",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "newMembers",
+          "type": {
+            "vec": "publicKey"
+          }
+        },
+        {
+          "name": "newThreshold",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "approveRecovery",
+      "docs": [
+        "Instruction for a guardian to approve a recovery proposal. Once the guardian",
+        "threshold is reached, the recovery delay starts."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "proposal",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "guardian",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelRecovery",
+      "docs": [
+        "Instruction for a member to object to a recovery proposal, which closes it."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "proposal",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "proposer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executeRecovery",
+      "docs": [
+        "Instruction to execute a recovery proposal once the delay has passed without",
+        "an objection. The members and threshold of the multisig are replaced, resetting",
+        "any weights and permissions, and the change index is updated to deprecate any",
+        "active transactions."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "proposal",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "proposer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createTransaction",
+      "docs": [
+        "Instruction to create a multisig transaction.",
+        "Each transaction is tied to a single authority, and must be specified when",
+        "creating the instruction below. authority 0 is reserved for internal",
+        "instructions, whereas authorities 1 or greater refer to a vault,",
+        "upgrade authority, or other. The authority can't be above the authority",
+        "index tracked by the multisig, or disabled."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "authorityIndex",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "createTransactionWithOptions",
+      "docs": [
+        "Instruction to create a multisig transaction with optional settings.",
+        "An explicit expiry must be in the future, and if the multisig has a default ttl",
+        "it can't be later than the default expiry.",
+        "Ephemeral signers are PDAs derived from [b\"squad\", transaction, index, b\"ephemeral_signer\"],",
+        "for index 0 up to the number declared (at most MAX_EPHEMERAL_SIGNERS), and are added as",
+        "signers when the transaction is executed - eg. for creating accounts that need to sign",
+        "for their own creation."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "authorityIndex",
+          "type": "u32"
+        },
+        {
+          "name": "expiresAt",
+          "type": {
+            "option": "i64"
+          }
+        },
+        {
+          "name": "ephemeralSigners",
+          "type": {
+            "option": "u8"
+          }
+        }
+      ]
+    },
+    {
+      "name": "activateTransaction",
+      "docs": [
+        "Instruction to set the state of a transaction \"active\".",
+        "\"active\" transactions can then be signed off by multisig members"
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "addInstruction",
+      "docs": [
+        "Instruction to attach an instruction to a transaction.",
+        "Transactions must be in the \"draft\" status, and any",
+        "signer (aside from execution payer) specified in an instruction",
+        "must match the authority PDA specified during the transaction creation."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "instruction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "incomingInstruction",
+          "type": {
+            "defined": "IncomingInstruction"
+          }
+        }
+      ]
+    },
+    {
+      "name": "createInstructionBuffer",
+      "docs": [
+        "Instruction to start uploading an instruction that is too large for add_instruction.",
+        "Creates the instruction buffer of the transaction, sized for data_len bytes of instruction data,",
+        "which is then uploaded with append_instruction_data. Only one buffer can be used at a time.",
+        "Transactions must be in the \"draft\" status."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructionBuffer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "instructionProgramId",
+          "type": "publicKey"
+        },
+        {
+          "name": "keys",
+          "type": {
+            "vec": {
+              "defined": "MsAccountMeta"
+            }
+          }
+        },
+        {
+          "name": "dataLen",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "appendInstructionData",
+      "docs": [
+        "Instruction to append the next part of the instruction data to the instruction buffer.",
+        "Transactions must be in the \"draft\" status."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructionBuffer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "data",
+          "type": "bytes"
+        }
+      ]
+    },
+    {
+      "name": "finalizeInstruction",
+      "docs": [
+        "Instruction to attach the uploaded instruction to the transaction once all of its data",
+        "has been appended. The instruction is saved in an MsInstruction at the next instruction",
+        "index, the same as add_instruction, and the instruction buffer is closed.",
+        "Transactions must be in the \"draft\" status."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "instructionBuffer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "instruction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeInstructionBuffer",
+      "docs": [
+        "Instruction for the creator of a transaction to close its instruction buffer without",
+        "finalizing it, returning the lamports to the creator. This works in any transaction status."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructionBuffer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "replaceInstruction",
+      "docs": [
+        "Instruction to replace an instruction attached to a transaction.",
+        "Transactions must be in the \"draft\" status, and the instruction keeps its",
+        "instruction_index, so the order of execution is unchanged.",
+        "Only MsInstruction accounts can be replaced, not batches or lookup instructions."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instruction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "incomingInstruction",
+          "type": {
+            "defined": "IncomingInstruction"
+          }
+        }
+      ]
+    },
+    {
+      "name": "removeInstruction",
+      "docs": [
+        "Instruction to remove an instruction attached to a transaction.",
+        "Transactions must be in the \"draft\" status. The MsInstruction accounts after the",
+        "removed one must be passed in order as remaining accounts, and each is moved down",
+        "one index so the instruction PDAs stay sequential. The last instruction account is",
+        "closed, and the instruction_index of the transaction is decreased.",
+        "Only MsInstruction accounts can be removed or moved, not batches or lookup instructions."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "instruction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "instructionIndex",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "addInstructions",
+      "docs": [
+        "Instruction to attach several instructions to a transaction at once.",
+        "The instructions are stored in a single MsInstructionBatch account, which uses",
+        "the PDA of the first instruction index it holds. The instruction_index of the",
+        "transaction is increased by the number of instructions in the batch.",
+        "Transactions must be in the \"draft\" status, and batched instructions can",
+        "only be executed with execute_transaction."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "instructionBatch",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "incomingInstructions",
+          "type": {
+            "vec": {
+              "defined": "IncomingInstruction"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "addLookupInstruction",
+      "docs": [
+        "Instruction to attach an instruction whose account keys reference addresses in",
+        "address lookup tables. The lookup tables must be controlled by the authority of the",
+        "transaction, and are checked and resolved when the transaction is executed.",
+        "Lookup instructions can only be executed with execute_transaction."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "instruction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "incomingInstruction",
+          "type": {
+            "defined": "IncomingLookupInstruction"
+          }
+        }
+      ]
+    },
+    {
+      "name": "approveTransaction",
+      "docs": [
+        "Instruction to approve a transaction on behalf of a member.",
+        "The transaction must have an \"active\" status. If the transaction authority",
+        "has an AuthorityPolicy, its threshold is used instead of the multisig threshold."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "rejectTransaction",
+      "docs": [
+        "Instruction to reject a transaction.",
+        "The transaction must have an \"active\" status. If the transaction authority",
+        "has an AuthorityPolicy, the rejection cutoff is based on its threshold. If the",
+        "multisig has a reject threshold, reaching it rejects the transaction as well."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "abstainTransaction",
+      "docs": [
+        "Instruction to abstain from voting on a transaction.",
+        "The transaction must have an \"active\" status. Abstaining counts toward the",
+        "participation on the transaction but not its approval, so the transaction is",
+        "rejected once the members that haven't rejected or abstained can't reach the threshold."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelTransaction",
+      "docs": [
+        "Instruction to cancel a transaction.",
+        "Transactions must be in the \"executeReady\" status.",
+        "Transaction will only be cancelled if the number of",
+        "cancellations reaches the threshold. A cancelled",
+        "transaction will no longer be able to be executed. If the transaction authority",
+        "has an AuthorityPolicy, its threshold is used instead of the multisig threshold."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "withdrawTransaction",
+      "docs": [
+        "Instruction for the creator to withdraw their own transaction.",
+        "Transactions must be in the \"draft\" or \"active\" status, and can't have any approvals yet.",
+        "The transaction is marked as \"cancelled\", and if close is set, the transaction and",
+        "its attached MsInstruction accounts (passed in order as remaining accounts, followed by",
+        "the instruction buffer if there is one) are closed in the same instruction,",
+        "returning the lamports to the creator."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "close",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "expireTransaction",
+      "docs": [
+        "Instruction to mark a transaction that has passed its expiry as \"expired\".",
+        "This is permissionless, and works for transactions that are in the",
+        "\"draft\", \"active\", or \"executeReady\" status."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeTransaction",
+      "docs": [
+        "Instruction to close a finished transaction and reclaim its rent.",
+        "The transaction must be \"executed\", \"rejected\", \"cancelled\" or \"expired\",",
+        "or deprecated by the multisig change index. All of the attached MsInstruction",
+        "(MsInstructionBatch and MsLookupInstruction) accounts must be passed in order as remaining accounts,",
+        "and are closed as well, along with the instruction buffer if it is passed after them.",
+        "The lamports are returned to the creator of the transaction."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executeTransaction",
+      "docs": [
+        "Instruction to execute a transaction.",
+        "Transaction status must be \"executeReady\", and the account list must match",
+        "the unique indexed accounts in the following manner:",
+        "[ix_1_account, ix_1_program_account, ix_1_remaining_account_1, ix_1_remaining_account_2, ...]",
+        "An MsInstructionBatch account is followed by the program and remaining accounts",
+        "of each of its instructions in order.",
+        "An MsLookupInstruction account is followed by its lookup table accounts, then the",
+        "program and the accounts resolved from the lookup tables.",
+        "",
+        "If the transaction was partially executed (with execute_transaction_chunk or",
+        "execute_instruction), the account list starts at the next instruction to execute.",
+        "",
+        "Refer to the README for more information on how to construct the account list."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "accountList",
+          "type": "bytes"
+        }
+      ]
+    },
+    {
+      "name": "executeTransactionChunk",
+      "docs": [
+        "Instruction to execute part of a transaction, for transactions that",
+        "don't fit in the compute or account limits of a single execution.",
+        "Executes the instructions from executed_index + 1 up to and including end_index,",
+        "and records the progress in the executed_index of the transaction. The transaction",
+        "is marked as executed once its last instruction has been executed.",
+        "The account list follows the same format as execute_transaction, starting at the",
+        "first instruction of the chunk. A chunk can't end in the middle of an MsInstructionBatch."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "accountList",
+          "type": "bytes"
+        },
+        {
+          "name": "endIndex",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "getExecutionAccounts",
+      "docs": [
+        "Read-only instruction that returns the accounts needed to execute a transaction,",
+        "meant to be used with a simulation. It walks the instructions from executed_index + 1",
+        "up to and including end_index (or the last instruction), and returns the deduplicated",
+        "account metas and the account_list for execute_transaction (or execute_transaction_chunk).",
+        "The instruction accounts of the transaction, and the lookup tables of any MsLookupInstruction,",
+        "are passed as remaining accounts in any order. The account metas are returned in pages",
+        "starting at offset, with at most limit accounts, as the return data is limited to 1024 bytes."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "endIndex",
+          "type": {
+            "option": "u8"
+          }
+        },
+        {
+          "name": "offset",
+          "type": "u16"
+        },
+        {
+          "name": "limit",
+          "type": {
+            "option": "u16"
+          }
+        }
+      ],
+      "returns": {
+        "defined": "ExecutionAccountsPage"
+      }
+    },
+    {
+      "name": "executeInstruction",
+      "docs": [
+        "Instruction to sequentially execute attached instructions.",
+        "Instructions executed in this matter must be executed in order,",
+        "this may be helpful for processing large batch transfers.",
+        "This instruction can only be used for transactions with an authority",
+        "index of 1 or greater, and not for instructions attached as a batch",
+        "or with lookup tables.",
+        "",
+        "NOTE - do not use this instruction if there is not total clarity around",
+        "potential side effects, as this instruction implies that the approved",
+        "transaction will be executed partially, and potentially spread out over",
+        "a period of time. This could introduce problems with state and failed",
+        "transactions. For example: a program invoked in one of these instructions",
+        "may be upgraded between executions and potentially leave one of the",
+        "necessary accounts in an invalid state."
+      ],
+      "accounts": [
+        {
+          "name": "multisig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transaction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "instruction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "member",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    }
+  ],
+  "accounts": [
+    {
+      "name": "ms",
+      "docs": [
+        "Ms is the basic state account for a multisig."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "threshold",
+            "type": "u16"
+          },
+          {
+            "name": "authorityIndex",
+            "type": "u16"
+          },
+          {
+            "name": "transactionIndex",
+            "type": "u32"
+          },
+          {
+            "name": "msChangeIndex",
+            "type": "u32"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "createKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "migrated",
+            "type": "bool"
+          },
+          {
+            "name": "keys",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "timeLock",
+            "type": "u32"
+          },
+          {
+            "name": "defaultTtl",
+            "type": "u32"
+          },
+          {
+            "name": "memberWeights",
+            "type": {
+              "vec": {
+                "defined": "MemberWeight"
+              }
+            }
+          },
+          {
+            "name": "authorityPolicyCount",
+            "type": "u16"
+          },
+          {
+            "name": "memberPermissions",
+            "type": {
+              "vec": {
+                "defined": "MemberPermissions"
+              }
+            }
+          },
+          {
+            "name": "recovery",
+            "type": {
+              "option": {
+                "defined": "RecoveryConfig"
+              }
+            }
+          },
+          {
+            "name": "rejectThreshold",
+            "type": "u16"
+          },
+          {
+            "name": "programPolicyCount",
+            "type": "u16"
+          },
+          {
+            "name": "disabledAuthorityCount",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "msMetadata",
+      "docs": [
+        "The MsMetadata stores the metadata (ie. name and description) of a multisig,",
+        "passed when creating it and updated with internal transactions."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "ms",
+            "type": "publicKey"
+          },
+          {
+            "name": "meta",
+            "type": "string"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "authorityPolicy",
+      "docs": [
+        "The AuthorityPolicy overrides the multisig threshold for transactions",
+        "that use the authority (vault) it was created for."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "ms",
+            "type": "publicKey"
+          },
+          {
+            "name": "authorityIndex",
+            "type": "u32"
+          },
+          {
+            "name": "threshold",
+            "type": "u16"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "authority",
+      "docs": [
+        "The Authority account describes an authority (vault) of the multisig,",
+        "seeded from [b\"squad\", multisig, authority_index, b\"authority_info\"]."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "ms",
+            "type": "publicKey"
+          },
+          {
+            "name": "authorityIndex",
+            "type": "u32"
+          },
+          {
+            "name": "label",
+            "type": "string"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "enabled",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "programPolicy",
+      "docs": [
+        "The ProgramPolicy restricts the programs that instructions of transactions using the",
+        "authority (vault) it was created for can invoke, to an allowlist or with a denylist."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "ms",
+            "type": "publicKey"
+          },
+          {
+            "name": "authorityIndex",
+            "type": "u32"
+          },
+          {
+            "name": "mode",
+            "type": {
+              "defined": "ProgramPolicyMode"
+            }
+          },
+          {
+            "name": "programs",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "spendingLimit",
+      "docs": [
+        "The SpendingLimit allows a set of members to transfer up to an amount of SOL or",
+        "tokens from an authority (vault) per period, without creating a transaction."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "ms",
+            "type": "publicKey"
+          },
+          {
+            "name": "authorityIndex",
+            "type": "u32"
+          },
+          {
+            "name": "authorityBump",
+            "type": "u8"
+          },
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "period",
+            "type": "i64"
+          },
+          {
+            "name": "remainingAmount",
+            "type": "u64"
+          },
+          {
+            "name": "lastReset",
+            "type": "i64"
+          },
+          {
+            "name": "members",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "recoveryProposal",
+      "docs": [
+        "The RecoveryProposal holds a replacement member set proposed by guardians, and is",
+        "seeded from [b\"squad\", multisig, b\"recovery\"]. Only one recovery can be proposed at a time."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "ms",
+            "type": "publicKey"
+          },
+          {
+            "name": "proposer",
+            "type": "publicKey"
+          },
+          {
+            "name": "newMembers",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "newThreshold",
+            "type": "u16"
+          },
+          {
+            "name": "approvals",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "readyAt",
+            "type": "i64"
+          },
+          {
+            "name": "configIndex",
+            "type": "u32"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "msTransaction",
+      "docs": [
+        "The MsTransaction is the state account for a multisig transaction"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "publicKey"
+          },
+          {
+            "name": "ms",
+            "type": "publicKey"
+          },
+          {
+            "name": "transactionIndex",
+            "type": "u32"
+          },
+          {
+            "name": "authorityIndex",
+            "type": "u32"
+          },
+          {
+            "name": "authorityBump",
+            "type": "u8"
+          },
+          {
+            "name": "status",
+            "type": {
+              "defined": "MsTransactionStatus"
+            }
+          },
+          {
+            "name": "instructionIndex",
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "approved",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "rejected",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "cancelled",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "executedIndex",
+            "type": "u8"
+          },
+          {
+            "name": "readyAt",
+            "type": "i64"
+          },
+          {
+            "name": "expiresAt",
+            "type": "i64"
+          },
+          {
+            "name": "ephemeralSigners",
+            "type": "u8"
+          },
+          {
+            "name": "abstained",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "voteChanges",
+            "type": {
+              "vec": {
+                "defined": "VoteChange"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "msInstruction",
+      "docs": [
+        "The state account for an instruction that is attached to a transaction.",
+        "Almost analagous to the native Instruction struct for solana, but with an extra",
+        "field for the bump."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "programId",
+            "type": "publicKey"
+          },
+          {
+            "name": "keys",
+            "type": {
+              "vec": {
+                "defined": "MsAccountMeta"
+              }
+            }
+          },
+          {
+            "name": "data",
+            "type": "bytes"
+          },
+          {
+            "name": "instructionIndex",
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "executed",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "msInstructionBatch",
+      "docs": [
+        "The state account for several consecutive instructions that are attached to a",
+        "transaction at once. Uses the PDA of the first instruction index it holds."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "instructionIndex",
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "instructions",
+            "type": {
+              "vec": {
+                "defined": "IncomingInstruction"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "msInstructionBuffer",
+      "docs": [
+        "The state account used to upload an instruction that is too large to be attached",
+        "with add_instruction in a single transaction. The account is sized for the full",
+        "instruction data when it's created, the data is then appended over several transactions,",
+        "and the account is turned into an MsInstruction by finalize_instruction.",
+        "Seeded from [b\"squad\", transaction, b\"instruction_buffer\"]."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "programId",
+            "type": "publicKey"
+          },
+          {
+            "name": "keys",
+            "type": {
+              "vec": {
+                "defined": "MsAccountMeta"
+              }
+            }
+          },
+          {
+            "name": "dataLen",
+            "type": "u32"
+          },
+          {
+            "name": "data",
+            "type": "bytes"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "msLookupInstruction",
+      "docs": [
+        "The state account for an instruction whose accounts are resolved from address",
+        "lookup tables controlled by the transaction authority when it's executed."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "programId",
+            "type": "publicKey"
+          },
+          {
+            "name": "lookupTables",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "keys",
+            "type": {
+              "vec": {
+                "defined": "MsLookupAccountMeta"
+              }
+            }
+          },
+          {
+            "name": "data",
+            "type": "bytes"
+          },
+          {
+            "name": "instructionIndex",
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "MemberWeight",
+      "docs": [
+        "The voting weight of a multisig member."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "key",
+            "type": "publicKey"
+          },
+          {
+            "name": "weight",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RecoveryConfig",
+      "docs": [
+        "The recovery configuration of a multisig. If enough guardians approve",
+        "a recovery proposal, and no member objects within the delay, the members",
+        "of the multisig are replaced."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "guardians",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "threshold",
+            "type": "u16"
+          },
+          {
+            "name": "delay",
+            "type": "u32"
+          },
+          {
+            "name": "changeIndex",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MemberPermissions",
+      "docs": [
+        "The permissions mask of a multisig member."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "key",
+            "type": "publicKey"
+          },
+          {
+            "name": "mask",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LegacyMs",
+      "docs": [
+        "The Ms layout prior to the trailing settings fields (time lock, etc.).",
+        "Multisigs created with this layout may not have the space needed to",
+        "deserialize into the current Ms, or may have stale bytes after the keys",
+        "left by removed members, and are read with this struct when migrating them."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "threshold",
+            "type": "u16"
+          },
+          {
+            "name": "authorityIndex",
+            "type": "u16"
+          },
+          {
+            "name": "transactionIndex",
+            "type": "u32"
+          },
+          {
+            "name": "msChangeIndex",
+            "type": "u32"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "createKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "migrated",
+            "type": "bool"
+          },
+          {
+            "name": "keys",
+            "type": {
+              "vec": "publicKey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "VoteChange",
+      "docs": [
+        "A change of a member's vote on a transaction, kept for auditing."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "member",
+            "type": "publicKey"
+          },
+          {
+            "name": "oldVote",
+            "type": {
+              "defined": "Vote"
+            }
+          },
+          {
+            "name": "newVote",
+            "type": {
+              "defined": "Vote"
+            }
+          },
+          {
+            "name": "slot",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MsLookupAccountMeta",
+      "docs": [
+        "Account meta for a MsLookupInstruction, referencing an address in one of its lookup tables"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tableIndex",
+            "type": "u8"
+          },
+          {
+            "name": "addressIndex",
+            "type": "u8"
+          },
+          {
+            "name": "isSigner",
+            "type": "bool"
+          },
+          {
+            "name": "isWritable",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "IncomingLookupInstruction",
+      "docs": [
+        "Incoming lookup instruction schema, used as an argument in add_lookup_instruction."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "programId",
+            "type": "publicKey"
+          },
+          {
+            "name": "lookupTables",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "keys",
+            "type": {
+              "vec": {
+                "defined": "MsLookupAccountMeta"
+              }
+            }
+          },
+          {
+            "name": "data",
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MsAccountMeta",
+      "docs": [
+        "Wrapper for our internal MsInstruction key serialization schema",
+        "MsAccount meta is identical to the AccountMeta struct, but defined",
+        "here for serialization purposes."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pubkey",
+            "type": "publicKey"
+          },
+          {
+            "name": "isSigner",
+            "type": "bool"
+          },
+          {
+            "name": "isWritable",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ExecutionAccounts",
+      "docs": [
+        "The accounts needed to execute a transaction, returned by get_execution_accounts.",
+        "The accounts are deduplicated and are passed as the remaining accounts of",
+        "execute_transaction, along with the account_list, which indexes into them."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "accounts",
+            "type": {
+              "vec": {
+                "defined": "MsAccountMeta"
+              }
+            }
+          },
+          {
+            "name": "accountList",
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ExecutionAccountsPage",
+      "docs": [
+        "A page of the ExecutionAccounts returned by get_execution_accounts. The return data",
+        "of an instruction is limited to 1024 bytes, so the accounts are fetched in pages by",
+        "passing the offset of the next page until all total_accounts have been returned."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "accounts",
+            "type": {
+              "vec": {
+                "defined": "MsAccountMeta"
+              }
+            }
+          },
+          {
+            "name": "accountList",
+            "type": "bytes"
+          },
+          {
+            "name": "totalAccounts",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "IncomingInstruction",
+      "docs": [
+        "Incoming instruction schema, used as an argument in the attach_instruction.",
+        "Identical to the solana struct for Instruction, but uses the MsAccountMeta.",
+        "Provided for de/serialization purposes."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "programId",
+            "type": "publicKey"
+          },
+          {
+            "name": "keys",
+            "type": {
+              "vec": {
+                "defined": "MsAccountMeta"
+              }
+            }
+          },
+          {
+            "name": "data",
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ProgramPolicyMode",
+      "docs": [
+        "Whether the programs of a ProgramPolicy are the only ones allowed, or the ones denied"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Allow"
+          },
+          {
+            "name": "Deny"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Vote",
+      "docs": [
+        "A vote of a member on a transaction."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Approve"
+          },
+          {
+            "name": "Reject"
+          },
+          {
+            "name": "Abstain"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MsTransactionStatus",
+      "docs": [
+        "MsTransactionStatus enum of the current status of the Multisig Transaction."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Draft"
+          },
+          {
+            "name": "Active"
+          },
+          {
+            "name": "ExecuteReady"
+          },
+          {
+            "name": "Executed"
+          },
+          {
+            "name": "Rejected"
+          },
+          {
+            "name": "Cancelled"
+          },
+          {
+            "name": "Expired"
+          }
+        ]
+      }
+    }
+  ],
+  "events": [
+    {
+      "name": "MultisigCreated",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "threshold",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "members",
+          "type": {
+            "vec": "publicKey"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MemberAdded",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "member",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "threshold",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "changeIndex",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MemberRemoved",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "member",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "threshold",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "changeIndex",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MemberKeyRotated",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "oldMember",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "newMember",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MemberWeightChanged",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "member",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "weight",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "changeIndex",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MemberPermissionsChanged",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "member",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "permissions",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "changeIndex",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RecoveryConfigSet",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "guardians",
+          "type": {
+            "vec": "publicKey"
+          },
+          "index": false
+        },
+        {
+          "name": "threshold",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "delay",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "changeIndex",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RecoveryConfigRemoved",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "changeIndex",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RecoveryProposed",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "guardian",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "newMembers",
+          "type": {
+            "vec": "publicKey"
+          },
+          "index": false
+        },
+        {
+          "name": "newThreshold",
+          "type": "u16",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RecoveryApproved",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "guardian",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "readyAt",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RecoveryCancelled",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "member",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RecoveryExecuted",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "newMembers",
+          "type": {
+            "vec": "publicKey"
+          },
+          "index": false
+        },
+        {
+          "name": "threshold",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "changeIndex",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ThresholdChanged",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "threshold",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "changeIndex",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RejectThresholdChanged",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "rejectThreshold",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "changeIndex",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TimeLockChanged",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "timeLock",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "changeIndex",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "DefaultTtlChanged",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "defaultTtl",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "changeIndex",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AuthorityAdded",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "authorityIndex",
+          "type": "u16",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AuthorityUpdated",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "authorityIndex",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "label",
+          "type": "string",
+          "index": false
+        },
+        {
+          "name": "enabled",
+          "type": "bool",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AuthorityPolicyUpdated",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "authorityIndex",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "threshold",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "changeIndex",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AuthorityPolicyRemoved",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "authorityIndex",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "changeIndex",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ProgramPolicyUpdated",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "authorityIndex",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "mode",
+          "type": {
+            "defined": "ProgramPolicyMode"
+          },
+          "index": false
+        },
+        {
+          "name": "programs",
+          "type": {
+            "vec": "publicKey"
+          },
+          "index": false
+        },
+        {
+          "name": "changeIndex",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ProgramPolicyRemoved",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "authorityIndex",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "changeIndex",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SpendingLimitAdded",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "spendingLimit",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "authorityIndex",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "period",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "members",
+          "type": {
+            "vec": "publicKey"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SpendingLimitRemoved",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "spendingLimit",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "authorityIndex",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SpendingLimitUsed",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "spendingLimit",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "member",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "destination",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "remainingAmount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TransactionCreated",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transactionIndex",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "authorityIndex",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "status",
+          "type": {
+            "defined": "MsTransactionStatus"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TransactionActivated",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transactionIndex",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "status",
+          "type": {
+            "defined": "MsTransactionStatus"
           },
-          {
-            "name": "isWritable",
-            "type": "bool"
-          }
-        ]
-      }
+          "index": false
+        }
+      ]
     },
     {
-      "name": "IncomingInstruction",
-      "docs": [
-        "Incoming instruction schema, used as an argument in the attach_instruction.",
-        "Identical to the solana struct for Instruction, but uses the MsAccountMeta.",
-        "Provided for de/serialization purposes."
-      ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "programId",
-            "type": "publicKey"
+      "name": "TransactionApproved",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transactionIndex",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "member",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "status",
+          "type": {
+            "defined": "MsTransactionStatus"
           },
-          {
-            "name": "keys",
-            "type": {
-              "vec": {
-                "defined": "MsAccountMeta"
-              }
-            }
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TransactionRejected",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transactionIndex",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "member",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "status",
+          "type": {
+            "defined": "MsTransactionStatus"
           },
-          {
-            "name": "data",
-            "type": "bytes"
-          }
-        ]
-      }
+          "index": false
+        }
+      ]
     },
     {
-      "name": "MsTransactionStatus",
-      "docs": [
-        "MsTransactionStatus enum of the current status of the Multisig Transaction."
-      ],
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "Draft"
+      "name": "TransactionAbstained",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transactionIndex",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "member",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "status",
+          "type": {
+            "defined": "MsTransactionStatus"
           },
-          {
-            "name": "Active"
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TransactionCancelled",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transactionIndex",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "member",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "status",
+          "type": {
+            "defined": "MsTransactionStatus"
           },
-          {
-            "name": "ExecuteReady"
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TransactionWithdrawn",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transactionIndex",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "closed",
+          "type": "bool",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TransactionExpired",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transactionIndex",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "status",
+          "type": {
+            "defined": "MsTransactionStatus"
           },
-          {
-            "name": "Executed"
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TransactionExecuted",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transactionIndex",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "member",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "status",
+          "type": {
+            "defined": "MsTransactionStatus"
           },
-          {
-            "name": "Rejected"
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "InstructionExecuted",
+      "fields": [
+        {
+          "name": "multisig",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transaction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "transactionIndex",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "instructionIndex",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "member",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "status",
+          "type": {
+            "defined": "MsTransactionStatus"
           },
-          {
-            "name": "Cancelled"
-          }
-        ]
-      }
+          "index": false
+        }
+      ]
     }
   ],
   "errors": [
@@ -1508,6 +8022,122 @@ export const IDL: SquadsMpl = {
     {
       "code": 6013,
       "name": "NotEnoughLamports"
+    },
+    {
+      "code": 6014,
+      "name": "TimeLockNotReleased"
+    },
+    {
+      "code": 6015,
+      "name": "TransactionExpired"
+    },
+    {
+      "code": 6016,
+      "name": "TransactionNotExpired"
+    },
+    {
+      "code": 6017,
+      "name": "InvalidExpiry"
+    },
+    {
+      "code": 6018,
+      "name": "InvalidMemberWeight"
+    },
+    {
+      "code": 6019,
+      "name": "InvalidAuthorityPolicy"
+    },
+    {
+      "code": 6020,
+      "name": "InvalidSpendingLimit"
+    },
+    {
+      "code": 6021,
+      "name": "SpendingLimitExceeded"
+    },
+    {
+      "code": 6022,
+      "name": "KeyNotInSpendingLimit"
+    },
+    {
+      "code": 6023,
+      "name": "MaxInstructionsReached"
+    },
+    {
+      "code": 6024,
+      "name": "InvalidLookupTable"
+    },
+    {
+      "code": 6025,
+      "name": "InvalidPermissions"
+    },
+    {
+      "code": 6026,
+      "name": "MissingPermission"
+    },
+    {
+      "code": 6027,
+      "name": "MetadataTooLong"
+    },
+    {
+      "code": 6028,
+      "name": "KeyAlreadyInMultisig"
+    },
+    {
+      "code": 6029,
+      "name": "InvalidRecoveryConfig"
+    },
+    {
+      "code": 6030,
+      "name": "KeyNotGuardian"
+    },
+    {
+      "code": 6031,
+      "name": "RecoveryNotReady"
+    },
+    {
+      "code": 6032,
+      "name": "TransactionAlreadyApproved"
+    },
+    {
+      "code": 6033,
+      "name": "InstructionBufferOverflow"
+    },
+    {
+      "code": 6034,
+      "name": "InstructionBufferIncomplete"
+    },
+    {
+      "code": 6035,
+      "name": "InstructionNotAllowed"
+    },
+    {
+      "code": 6036,
+      "name": "InvalidProgramPolicy"
+    },
+    {
+      "code": 6037,
+      "name": "ProgramNotAllowed"
+    },
+    {
+      "code": 6038,
+      "name": "InvalidAuthorityLabel"
+    },
+    {
+      "code": 6039,
+      "name": "AuthorityDisabled"
+    },
+    {
+      "code": 6040,
+      "name": "TooManyEphemeralSigners"
+    },
+    {
+      "code": 6041,
+      "name": "RecoveryConfigChanged"
+    },
+    {
+      "code": 6042,
+      "name": "MultisigNotMigrated"
     }
   ]
 };
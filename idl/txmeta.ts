@@ -4,6 +4,11 @@ export type Txmeta = {
   "instructions": [
     {
       "name": "trackMeta",
+      "docs": [
+        "Logs the meta string and remaining account keys for a transaction.",
+        "The logs are only available as long as the RPC retains them, prefer",
+        "create_transaction_meta to store the description on-chain."
+      ],
       "accounts": [
         {
           "name": "member",
@@ -27,6 +32,163 @@ export type Txmeta = {
           "type": "string"
         }
       ]
+    },
+    {
+      "name": "createTransactionMeta",
+      "docs": [
+        "Creates the TransactionMeta account for a [squads_mpl::state::MsTransaction],",
+        "holding its title, description and links. Only the transaction creator can",
+        "create it, while the transaction is in a Draft or Active state."
+      ],
+      "accounts": [
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "transaction",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transactionMeta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "title",
+          "type": "string"
+        },
+        {
+          "name": "description",
+          "type": "string"
+        },
+        {
+          "name": "links",
+          "type": {
+            "vec": "string"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateTransactionMeta",
+      "docs": [
+        "Updates the title, description and links of a TransactionMeta account.",
+        "Only the transaction creator can update it, while the transaction is in a",
+        "Draft or Active state. The account is resized to fit the new content."
+      ],
+      "accounts": [
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "transaction",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transactionMeta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "title",
+          "type": "string"
+        },
+        {
+          "name": "description",
+          "type": "string"
+        },
+        {
+          "name": "links",
+          "type": {
+            "vec": "string"
+          }
+        }
+      ]
+    },
+    {
+      "name": "closeTransactionMeta",
+      "docs": [
+        "Closes a TransactionMeta account once its transaction is finalized (executed,",
+        "rejected, cancelled or expired) or has been closed, returning the rent to the creator."
+      ],
+      "accounts": [
+        {
+          "name": "transaction",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transactionMeta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    }
+  ],
+  "accounts": [
+    {
+      "name": "transactionMeta",
+      "docs": [
+        "The TransactionMeta describes a [squads_mpl::state::MsTransaction], and is",
+        "seeded from [b\"squad\", transaction, b\"transaction_meta\"]."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "transaction",
+            "type": "publicKey"
+          },
+          {
+            "name": "creator",
+            "type": "publicKey"
+          },
+          {
+            "name": "title",
+            "type": "string"
+          },
+          {
+            "name": "description",
+            "type": "string"
+          },
+          {
+            "name": "links",
+            "type": {
+              "vec": "string"
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
     }
   ],
   "errors": [
@@ -44,6 +206,16 @@ export type Txmeta = {
       "code": 6002,
       "name": "InvalidTransaction",
       "msg": "The transaction is either not associated with the supplied multisig or it's creator is not the supplied signer"
+    },
+    {
+      "code": 6003,
+      "name": "InvalidTransactionState",
+      "msg": "The transaction is not in a state that allows this action."
+    },
+    {
+      "code": 6004,
+      "name": "MetaTooLong",
+      "msg": "The title, description or links exceed the size limits."
     }
   ]
 };
@@ -54,6 +226,11 @@ export const IDL: Txmeta = {
   "instructions": [
     {
       "name": "trackMeta",
+      "docs": [
+        "Logs the meta string and remaining account keys for a transaction.",
+        "The logs are only available as long as the RPC retains them, prefer",
+        "create_transaction_meta to store the description on-chain."
+      ],
       "accounts": [
         {
           "name": "member",
@@ -77,6 +254,163 @@ export const IDL: Txmeta = {
           "type": "string"
         }
       ]
+    },
+    {
+      "name": "createTransactionMeta",
+      "docs": [
+        "Creates the TransactionMeta account for a [squads_mpl::state::MsTransaction],",
+        "holding its title, description and links. Only the transaction creator can",
+        "create it, while the transaction is in a Draft or Active state."
+      ],
+      "accounts": [
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "transaction",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transactionMeta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "title",
+          "type": "string"
+        },
+        {
+          "name": "description",
+          "type": "string"
+        },
+        {
+          "name": "links",
+          "type": {
+            "vec": "string"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateTransactionMeta",
+      "docs": [
+        "Updates the title, description and links of a TransactionMeta account.",
+        "Only the transaction creator can update it, while the transaction is in a",
+        "Draft or Active state. The account is resized to fit the new content."
+      ],
+      "accounts": [
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "transaction",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transactionMeta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "title",
+          "type": "string"
+        },
+        {
+          "name": "description",
+          "type": "string"
+        },
+        {
+          "name": "links",
+          "type": {
+            "vec": "string"
+          }
+        }
+      ]
+    },
+    {
+      "name": "closeTransactionMeta",
+      "docs": [
+        "Closes a TransactionMeta account once its transaction is finalized (executed,",
+        "rejected, cancelled or expired) or has been closed, returning the rent to the creator."
+      ],
+      "accounts": [
+        {
+          "name": "transaction",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transactionMeta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    }
+  ],
+  "accounts": [
+    {
+      "name": "transactionMeta",
+      "docs": [
+        "The TransactionMeta describes a [squads_mpl::state::MsTransaction], and is",
+        "seeded from [b\"squad\", transaction, b\"transaction_meta\"]."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "transaction",
+            "type": "publicKey"
+          },
+          {
+            "name": "creator",
+            "type": "publicKey"
+          },
+          {
+            "name": "title",
+            "type": "string"
+          },
+          {
+            "name": "description",
+            "type": "string"
+          },
+          {
+            "name": "links",
+            "type": {
+              "vec": "string"
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
     }
   ],
   "errors": [
@@ -94,6 +428,16 @@ export const IDL: Txmeta = {
       "code": 6002,
       "name": "InvalidTransaction",
       "msg": "The transaction is either not associated with the supplied multisig or it's creator is not the supplied signer"
+    },
+    {
+      "code": 6003,
+      "name": "InvalidTransactionState",
+      "msg": "The transaction is not in a state that allows this action."
+    },
+    {
+      "code": 6004,
+      "name": "MetaTooLong",
+      "msg": "The title, description or links exceed the size limits."
     }
   ]
 };
@@ -4,20 +4,22 @@
 */
 
 use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
 use crate::state::*;
 use crate::errors::*;
 
 /// The create multisig account context
 /// Expects the following accounts:
 /// 1. multisig account
-/// 2. creator account [signer]
-/// 3. system program
+/// 2. metadata account
+/// 3. creator account [signer]
+/// 4. system program
 /// 
 /// Expects the following arguments:
 /// 1. threshold: u16
 /// 2. create_key: Pubkey
 /// 3. members: Vec<Pubkey>
-/// 4. meta: String (for optional on-chain memo)
+/// 4. meta: String (stored in the metadata account)
 #[derive(Accounts)]
 #[instruction(threshold: u16, create_key: Pubkey, members: Vec<Pubkey>, meta: String)]
 pub struct Create<'info> {
@@ -29,13 +31,21 @@ pub struct Create<'info> {
     )]
     pub multisig: Account<'info, Ms>,
 
+    #[account(
+        init,
+        payer = creator,
+        space = MsMetadata::size(&meta),
+        seeds = [b"squad", multisig.key().as_ref(), b"metadata"], bump
+    )]
+    pub metadata: Account<'info, MsMetadata>,
+
     #[account(mut)]
     pub creator: Signer<'info>,
     pub system_program: Program<'info, System>,
 }
 
 /// The account context for creating a new multisig transaction
-/// Upon fresh creation the transaction will be in a Draft state
+/// Upon fresh creation the transaction will be in a Draft state, and the creator must have the Initiate permission
 /// 
 /// Expects the following accounts:
 /// 1. multisig account
@@ -52,6 +62,7 @@ pub struct CreateTransaction<'info> {
             b"multisig"
         ],
         bump = multisig.bump,
+        constraint = multisig.migrated @MsError::MultisigNotMigrated,
     )]
     pub multisig: Account<'info, Ms>,
 
@@ -71,6 +82,7 @@ pub struct CreateTransaction<'info> {
     #[account(
         mut,
         constraint = multisig.is_member(creator.key()).is_some() @MsError::KeyNotInMultisig,
+        constraint = multisig.has_permission(creator.key(), MemberPermissions::INITIATE) @MsError::MissingPermission,
     )]
     pub creator: Signer<'info>,
     pub system_program: Program<'info, System>,
@@ -98,6 +110,7 @@ pub struct AddInstruction<'info> {
             b"multisig"
         ],
         bump = multisig.bump,
+        constraint = multisig.migrated @MsError::MultisigNotMigrated,
     )]
     pub multisig: Account<'info, Ms>,
 
@@ -137,16 +150,23 @@ pub struct AddInstruction<'info> {
     pub system_program: Program<'info, System>,
 }
 
-/// The account context for activating a transaction
+/// The account context for creating a buffer to upload a large instruction
 /// The transaction must be in a Draft state, and the creator must be a member of the multisig
 /// 
 /// Expects the following accounts:
 /// 1. multisig account
 /// 2. transaction account
-/// 3. creator account [signer]
+/// 3. instruction buffer account
+/// 4. creator account [signer]
+/// 5. system program
 /// 
+/// Expects the following arguments:
+/// 1. instruction_program_id: Pubkey
+/// 2. keys: Vec<MsAccountMeta>
+/// 3. data_len: u32
 #[derive(Accounts)]
-pub struct ActivateTransaction<'info> {
+#[instruction(instruction_program_id: Pubkey, keys: Vec<MsAccountMeta>, data_len: u32)]
+pub struct CreateInstructionBuffer<'info> {
     #[account(
         seeds = [
             b"squad",
@@ -154,11 +174,11 @@ pub struct ActivateTransaction<'info> {
             b"multisig"
         ],
         bump = multisig.bump,
+        constraint = multisig.migrated @MsError::MultisigNotMigrated,
     )]
     pub multisig: Account<'info, Ms>,
 
     #[account(
-        mut,
         seeds = [
             b"squad",
             multisig.key().as_ref(),
@@ -167,29 +187,42 @@ pub struct ActivateTransaction<'info> {
         ], bump = transaction.bump,
         constraint = transaction.creator == creator.key(),
         constraint = transaction.status == MsTransactionStatus::Draft @MsError::InvalidTransactionState,
-        constraint = transaction.transaction_index > multisig.ms_change_index @MsError::DeprecatedTransaction,
         constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
     )]
     pub transaction: Account<'info, MsTransaction>,
 
+    #[account(
+        init,
+        payer = creator,
+        space = MsInstructionBuffer::size(&keys, data_len),
+        seeds = [
+            b"squad",
+            transaction.key().as_ref(),
+            b"instruction_buffer"
+        ],
+        bump
+    )]
+    pub instruction_buffer: Account<'info, MsInstructionBuffer>,
+
     #[account(
         mut,
         constraint = multisig.is_member(creator.key()).is_some() @MsError::KeyNotInMultisig,
     )]
     pub creator: Signer<'info>,
-    // pub system_program: Program<'info, System>,
+    pub system_program: Program<'info, System>,
 }
 
-/// The account context for voting on a transaction
-/// The transaction must be in an Active state, and the voter must be a member of the multisig
+/// The account context for appending data to an instruction buffer
+/// The transaction must be in a Draft state, and the creator must be a member of the multisig
 /// 
 /// Expects the following accounts:
 /// 1. multisig account
 /// 2. transaction account
-/// 3. voter account [signer]
+/// 3. instruction buffer account
+/// 4. creator account [signer]
 /// 
 #[derive(Accounts)]
-pub struct VoteTransaction<'info> {
+pub struct AppendInstructionData<'info> {
     #[account(
         seeds = [
             b"squad",
@@ -197,49 +230,63 @@ pub struct VoteTransaction<'info> {
             b"multisig"
         ],
         bump = multisig.bump,
+        constraint = multisig.migrated @MsError::MultisigNotMigrated,
     )]
     pub multisig: Account<'info, Ms>,
 
     #[account(
-        mut,
         seeds = [
             b"squad",
             multisig.key().as_ref(),
             &transaction.transaction_index.to_le_bytes(),
             b"transaction"
         ], bump = transaction.bump,
-        constraint = transaction.status == MsTransactionStatus::Active @MsError::InvalidTransactionState,
-        constraint = transaction.transaction_index > multisig.ms_change_index @MsError::DeprecatedTransaction,
+        constraint = transaction.creator == creator.key(),
+        constraint = transaction.status == MsTransactionStatus::Draft @MsError::InvalidTransactionState,
         constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
     )]
     pub transaction: Account<'info, MsTransaction>,
 
     #[account(
         mut,
-        constraint = multisig.is_member(member.key()).is_some() @MsError::KeyNotInMultisig,
+        seeds = [
+            b"squad",
+            transaction.key().as_ref(),
+            b"instruction_buffer"
+        ],
+        bump = instruction_buffer.bump,
     )]
-    pub member: Signer<'info>,
-    // pub system_program: Program<'info, System>,
+    pub instruction_buffer: Account<'info, MsInstructionBuffer>,
+
+    #[account(
+        mut,
+        constraint = multisig.is_member(creator.key()).is_some() @MsError::KeyNotInMultisig,
+    )]
+    pub creator: Signer<'info>,
 }
 
-/// The account context for submitting a vote to cancel a transaction
-/// The transaction must be in an ExecuteReady state, and the voter must be a member of the multisig
+/// The account context for turning a complete instruction buffer into an instruction of the transaction
+/// The transaction must be in a Draft state, and the creator must be a member of the multisig
+/// The instruction buffer is closed, and its lamports returned to the creator.
 /// 
 /// Expects the following accounts:
 /// 1. multisig account
 /// 2. transaction account
-/// 3. member account [signer]
+/// 3. instruction buffer account
+/// 4. instruction account
+/// 5. creator account [signer]
+/// 6. system program
 /// 
 #[derive(Accounts)]
-pub struct CancelTransaction<'info> {
+pub struct FinalizeInstruction<'info> {
     #[account(
-        mut,
         seeds = [
             b"squad",
             multisig.create_key.as_ref(),
             b"multisig"
         ],
         bump = multisig.bump,
+        constraint = multisig.migrated @MsError::MultisigNotMigrated,
     )]
     pub multisig: Account<'info, Ms>,
 
@@ -251,82 +298,184 @@ pub struct CancelTransaction<'info> {
             &transaction.transaction_index.to_le_bytes(),
             b"transaction"
         ], bump = transaction.bump,
-        constraint = transaction.status == MsTransactionStatus::ExecuteReady @MsError::InvalidTransactionState,
+        constraint = transaction.creator == creator.key(),
+        constraint = transaction.status == MsTransactionStatus::Draft @MsError::InvalidTransactionState,
         constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
     )]
     pub transaction: Account<'info, MsTransaction>,
 
     #[account(
         mut,
-        constraint = multisig.is_member(member.key()).is_some() @MsError::KeyNotInMultisig,
+        seeds = [
+            b"squad",
+            transaction.key().as_ref(),
+            b"instruction_buffer"
+        ],
+        bump = instruction_buffer.bump,
+        constraint = instruction_buffer.is_complete() @MsError::InstructionBufferIncomplete,
+        close = creator,
     )]
-    pub member: Signer<'info>,
+    pub instruction_buffer: Account<'info, MsInstructionBuffer>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + instruction_buffer.to_incoming_instruction().get_max_size(),
+        seeds = [
+            b"squad",
+            transaction.key().as_ref(),
+            &transaction.instruction_index.checked_add(1).unwrap().to_le_bytes(),
+            b"instruction"
+        ],
+        bump
+    )]
+    pub instruction: Account<'info, MsInstruction>,
+
+    #[account(
+        mut,
+        constraint = multisig.is_member(creator.key()).is_some() @MsError::KeyNotInMultisig,
+    )]
+    pub creator: Signer<'info>,
     pub system_program: Program<'info, System>,
 }
 
-/// The account context for executing a transaction
-/// The transaction must be in an ExecuteReady state, and the creator must be a member of the multisig
+/// The account context for closing an instruction buffer that won't be finalized
+/// Only the creator of the transaction can close the buffer, which returns its lamports to them.
 /// 
 /// Expects the following accounts:
 /// 1. multisig account
 /// 2. transaction account
-/// 3. member account [signer]
+/// 3. instruction buffer account
+/// 4. creator account [signer]
 /// 
 #[derive(Accounts)]
-pub struct ExecuteTransaction<'info> {
+pub struct CloseInstructionBuffer<'info> {
     #[account(
-        mut,
         seeds = [
             b"squad",
             multisig.create_key.as_ref(),
             b"multisig"
         ],
         bump = multisig.bump,
+        constraint = multisig.migrated @MsError::MultisigNotMigrated,
     )]
-    pub multisig: Box<Account<'info, Ms>>,
+    pub multisig: Account<'info, Ms>,
 
     #[account(
-        mut,
         seeds = [
             b"squad",
             multisig.key().as_ref(),
             &transaction.transaction_index.to_le_bytes(),
             b"transaction"
         ], bump = transaction.bump,
-        constraint = transaction.status == MsTransactionStatus::ExecuteReady @MsError::InvalidTransactionState,
+        constraint = transaction.creator == creator.key(),
         constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
-        // if they've already started sequential execution, they must continue
-        constraint = transaction.executed_index < 1 @MsError::PartialExecution,
     )]
     pub transaction: Account<'info, MsTransaction>,
 
     #[account(
         mut,
-        constraint = multisig.is_member(member.key()).is_some() @MsError::KeyNotInMultisig,
+        seeds = [
+            b"squad",
+            transaction.key().as_ref(),
+            b"instruction_buffer"
+        ],
+        bump = instruction_buffer.bump,
+        close = creator,
     )]
-    pub member: Signer<'info>,
+    pub instruction_buffer: Account<'info, MsInstructionBuffer>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
 }
 
-/// The account context for executing a transaction instruction individually
-/// The transaction must be in an ExecuteReady state, and the creator must be a member of the multisig, and the instruction must correlate to the next executed index
+/// The account context for replacing an instruction of a transaction
+/// The transaction must be in a Draft state, and the creator must be a member of the multisig
+/// The instruction account is resized to fit the new instruction, the creator pays or is refunded the difference in rent.
 /// 
 /// Expects the following accounts:
 /// 1. multisig account
 /// 2. transaction account
-/// 3. member account [signer]
+/// 3. instruction account
+/// 4. creator account [signer]
+/// 5. system program
 /// 
+/// Expects the following arguments:
+/// 1. instruction_data: IncomingInstruction
 #[derive(Accounts)]
-pub struct ExecuteInstruction<'info> {
+#[instruction(instruction_data: IncomingInstruction)]
+pub struct ReplaceInstruction<'info> {
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+        constraint = multisig.migrated @MsError::MultisigNotMigrated,
+    )]
+    pub multisig: Account<'info, Ms>,
+
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = transaction.creator == creator.key(),
+        constraint = transaction.status == MsTransactionStatus::Draft @MsError::InvalidTransactionState,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            transaction.key().as_ref(),
+            &instruction.instruction_index.to_le_bytes(),
+            b"instruction"
+        ], bump = instruction.bump,
+        realloc = 8 + instruction_data.get_max_size(),
+        realloc::payer = creator,
+        realloc::zero = false,
+    )]
+    pub instruction: Account<'info, MsInstruction>,
+
     #[account(
         mut,
+        constraint = multisig.is_member(creator.key()).is_some() @MsError::KeyNotInMultisig,
+    )]
+    pub creator: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for removing an instruction of a transaction
+/// The transaction must be in a Draft state, and the creator must be a member of the multisig
+/// The MsInstruction accounts after the removed one are passed in order as remaining accounts (writable),
+/// and each is moved down one index. The last instruction account is closed.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. transaction account
+/// 3. instruction account (the instruction to remove)
+/// 4. creator account [signer] (pays or is refunded the rent difference, and receives the lamports)
+/// 5. system program
+/// 
+#[derive(Accounts)]
+#[instruction(instruction_index: u8)]
+pub struct RemoveInstruction<'info> {
+    #[account(
         seeds = [
             b"squad",
             multisig.create_key.as_ref(),
             b"multisig"
         ],
         bump = multisig.bump,
+        constraint = multisig.migrated @MsError::MultisigNotMigrated,
     )]
-    pub multisig: Box<Account<'info, Ms>>,
+    pub multisig: Account<'info, Ms>,
 
     #[account(
         mut,
@@ -336,7 +485,8 @@ pub struct ExecuteInstruction<'info> {
             &transaction.transaction_index.to_le_bytes(),
             b"transaction"
         ], bump = transaction.bump,
-        constraint = transaction.status == MsTransactionStatus::ExecuteReady @MsError::InvalidTransactionState,
+        constraint = transaction.creator == creator.key(),
+        constraint = transaction.status == MsTransactionStatus::Draft @MsError::InvalidTransactionState,
         constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
     )]
     pub transaction: Account<'info, MsTransaction>,
@@ -346,60 +496,1564 @@ pub struct ExecuteInstruction<'info> {
         seeds = [
             b"squad",
             transaction.key().as_ref(),
-            &transaction.executed_index.checked_add(1).unwrap().to_le_bytes(),
+            &instruction_index.to_le_bytes(),
             b"instruction"
         ], bump = instruction.bump,
-        // it should be the next expected instruction account to be executed
-        constraint = instruction.instruction_index == transaction.executed_index.checked_add(1).unwrap() @MsError::InvalidInstructionAccount,
     )]
     pub instruction: Account<'info, MsInstruction>,
 
     #[account(
         mut,
-        constraint = multisig.is_member(member.key()).is_some() @MsError::KeyNotInMultisig,
+        constraint = multisig.is_member(creator.key()).is_some() @MsError::KeyNotInMultisig,
     )]
-    pub member: Signer<'info>,
+    pub creator: Signer<'info>,
+    pub system_program: Program<'info, System>,
 }
 
-/// The account context for executing an internal multisig transaction (which changes the multisig account)
+impl<'info> RemoveInstruction<'info> {
+    /// Resizes an instruction account to fit the instruction moved into it,
+    /// the creator pays or is refunded the difference in rent.
+    pub fn resize_instruction(
+        &self,
+        instruction_info: &AccountInfo<'info>,
+        instruction: &IncomingInstruction,
+    ) -> Result<()> {
+        let new_len = 8 + instruction.get_max_size();
+        let rent_exempt_lamports = Rent::get()?.minimum_balance(new_len);
+        let current_lamports = instruction_info.lamports();
+        if rent_exempt_lamports > current_lamports {
+            anchor_lang::system_program::transfer(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: self.creator.to_account_info(),
+                        to: instruction_info.clone(),
+                    },
+                ),
+                rent_exempt_lamports - current_lamports,
+            )?;
+        } else {
+            let refund = current_lamports - rent_exempt_lamports;
+            **instruction_info.try_borrow_mut_lamports()? -= refund;
+            **self.creator.to_account_info().try_borrow_mut_lamports()? += refund;
+        }
+        instruction_info.realloc(new_len, false)?;
+        Ok(())
+    }
+}
+
+/// The account context for adding several instructions to a transaction at once
+/// The transaction must be in a Draft state, and the creator must be a member of the multisig
 /// 
 /// Expects the following accounts:
-/// 1. multisig account [signer]
+/// 1. multisig account
+/// 2. transaction account
+/// 3. instruction batch account
+/// 4. creator account [signer]
+/// 5. system program
+/// 
+/// Expects the following arguments:
+/// 1. instructions: Vec<IncomingInstruction>
 #[derive(Accounts)]
-pub struct MsAuth<'info> {
+#[instruction(instructions: Vec<IncomingInstruction>)]
+pub struct AddInstructions<'info> {
     #[account(
-        mut,
         seeds = [
             b"squad",
             multisig.create_key.as_ref(),
             b"multisig"
-        ], bump = multisig.bump,
-        signer
+        ],
+        bump = multisig.bump,
+        constraint = multisig.migrated @MsError::MultisigNotMigrated,
     )]
-    pub multisig: Box<Account<'info, Ms>>,
+    pub multisig: Account<'info, Ms>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = transaction.creator == creator.key(),
+        constraint = transaction.status == MsTransactionStatus::Draft @MsError::InvalidTransactionState,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + MsInstructionBatch::get_max_size(&instructions),
+        seeds = [
+            b"squad",
+            transaction.key().as_ref(),
+            &transaction.instruction_index.checked_add(1).unwrap().to_le_bytes(),
+            b"instruction"
+        ],
+        bump
+    )]
+    pub instruction_batch: Account<'info, MsInstructionBatch>,
+
+    #[account(
+        mut,
+        constraint = multisig.is_member(creator.key()).is_some() @MsError::KeyNotInMultisig,
+    )]
+    pub creator: Signer<'info>,
+    pub system_program: Program<'info, System>,
 }
 
-/// The account context for reallocating the multisig account (for add member, where the size may need to be adjusted)
+/// The account context for adding an instruction that resolves its accounts from address lookup tables
+/// The transaction must be in a Draft state, and the creator must be a member of the multisig
 /// 
 /// Expects the following accounts:
-/// 1. multisig account [signer]
-/// 2. rent sysvar
-/// 3. system program
-/// 
+/// 1. multisig account
+/// 2. transaction account
+/// 3. lookup instruction account
+/// 4. creator account [signer]
+/// 5. system program
 /// 
+/// Expects the following arguments:
+/// 1. instruction_data: IncomingLookupInstruction
 #[derive(Accounts)]
-pub struct MsAuthRealloc<'info> {
+#[instruction(instruction_data: IncomingLookupInstruction)]
+pub struct AddLookupInstruction<'info> {
     #[account(
-        mut,
         seeds = [
             b"squad",
             multisig.create_key.as_ref(),
             b"multisig"
-        ], bump = multisig.bump,
-        signer
+        ],
+        bump = multisig.bump,
+        constraint = multisig.migrated @MsError::MultisigNotMigrated,
     )]
-    pub multisig: Box<Account<'info, Ms>>,
+    pub multisig: Account<'info, Ms>,
 
-    pub rent: Sysvar<'info, Rent>,
-    pub system_program: Program<'info, System>,
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = transaction.creator == creator.key(),
+        constraint = transaction.status == MsTransactionStatus::Draft @MsError::InvalidTransactionState,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + instruction_data.get_max_size(),
+        seeds = [
+            b"squad",
+            transaction.key().as_ref(),
+            &transaction.instruction_index.checked_add(1).unwrap().to_le_bytes(),
+            b"instruction"
+        ],
+        bump
+    )]
+    pub instruction: Account<'info, MsLookupInstruction>,
+
+    #[account(
+        mut,
+        constraint = multisig.is_member(creator.key()).is_some() @MsError::KeyNotInMultisig,
+    )]
+    pub creator: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for activating a transaction
+/// The transaction must be in a Draft state, and the creator must be a member of the multisig
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. transaction account
+/// 3. creator account [signer]
+/// 
+#[derive(Accounts)]
+pub struct ActivateTransaction<'info> {
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+        constraint = multisig.migrated @MsError::MultisigNotMigrated,
+    )]
+    pub multisig: Account<'info, Ms>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = transaction.creator == creator.key(),
+        constraint = transaction.status == MsTransactionStatus::Draft @MsError::InvalidTransactionState,
+        constraint = transaction.transaction_index > multisig.ms_change_index @MsError::DeprecatedTransaction,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    #[account(
+        mut,
+        constraint = multisig.is_member(creator.key()).is_some() @MsError::KeyNotInMultisig,
+    )]
+    pub creator: Signer<'info>,
+    // pub system_program: Program<'info, System>,
+}
+
+/// The account context for voting on a transaction
+/// The transaction must be in an Active state, and the voter must be a member of the multisig with the Vote permission
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. transaction account
+/// 3. voter account [signer]
+/// 
+/// If the multisig has any authority policies, the AuthorityPolicy PDA of the
+/// transaction authority must be passed as the first remaining account,
+/// even if that authority doesn't have a policy.
+/// 
+#[derive(Accounts)]
+pub struct VoteTransaction<'info> {
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+        constraint = multisig.migrated @MsError::MultisigNotMigrated,
+    )]
+    pub multisig: Account<'info, Ms>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = transaction.status == MsTransactionStatus::Active @MsError::InvalidTransactionState,
+        constraint = transaction.transaction_index > multisig.ms_change_index @MsError::DeprecatedTransaction,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    #[account(
+        mut,
+        constraint = multisig.is_member(member.key()).is_some() @MsError::KeyNotInMultisig,
+        constraint = multisig.has_permission(member.key(), MemberPermissions::VOTE) @MsError::MissingPermission,
+    )]
+    pub member: Signer<'info>,
+    // pub system_program: Program<'info, System>,
+}
+
+impl<'info> VoteTransaction<'info> {
+    /// Sets the vote of the member on the transaction, recording any change of vote
+    pub fn vote(&mut self, vote: Vote) -> Result<()> {
+        let available_space = self
+            .transaction
+            .to_account_info()
+            .data_len()
+            .saturating_sub(8);
+        let member = self.member.key();
+        self.transaction
+            .set_vote(member, vote, Clock::get()?.slot, available_space)
+    }
+
+    /// Rejects the transaction once the rejections reach the reject threshold of the multisig
+    /// (if set), or the members that haven't rejected or abstained can no longer reach the threshold.
+    pub fn reject_if_unreachable(&mut self, threshold: u16) -> Result<()> {
+        let rejected_weight = self.multisig.weight_of(&self.transaction.rejected);
+        // ie total weight 7, threshold 3, 3 rejected and 2 abstained = 2 remaining
+        let remaining_weight = self
+            .multisig
+            .total_weight()
+            .saturating_sub(rejected_weight)
+            .saturating_sub(self.multisig.weight_of(&self.transaction.abstained));
+        // the reject threshold is capped by what the members can reach
+        let reject_threshold = self.multisig.reject_threshold.min(self.multisig.max_threshold());
+        if remaining_weight < u32::from(threshold)
+            || (reject_threshold > 0 && rejected_weight >= u32::from(reject_threshold))
+        {
+            self.transaction.set_rejected()?;
+        }
+        Ok(())
+    }
+
+    /// Gets the threshold for the transaction, which is overridden by the
+    /// AuthorityPolicy of the transaction authority if it has one.
+    pub fn threshold(&self, remaining_accounts: &[AccountInfo<'info>]) -> Result<u16> {
+        AuthorityPolicy::threshold_for(
+            &self.multisig,
+            &self.multisig.key(),
+            self.transaction.authority_index,
+            remaining_accounts,
+        )
+    }
+}
+
+/// The account context for submitting a vote to cancel a transaction
+/// The transaction must be in an ExecuteReady state, and the voter must be a member of the multisig with the Vote permission
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. transaction account
+/// 3. member account [signer]
+/// 
+/// While the multisig has any AuthorityPolicy, the policy PDA of the transaction authority
+/// must be passed as a remaining account, as with the other votes.
+#[derive(Accounts)]
+pub struct CancelTransaction<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+        constraint = multisig.migrated @MsError::MultisigNotMigrated,
+    )]
+    pub multisig: Account<'info, Ms>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = transaction.status == MsTransactionStatus::ExecuteReady @MsError::InvalidTransactionState,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    #[account(
+        mut,
+        constraint = multisig.is_member(member.key()).is_some() @MsError::KeyNotInMultisig,
+        constraint = multisig.has_permission(member.key(), MemberPermissions::VOTE) @MsError::MissingPermission,
+    )]
+    pub member: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CancelTransaction<'info> {
+    /// Gets the threshold for cancelling the transaction, which is overridden by the
+    /// AuthorityPolicy of the transaction authority if it has one.
+    pub fn threshold(&self, remaining_accounts: &[AccountInfo<'info>]) -> Result<u16> {
+        AuthorityPolicy::threshold_for(
+            &self.multisig,
+            &self.multisig.key(),
+            self.transaction.authority_index,
+            remaining_accounts,
+        )
+    }
+}
+
+/// The account context for a member rotating their key
+/// Both the old and the new key must sign, and the new key can't already be a member of the multisig.
+/// Pending transactions of the multisig can be passed in as remaining accounts (writable)
+/// to carry the votes of the old key over to the new key, and spending limits of the
+/// multisig (writable) to replace the old key in their members.
+///
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. old member account [signer]
+/// 3. new member account [signer]
+#[derive(Accounts)]
+pub struct RotateMemberKey<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+        constraint = multisig.migrated @MsError::MultisigNotMigrated,
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        constraint = multisig.is_member(old_member.key()).is_some() @MsError::KeyNotInMultisig,
+    )]
+    pub old_member: Signer<'info>,
+
+    #[account(
+        constraint = multisig.is_member(new_member.key()).is_none() @MsError::KeyAlreadyInMultisig,
+    )]
+    pub new_member: Signer<'info>,
+}
+
+/// The account context for a guardian proposing a recovery of the multisig
+/// The guardian pays for the recovery proposal account, and approves it.
+///
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. recovery proposal account
+/// 3. guardian account [signer]
+/// 4. system program
+///
+/// Expects the following arguments:
+/// 1. new_members: Vec<Pubkey>
+/// 2. new_threshold: u16
+#[derive(Accounts)]
+#[instruction(new_members: Vec<Pubkey>, new_threshold: u16)]
+pub struct ProposeRecovery<'info> {
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+        constraint = multisig.migrated @MsError::MultisigNotMigrated,
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        init,
+        payer = guardian,
+        space = RecoveryProposal::size(new_members.len()),
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            b"recovery"
+        ], bump
+    )]
+    pub proposal: Account<'info, RecoveryProposal>,
+
+    #[account(
+        mut,
+        constraint = multisig.is_guardian(guardian.key()) @MsError::KeyNotGuardian,
+    )]
+    pub guardian: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for a guardian approving a recovery proposal
+///
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. recovery proposal account
+/// 3. guardian account [signer]
+#[derive(Accounts)]
+pub struct ApproveRecovery<'info> {
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+        constraint = multisig.migrated @MsError::MultisigNotMigrated,
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            b"recovery"
+        ], bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, RecoveryProposal>,
+
+    #[account(
+        constraint = multisig.is_guardian(guardian.key()) @MsError::KeyNotGuardian,
+    )]
+    pub guardian: Signer<'info>,
+}
+
+/// The account context for a member objecting to a recovery proposal, which closes it
+/// The rent of the recovery proposal is returned to the proposer.
+///
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. recovery proposal account
+/// 3. proposer account
+/// 4. member account [signer]
+#[derive(Accounts)]
+pub struct CancelRecovery<'info> {
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+        constraint = multisig.migrated @MsError::MultisigNotMigrated,
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            b"recovery"
+        ], bump = proposal.bump,
+        close = proposer
+    )]
+    pub proposal: Account<'info, RecoveryProposal>,
+
+    /// CHECK: the guardian that proposed the recovery, receives the rent
+    #[account(mut, address = proposal.proposer @MsError::InvalidInstructionAccount)]
+    pub proposer: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = multisig.is_member(member.key()).is_some() @MsError::KeyNotInMultisig,
+    )]
+    pub member: Signer<'info>,
+}
+
+/// The account context for executing a recovery proposal once the delay has passed
+/// The members of the multisig are replaced, and the rent of the recovery proposal is
+/// returned to the proposer. The payer covers any additional rent for the multisig.
+///
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. recovery proposal account
+/// 3. proposer account
+/// 4. payer account [signer]
+/// 5. rent sysvar
+/// 6. system program
+#[derive(Accounts)]
+pub struct ExecuteRecovery<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+        constraint = multisig.migrated @MsError::MultisigNotMigrated,
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            b"recovery"
+        ], bump = proposal.bump,
+        close = proposer
+    )]
+    pub proposal: Account<'info, RecoveryProposal>,
+
+    /// CHECK: the guardian that proposed the recovery, receives the rent
+    #[account(mut, address = proposal.proposer @MsError::InvalidInstructionAccount)]
+    pub proposer: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for marking an expired transaction
+/// The transaction must be in a Draft, Active or ExecuteReady state, anyone can invoke it
+///
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. transaction account
+///
+#[derive(Accounts)]
+pub struct ExpireTransaction<'info> {
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+        constraint = multisig.migrated @MsError::MultisigNotMigrated,
+    )]
+    pub multisig: Account<'info, Ms>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = matches!(
+            transaction.status,
+            MsTransactionStatus::Draft | MsTransactionStatus::Active | MsTransactionStatus::ExecuteReady
+        ) @MsError::InvalidTransactionState,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+}
+
+/// The account context for closing a finished transaction
+/// The transaction must be Executed, Rejected, Cancelled, Expired, or deprecated by the change index.
+/// The MsInstruction accounts of the transaction are passed in as remaining accounts.
+///
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. transaction account
+/// 3. creator account (receives the lamports)
+///
+#[derive(Accounts)]
+pub struct CloseTransaction<'info> {
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+        constraint = multisig.migrated @MsError::MultisigNotMigrated,
+    )]
+    pub multisig: Account<'info, Ms>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = transaction.is_closeable(multisig.ms_change_index) @MsError::InvalidTransactionState,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+        close = creator,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    /// CHECK: only receives the lamports, must be the creator of the transaction
+    #[account(
+        mut,
+        address = transaction.creator @MsError::InvalidInstructionAccount,
+    )]
+    pub creator: UncheckedAccount<'info>,
+}
+
+/// The account context for the creator withdrawing their transaction
+/// The transaction must be in a Draft or Active state, and can't have any approvals yet.
+/// When the transaction is closed, the MsInstruction accounts of the transaction are passed in as remaining accounts.
+///
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. transaction account
+/// 3. creator account [signer] (receives the lamports)
+///
+#[derive(Accounts)]
+pub struct WithdrawTransaction<'info> {
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+        constraint = multisig.migrated @MsError::MultisigNotMigrated,
+    )]
+    pub multisig: Account<'info, Ms>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = transaction.creator == creator.key() @MsError::InvalidInstructionAccount,
+        constraint = matches!(transaction.status, MsTransactionStatus::Draft | MsTransactionStatus::Active) @MsError::InvalidTransactionState,
+        constraint = transaction.approved.is_empty() @MsError::TransactionAlreadyApproved,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+}
+
+/// The account context for executing a transaction
+/// The transaction must be in an ExecuteReady state, and the creator must be a member of the multisig with the Execute permission
+/// Execution continues from the executed_index of the transaction, if it was partially executed
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. transaction account
+/// 3. member account [signer]
+/// 
+#[derive(Accounts)]
+pub struct ExecuteTransaction<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+        constraint = multisig.migrated @MsError::MultisigNotMigrated,
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = transaction.status == MsTransactionStatus::ExecuteReady @MsError::InvalidTransactionState,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    #[account(
+        mut,
+        constraint = multisig.is_member(member.key()).is_some() @MsError::KeyNotInMultisig,
+        constraint = multisig.has_permission(member.key(), MemberPermissions::EXECUTE) @MsError::MissingPermission,
+    )]
+    pub member: Signer<'info>,
+}
+
+/// The account context for getting the accounts needed to execute a transaction
+/// This is a read-only instruction, meant to be simulated.
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. transaction account
+/// 
+#[derive(Accounts)]
+pub struct GetExecutionAccounts<'info> {
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+        constraint = multisig.migrated @MsError::MultisigNotMigrated,
+    )]
+    pub multisig: Account<'info, Ms>,
+
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+}
+
+/// The account context for executing a transaction instruction individually
+/// The transaction must be in an ExecuteReady state, and the creator must be a member of the multisig with the Execute permission, and the instruction must correlate to the next executed index
+/// 
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. transaction account
+/// 3. member account [signer]
+/// 
+#[derive(Accounts)]
+pub struct ExecuteInstruction<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+        constraint = multisig.migrated @MsError::MultisigNotMigrated,
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &transaction.transaction_index.to_le_bytes(),
+            b"transaction"
+        ], bump = transaction.bump,
+        constraint = transaction.status == MsTransactionStatus::ExecuteReady @MsError::InvalidTransactionState,
+        constraint = transaction.ms == multisig.key() @MsError::InvalidInstructionAccount,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            transaction.key().as_ref(),
+            &transaction.executed_index.checked_add(1).unwrap().to_le_bytes(),
+            b"instruction"
+        ], bump = instruction.bump,
+        // it should be the next expected instruction account to be executed
+        constraint = instruction.instruction_index == transaction.executed_index.checked_add(1).unwrap() @MsError::InvalidInstructionAccount,
+    )]
+    pub instruction: Account<'info, MsInstruction>,
+
+    #[account(
+        mut,
+        constraint = multisig.is_member(member.key()).is_some() @MsError::KeyNotInMultisig,
+        constraint = multisig.has_permission(member.key(), MemberPermissions::EXECUTE) @MsError::MissingPermission,
+    )]
+    pub member: Signer<'info>,
+}
+
+/// The account context for executing an internal multisig transaction (which changes the multisig account)
+/// 
+/// Expects the following accounts:
+/// 1. multisig account [signer]
+#[derive(Accounts)]
+pub struct MsAuth<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+        constraint = multisig.migrated @MsError::MultisigNotMigrated,
+        signer
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+}
+
+/// The account context for reallocating the multisig account (for add member, where the size may need to be adjusted)
+/// 
+/// Expects the following accounts:
+/// 1. multisig account [signer]
+/// 2. rent sysvar
+/// 3. system program
+/// 
+/// 
+#[derive(Accounts)]
+pub struct MsAuthRealloc<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+        constraint = multisig.migrated @MsError::MultisigNotMigrated,
+        signer
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MsAuthRealloc<'info> {
+    /// Reallocates the multisig if it doesn't have the additional space available,
+    /// bumping it up by the size of 10 member keys. If the multisig needs to be
+    /// reallocated, it must be prefunded with enough lamports to cover the new size.
+    /// The in-memory multisig is kept as is, so changes made before the realloc
+    /// are still serialized when the instruction exits.
+    pub fn realloc_if_needed(&mut self, additional_space: usize) -> Result<()> {
+        let multisig_account_info = self.multisig.to_account_info();
+        if *multisig_account_info.owner != crate::ID {
+            return err!(MsError::InvalidInstructionAccount);
+        }
+        let curr_data_size = multisig_account_info.data_len();
+        let needed_space = self.multisig.space_used() + additional_space;
+
+        if needed_space > curr_data_size {
+            // add space for 10 more keys
+            let needed_len = needed_space.max(curr_data_size + (10 * 32));
+            // reallocate more space
+            AccountInfo::realloc(&multisig_account_info, needed_len, false)?;
+            // if more lamports are needed, the multisig needs to be prefunded
+            let rent_exempt_lamports = self.rent.minimum_balance(needed_len).max(1);
+            let top_up_lamports =
+                rent_exempt_lamports.saturating_sub(multisig_account_info.lamports());
+            if top_up_lamports > 0 {
+                return err!(MsError::NotEnoughLamports);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The account context for migrating a multisig created with the legacy Ms layout
+/// The multisig is not deserialized here, as it may not fit the current layout yet
+///
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. member account [signer]
+/// 3. rent sysvar
+/// 4. system program
+#[derive(Accounts)]
+pub struct MigrateMultisig<'info> {
+    /// CHECK: owner, discriminator, and seeds are validated in the instruction
+    #[account(
+        mut,
+        owner = crate::ID @MsError::InvalidInstructionAccount,
+    )]
+    pub multisig: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub member: Signer<'info>,
+
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for creating the metadata of a multisig created before
+/// the MsMetadata account existed (internal)
+/// The payer funds the metadata account, and needs to sign the execution.
+///
+/// Expects the following accounts:
+/// 1. multisig account [signer]
+/// 2. metadata account
+/// 3. payer account [signer]
+/// 4. system program
+///
+/// Expects the following arguments:
+/// 1. meta: String
+#[derive(Accounts)]
+#[instruction(meta: String)]
+pub struct InitMetadata<'info> {
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+        constraint = multisig.migrated @MsError::MultisigNotMigrated,
+        signer
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = MsMetadata::size(&meta),
+        seeds = [b"squad", multisig.key().as_ref(), b"metadata"], bump
+    )]
+    pub metadata: Account<'info, MsMetadata>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for updating the metadata of the multisig (internal)
+/// The metadata account is reallocated to fit the new metadata, with the payer
+/// covering any additional rent.
+///
+/// Expects the following accounts:
+/// 1. multisig account [signer]
+/// 2. metadata account
+/// 3. payer account [signer]
+/// 4. system program
+///
+/// Expects the following arguments:
+/// 1. meta: String
+#[derive(Accounts)]
+#[instruction(meta: String)]
+pub struct UpdateMetadata<'info> {
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+        constraint = multisig.migrated @MsError::MultisigNotMigrated,
+        signer
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            b"metadata"
+        ], bump = metadata.bump,
+        realloc = MsMetadata::size(&meta),
+        realloc::payer = payer,
+        realloc::zero = false,
+    )]
+    pub metadata: Account<'info, MsMetadata>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for registering an authority of the multisig (internal)
+///
+/// Expects the following accounts:
+/// 1. multisig account [signer]
+/// 2. authority account
+/// 3. payer account [signer]
+/// 4. system program
+///
+/// Expects the following arguments:
+/// 1. authority_index: u32
+/// 2. label: String
+#[derive(Accounts)]
+#[instruction(authority_index: u32, label: String)]
+pub struct RegisterAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+        constraint = multisig.migrated @MsError::MultisigNotMigrated,
+        signer
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = Authority::size(&label),
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &authority_index.to_le_bytes(),
+            b"authority_info"
+        ], bump
+    )]
+    pub authority: Account<'info, Authority>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for updating the label and status of an authority of the multisig (internal)
+/// The authority account is resized to fit the new label, the payer pays or is refunded the difference in rent.
+///
+/// Expects the following accounts:
+/// 1. multisig account [signer]
+/// 2. authority account
+/// 3. payer account [signer]
+/// 4. system program
+///
+/// Expects the following arguments:
+/// 1. label: String
+/// 2. enabled: bool
+#[derive(Accounts)]
+#[instruction(label: String, enabled: bool)]
+pub struct UpdateAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+        constraint = multisig.migrated @MsError::MultisigNotMigrated,
+        signer
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &authority.authority_index.to_le_bytes(),
+            b"authority_info"
+        ], bump = authority.bump,
+        constraint = authority.ms == multisig.key() @MsError::InvalidAuthorityIndex,
+        realloc = Authority::size(&label),
+        realloc::payer = payer,
+        realloc::zero = false,
+    )]
+    pub authority: Account<'info, Authority>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for adding an authority policy to the multisig (internal)
+///
+/// Expects the following accounts:
+/// 1. multisig account [signer]
+/// 2. authority policy account
+/// 3. payer account [signer]
+/// 4. system program
+///
+/// Expects the following arguments:
+/// 1. authority_index: u32
+#[derive(Accounts)]
+#[instruction(authority_index: u32)]
+pub struct AddAuthorityPolicy<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+        constraint = multisig.migrated @MsError::MultisigNotMigrated,
+        signer
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = AuthorityPolicy::SIZE,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &authority_index.to_le_bytes(),
+            b"policy"
+        ], bump
+    )]
+    pub policy: Account<'info, AuthorityPolicy>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for changing an authority policy of the multisig (internal)
+///
+/// Expects the following accounts:
+/// 1. multisig account [signer]
+/// 2. authority policy account
+#[derive(Accounts)]
+pub struct ChangeAuthorityPolicy<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+        constraint = multisig.migrated @MsError::MultisigNotMigrated,
+        signer
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &policy.authority_index.to_le_bytes(),
+            b"policy"
+        ], bump = policy.bump,
+        constraint = policy.ms == multisig.key() @MsError::InvalidAuthorityPolicy,
+    )]
+    pub policy: Account<'info, AuthorityPolicy>,
+}
+
+/// The account context for removing an authority policy from the multisig (internal)
+///
+/// Expects the following accounts:
+/// 1. multisig account [signer]
+/// 2. authority policy account
+/// 3. rent collector account (receives the lamports)
+#[derive(Accounts)]
+pub struct RemoveAuthorityPolicy<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+        constraint = multisig.migrated @MsError::MultisigNotMigrated,
+        signer
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &policy.authority_index.to_le_bytes(),
+            b"policy"
+        ], bump = policy.bump,
+        constraint = policy.ms == multisig.key() @MsError::InvalidAuthorityPolicy,
+        close = rent_collector,
+    )]
+    pub policy: Account<'info, AuthorityPolicy>,
+
+    /// CHECK: only receives the lamports of the closed policy
+    #[account(mut)]
+    pub rent_collector: UncheckedAccount<'info>,
+}
+
+/// The account context for adding a program policy to an authority of the multisig (internal)
+///
+/// Expects the following accounts:
+/// 1. multisig account [signer]
+/// 2. program policy account
+/// 3. payer account [signer]
+/// 4. system program
+///
+/// Expects the following arguments:
+/// 1. authority_index: u32
+/// 2. mode: ProgramPolicyMode
+/// 3. programs: Vec<Pubkey>
+#[derive(Accounts)]
+#[instruction(authority_index: u32, mode: ProgramPolicyMode, programs: Vec<Pubkey>)]
+pub struct AddProgramPolicy<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+        constraint = multisig.migrated @MsError::MultisigNotMigrated,
+        signer
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = ProgramPolicy::size(programs.len()),
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &authority_index.to_le_bytes(),
+            b"program_policy"
+        ], bump
+    )]
+    pub policy: Account<'info, ProgramPolicy>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for changing a program policy of the multisig (internal)
+/// The policy account is resized to fit the new programs, the payer pays or is refunded the difference in rent.
+///
+/// Expects the following accounts:
+/// 1. multisig account [signer]
+/// 2. program policy account
+/// 3. payer account [signer]
+/// 4. system program
+///
+/// Expects the following arguments:
+/// 1. mode: ProgramPolicyMode
+/// 2. programs: Vec<Pubkey>
+#[derive(Accounts)]
+#[instruction(mode: ProgramPolicyMode, programs: Vec<Pubkey>)]
+pub struct ChangeProgramPolicy<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+        constraint = multisig.migrated @MsError::MultisigNotMigrated,
+        signer
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &policy.authority_index.to_le_bytes(),
+            b"program_policy"
+        ], bump = policy.bump,
+        constraint = policy.ms == multisig.key() @MsError::InvalidProgramPolicy,
+        realloc = ProgramPolicy::size(programs.len()),
+        realloc::payer = payer,
+        realloc::zero = false,
+    )]
+    pub policy: Account<'info, ProgramPolicy>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for removing a program policy from the multisig (internal)
+///
+/// Expects the following accounts:
+/// 1. multisig account [signer]
+/// 2. program policy account
+/// 3. rent collector account (receives the lamports)
+#[derive(Accounts)]
+pub struct RemoveProgramPolicy<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+        constraint = multisig.migrated @MsError::MultisigNotMigrated,
+        signer
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &policy.authority_index.to_le_bytes(),
+            b"program_policy"
+        ], bump = policy.bump,
+        constraint = policy.ms == multisig.key() @MsError::InvalidProgramPolicy,
+        close = rent_collector,
+    )]
+    pub policy: Account<'info, ProgramPolicy>,
+
+    /// CHECK: only receives the lamports of the closed policy
+    #[account(mut)]
+    pub rent_collector: UncheckedAccount<'info>,
+}
+
+/// The account context for adding a spending limit to an authority of the multisig (internal)
+///
+/// Expects the following accounts:
+/// 1. multisig account [signer]
+/// 2. spending limit account
+/// 3. payer account [signer]
+/// 4. system program
+///
+/// Expects the following arguments:
+/// 1. authority_index: u32
+/// 2. mint: Pubkey
+/// 3. amount: u64
+/// 4. period: i64
+/// 5. members: Vec<Pubkey>
+#[derive(Accounts)]
+#[instruction(authority_index: u32, mint: Pubkey, amount: u64, period: i64, members: Vec<Pubkey>)]
+pub struct AddSpendingLimit<'info> {
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+        constraint = multisig.migrated @MsError::MultisigNotMigrated,
+        signer
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = SpendingLimit::size_with_members(members.len()),
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &authority_index.to_le_bytes(),
+            mint.as_ref(),
+            b"spending_limit"
+        ], bump
+    )]
+    pub spending_limit: Account<'info, SpendingLimit>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for removing a spending limit from the multisig (internal)
+///
+/// Expects the following accounts:
+/// 1. multisig account [signer]
+/// 2. spending limit account
+/// 3. rent collector account (receives the lamports)
+#[derive(Accounts)]
+pub struct RemoveSpendingLimit<'info> {
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ], bump = multisig.bump,
+        constraint = multisig.migrated @MsError::MultisigNotMigrated,
+        signer
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &spending_limit.authority_index.to_le_bytes(),
+            spending_limit.mint.as_ref(),
+            b"spending_limit"
+        ], bump = spending_limit.bump,
+        constraint = spending_limit.ms == multisig.key() @MsError::InvalidSpendingLimit,
+        close = rent_collector,
+    )]
+    pub spending_limit: Account<'info, SpendingLimit>,
+
+    /// CHECK: only receives the lamports of the closed spending limit
+    #[account(mut)]
+    pub rent_collector: UncheckedAccount<'info>,
+}
+
+/// The account context for transferring native SOL from an authority with a spending limit
+/// The member must be a member of the multisig and of the spending limit
+///
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. spending limit account
+/// 3. authority account (vault)
+/// 4. destination account
+/// 5. member account [signer]
+/// 6. system program
+///
+/// The Authority account is passed as a remaining account while the multisig has disabled authorities
+#[derive(Accounts)]
+pub struct UseSpendingLimit<'info> {
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+        constraint = multisig.migrated @MsError::MultisigNotMigrated,
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &spending_limit.authority_index.to_le_bytes(),
+            spending_limit.mint.as_ref(),
+            b"spending_limit"
+        ], bump = spending_limit.bump,
+        constraint = spending_limit.ms == multisig.key() @MsError::InvalidSpendingLimit,
+        constraint = spending_limit.mint == Pubkey::default() @MsError::InvalidSpendingLimit,
+    )]
+    pub spending_limit: Account<'info, SpendingLimit>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &spending_limit.authority_index.to_le_bytes(),
+            b"authority"
+        ], bump = spending_limit.authority_bump,
+    )]
+    pub authority: SystemAccount<'info>,
+
+    /// CHECK: only receives the transferred lamports
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = multisig.is_member(member.key()).is_some() @MsError::KeyNotInMultisig,
+        constraint = spending_limit.is_member(member.key()).is_some() @MsError::KeyNotInSpendingLimit,
+    )]
+    pub member: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The account context for transferring tokens from an authority with a spending limit
+/// The member must be a member of the multisig and of the spending limit
+///
+/// Expects the following accounts:
+/// 1. multisig account
+/// 2. spending limit account
+/// 3. authority account (vault)
+/// 4. authority token account
+/// 5. destination token account
+/// 6. member account [signer]
+/// 7. token program
+///
+/// The Authority account is passed as a remaining account while the multisig has disabled authorities
+#[derive(Accounts)]
+pub struct UseTokenSpendingLimit<'info> {
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.create_key.as_ref(),
+            b"multisig"
+        ],
+        bump = multisig.bump,
+        constraint = multisig.migrated @MsError::MultisigNotMigrated,
+    )]
+    pub multisig: Box<Account<'info, Ms>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &spending_limit.authority_index.to_le_bytes(),
+            spending_limit.mint.as_ref(),
+            b"spending_limit"
+        ], bump = spending_limit.bump,
+        constraint = spending_limit.ms == multisig.key() @MsError::InvalidSpendingLimit,
+    )]
+    pub spending_limit: Account<'info, SpendingLimit>,
+
+    /// CHECK: the authority PDA, only used to sign the token transfer
+    #[account(
+        seeds = [
+            b"squad",
+            multisig.key().as_ref(),
+            &spending_limit.authority_index.to_le_bytes(),
+            b"authority"
+        ], bump = spending_limit.authority_bump,
+    )]
+    pub authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = authority_token_account.owner == authority.key() @MsError::InvalidInstructionAccount,
+        constraint = authority_token_account.mint == spending_limit.mint @MsError::InvalidSpendingLimit,
+    )]
+    pub authority_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = destination_token_account.mint == spending_limit.mint @MsError::InvalidSpendingLimit,
+    )]
+    pub destination_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = multisig.is_member(member.key()).is_some() @MsError::KeyNotInMultisig,
+        constraint = spending_limit.is_member(member.key()).is_some() @MsError::KeyNotInSpendingLimit,
+    )]
+    pub member: Signer<'info>,
+    pub token_program: Program<'info, Token>,
 }
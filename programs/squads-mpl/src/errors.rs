@@ -21,4 +21,33 @@ pub enum MsError {
     EmptyMembers,
     PartialExecution,
     NotEnoughLamports,
+    TimeLockNotReleased,
+    TransactionExpired,
+    TransactionNotExpired,
+    InvalidExpiry,
+    InvalidMemberWeight,
+    InvalidAuthorityPolicy,
+    InvalidSpendingLimit,
+    SpendingLimitExceeded,
+    KeyNotInSpendingLimit,
+    MaxInstructionsReached,
+    InvalidLookupTable,
+    InvalidPermissions,
+    MissingPermission,
+    MetadataTooLong,
+    KeyAlreadyInMultisig,
+    InvalidRecoveryConfig,
+    KeyNotGuardian,
+    RecoveryNotReady,
+    TransactionAlreadyApproved,
+    InstructionBufferOverflow,
+    InstructionBufferIncomplete,
+    InstructionNotAllowed,
+    InvalidProgramPolicy,
+    ProgramNotAllowed,
+    InvalidAuthorityLabel,
+    AuthorityDisabled,
+    TooManyEphemeralSigners,
+    RecoveryConfigChanged,
+    MultisigNotMigrated,
 }
\ No newline at end of file
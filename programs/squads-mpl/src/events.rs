@@ -0,0 +1,332 @@
+/*
+    Squads Multisig Program - Events
+    https://github.com/squads-protocol/squads-mpl
+*/
+
+use anchor_lang::prelude::*;
+use crate::state::{MsTransactionStatus, ProgramPolicyMode};
+
+/// Emitted when a new multisig is created
+#[event]
+pub struct MultisigCreated {
+    pub multisig: Pubkey,
+    pub creator: Pubkey,
+    pub threshold: u16,
+    pub members: Vec<Pubkey>,
+}
+
+/// Emitted when a member is added to the multisig (internal transaction)
+#[event]
+pub struct MemberAdded {
+    pub multisig: Pubkey,
+    pub member: Pubkey,
+    pub threshold: u16,         // threshold of the multisig after the change
+    pub change_index: u32,      // the new change index of the multisig
+}
+
+/// Emitted when a member is removed from the multisig (internal transaction)
+#[event]
+pub struct MemberRemoved {
+    pub multisig: Pubkey,
+    pub member: Pubkey,
+    pub threshold: u16,         // threshold of the multisig after the change
+    pub change_index: u32,      // the new change index of the multisig
+}
+
+/// Emitted when a member rotates their key
+#[event]
+pub struct MemberKeyRotated {
+    pub multisig: Pubkey,
+    pub old_member: Pubkey,
+    pub new_member: Pubkey,
+}
+
+/// Emitted when the voting weight of a member is changed (internal transaction)
+#[event]
+pub struct MemberWeightChanged {
+    pub multisig: Pubkey,
+    pub member: Pubkey,
+    pub weight: u16,
+    pub change_index: u32,      // the new change index of the multisig
+}
+
+/// Emitted when the permissions of a member are changed (internal transaction)
+#[event]
+pub struct MemberPermissionsChanged {
+    pub multisig: Pubkey,
+    pub member: Pubkey,
+    pub permissions: u8,
+    pub change_index: u32,      // the new change index of the multisig
+}
+
+/// Emitted when the recovery config of the multisig is set (internal transaction)
+#[event]
+pub struct RecoveryConfigSet {
+    pub multisig: Pubkey,
+    pub guardians: Vec<Pubkey>,
+    pub threshold: u16,
+    pub delay: u32,
+    pub change_index: u32,      // the new change index of the multisig
+}
+
+/// Emitted when the recovery config of the multisig is removed (internal transaction)
+#[event]
+pub struct RecoveryConfigRemoved {
+    pub multisig: Pubkey,
+    pub change_index: u32,      // the new change index of the multisig
+}
+
+/// Emitted when guardians propose a recovery of the multisig
+#[event]
+pub struct RecoveryProposed {
+    pub multisig: Pubkey,
+    pub guardian: Pubkey,
+    pub new_members: Vec<Pubkey>,
+    pub new_threshold: u16,
+}
+
+/// Emitted when a guardian approves a recovery, ready_at is set once enough guardians approved
+#[event]
+pub struct RecoveryApproved {
+    pub multisig: Pubkey,
+    pub guardian: Pubkey,
+    pub ready_at: i64,
+}
+
+/// Emitted when a member objects to a recovery
+#[event]
+pub struct RecoveryCancelled {
+    pub multisig: Pubkey,
+    pub member: Pubkey,
+}
+
+/// Emitted when a recovery replaces the members of the multisig
+#[event]
+pub struct RecoveryExecuted {
+    pub multisig: Pubkey,
+    pub new_members: Vec<Pubkey>,
+    pub threshold: u16,
+    pub change_index: u32,      // the new change index of the multisig
+}
+
+/// Emitted when the threshold of the multisig is changed (internal transaction)
+#[event]
+pub struct ThresholdChanged {
+    pub multisig: Pubkey,
+    pub threshold: u16,
+    pub change_index: u32,      // the new change index of the multisig
+}
+
+/// Emitted when the reject threshold of the multisig is changed (internal transaction)
+#[event]
+pub struct RejectThresholdChanged {
+    pub multisig: Pubkey,
+    pub reject_threshold: u16,
+    pub change_index: u32,      // the new change index of the multisig
+}
+
+/// Emitted when the time lock of the multisig is changed (internal transaction)
+#[event]
+pub struct TimeLockChanged {
+    pub multisig: Pubkey,
+    pub time_lock: u32,
+    pub change_index: u32,      // the new change index of the multisig
+}
+
+/// Emitted when the default ttl of new transactions is changed (internal transaction)
+#[event]
+pub struct DefaultTtlChanged {
+    pub multisig: Pubkey,
+    pub default_ttl: u32,
+    pub change_index: u32,      // the new change index of the multisig
+}
+
+/// Emitted when the tracked authority index of the multisig is increased (internal transaction)
+#[event]
+pub struct AuthorityAdded {
+    pub multisig: Pubkey,
+    pub authority_index: u16,
+}
+
+/// Emitted when an authority of the multisig is registered or updated (internal transaction)
+#[event]
+pub struct AuthorityUpdated {
+    pub multisig: Pubkey,
+    pub authority_index: u32,
+    pub label: String,
+    pub enabled: bool,
+}
+
+/// Emitted when an authority policy is added or changed (internal transaction)
+#[event]
+pub struct AuthorityPolicyUpdated {
+    pub multisig: Pubkey,
+    pub authority_index: u32,
+    pub threshold: u16,
+    pub change_index: u32,      // the new change index of the multisig
+}
+
+/// Emitted when an authority policy is removed (internal transaction)
+#[event]
+pub struct AuthorityPolicyRemoved {
+    pub multisig: Pubkey,
+    pub authority_index: u32,
+    pub change_index: u32,      // the new change index of the multisig
+}
+
+/// Emitted when a program policy is added or changed (internal transaction)
+#[event]
+pub struct ProgramPolicyUpdated {
+    pub multisig: Pubkey,
+    pub authority_index: u32,
+    pub mode: ProgramPolicyMode,
+    pub programs: Vec<Pubkey>,
+    pub change_index: u32,      // the new change index of the multisig
+}
+
+/// Emitted when a program policy is removed (internal transaction)
+#[event]
+pub struct ProgramPolicyRemoved {
+    pub multisig: Pubkey,
+    pub authority_index: u32,
+    pub change_index: u32,      // the new change index of the multisig
+}
+
+/// Emitted when a spending limit is added to an authority (internal transaction)
+#[event]
+pub struct SpendingLimitAdded {
+    pub multisig: Pubkey,
+    pub spending_limit: Pubkey,
+    pub authority_index: u32,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub period: i64,
+    pub members: Vec<Pubkey>,
+}
+
+/// Emitted when a spending limit is removed (internal transaction)
+#[event]
+pub struct SpendingLimitRemoved {
+    pub multisig: Pubkey,
+    pub spending_limit: Pubkey,
+    pub authority_index: u32,
+}
+
+/// Emitted when a member transfers funds within a spending limit
+#[event]
+pub struct SpendingLimitUsed {
+    pub multisig: Pubkey,
+    pub spending_limit: Pubkey,
+    pub member: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub remaining_amount: u64,  // the amount left to spend in the current period
+}
+
+/// Emitted when a transaction is created
+#[event]
+pub struct TransactionCreated {
+    pub multisig: Pubkey,
+    pub transaction: Pubkey,
+    pub transaction_index: u32,
+    pub authority_index: u32,
+    pub creator: Pubkey,
+    pub status: MsTransactionStatus,
+}
+
+/// Emitted when a transaction is activated by its creator
+#[event]
+pub struct TransactionActivated {
+    pub multisig: Pubkey,
+    pub transaction: Pubkey,
+    pub transaction_index: u32,
+    pub creator: Pubkey,
+    pub status: MsTransactionStatus,
+}
+
+/// Emitted when a member approves a transaction, the status reflects
+/// whether the transaction became ExecuteReady
+#[event]
+pub struct TransactionApproved {
+    pub multisig: Pubkey,
+    pub transaction: Pubkey,
+    pub transaction_index: u32,
+    pub member: Pubkey,
+    pub status: MsTransactionStatus,
+}
+
+/// Emitted when a member rejects a transaction, the status reflects
+/// whether the transaction became Rejected
+#[event]
+pub struct TransactionRejected {
+    pub multisig: Pubkey,
+    pub transaction: Pubkey,
+    pub transaction_index: u32,
+    pub member: Pubkey,
+    pub status: MsTransactionStatus,
+}
+
+/// Emitted when a member abstains from voting on a transaction, the status reflects
+/// whether the transaction became Rejected
+#[event]
+pub struct TransactionAbstained {
+    pub multisig: Pubkey,
+    pub transaction: Pubkey,
+    pub transaction_index: u32,
+    pub member: Pubkey,
+    pub status: MsTransactionStatus,
+}
+
+/// Emitted when a member votes to cancel a transaction, the status reflects
+/// whether the transaction became Cancelled
+#[event]
+pub struct TransactionCancelled {
+    pub multisig: Pubkey,
+    pub transaction: Pubkey,
+    pub transaction_index: u32,
+    pub member: Pubkey,
+    pub status: MsTransactionStatus,
+}
+
+/// Emitted when the creator withdraws their transaction before it was approved,
+/// closed reflects whether the transaction account was closed as well
+#[event]
+pub struct TransactionWithdrawn {
+    pub multisig: Pubkey,
+    pub transaction: Pubkey,
+    pub transaction_index: u32,
+    pub creator: Pubkey,
+    pub closed: bool,
+}
+
+/// Emitted when a transaction is marked as expired
+#[event]
+pub struct TransactionExpired {
+    pub multisig: Pubkey,
+    pub transaction: Pubkey,
+    pub transaction_index: u32,
+    pub status: MsTransactionStatus,
+}
+
+/// Emitted when a transaction is executed with execute_transaction or execute_transaction_chunk,
+/// the status reflects whether all of its instructions have been executed
+#[event]
+pub struct TransactionExecuted {
+    pub multisig: Pubkey,
+    pub transaction: Pubkey,
+    pub transaction_index: u32,
+    pub member: Pubkey,
+    pub status: MsTransactionStatus,
+}
+
+/// Emitted when an instruction of a transaction is executed with execute_instruction,
+/// the status reflects whether it was the last instruction of the transaction
+#[event]
+pub struct InstructionExecuted {
+    pub multisig: Pubkey,
+    pub transaction: Pubkey,
+    pub transaction_index: u32,
+    pub instruction_index: u8,
+    pub member: Pubkey,
+    pub status: MsTransactionStatus,
+}
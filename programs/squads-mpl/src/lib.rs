@@ -8,18 +8,21 @@ use anchor_lang::{
     solana_program::{
         instruction::Instruction,
         program::invoke_signed
-    }
+    },
+    system_program,
+    Discriminator,
 };
-
-use hex::FromHex;
+use anchor_spl::token;
 
 use state::*;
 use errors::*;
 use account::*;
+use events::*;
 
 pub mod state;
 pub mod account;
 pub mod errors;
+pub mod events;
 
 #[cfg(not(feature = "no-entrypoint"))]
 use {default_env::default_env, solana_security_txt::security_txt};
@@ -38,6 +41,45 @@ security_txt! {
 
 declare_id!("SMPLecH534NA9acpos4G6x7uf3LWbCAwZQE9e8ZekMu");
 
+/// Discriminators of the squads_mpl instructions that can be attached to internal
+/// (authority index 0) transactions. These are the config actions signed by the multisig
+/// itself, anything else (ie. execute_transaction, which could recurse) is refused on execution.
+pub const INTERNAL_INSTRUCTIONS: [[u8; 8]; 26] = [
+    instruction::AddMember::DISCRIMINATOR,
+    instruction::AddMemberWithWeight::DISCRIMINATOR,
+    instruction::ChangeMemberWeight::DISCRIMINATOR,
+    instruction::ChangeMemberPermissions::DISCRIMINATOR,
+    instruction::RemoveMember::DISCRIMINATOR,
+    instruction::RemoveMemberAndChangeThreshold::DISCRIMINATOR,
+    instruction::AddMemberAndChangeThreshold::DISCRIMINATOR,
+    instruction::ChangeThreshold::DISCRIMINATOR,
+    instruction::ChangeRejectThreshold::DISCRIMINATOR,
+    instruction::ChangeTimeLock::DISCRIMINATOR,
+    instruction::ChangeDefaultTtl::DISCRIMINATOR,
+    instruction::SetRecoveryConfig::DISCRIMINATOR,
+    instruction::RemoveRecoveryConfig::DISCRIMINATOR,
+    instruction::AddAuthority::DISCRIMINATOR,
+    instruction::RegisterAuthority::DISCRIMINATOR,
+    instruction::UpdateAuthority::DISCRIMINATOR,
+    instruction::InitMetadata::DISCRIMINATOR,
+    instruction::UpdateMetadata::DISCRIMINATOR,
+    instruction::AddAuthorityPolicy::DISCRIMINATOR,
+    instruction::ChangeAuthorityPolicy::DISCRIMINATOR,
+    instruction::RemoveAuthorityPolicy::DISCRIMINATOR,
+    instruction::AddProgramPolicy::DISCRIMINATOR,
+    instruction::ChangeProgramPolicy::DISCRIMINATOR,
+    instruction::RemoveProgramPolicy::DISCRIMINATOR,
+    instruction::AddSpendingLimit::DISCRIMINATOR,
+    instruction::RemoveSpendingLimit::DISCRIMINATOR,
+];
+
+/// Checks if the instruction data belongs to an instruction allowed in internal transactions
+pub fn is_internal_instruction(data: &[u8]) -> bool {
+    INTERNAL_INSTRUCTIONS
+        .iter()
+        .any(|discriminator| data.get(0..8) == Some(&discriminator[..]))
+}
+
 #[program]
 pub mod squads_mpl {
 
@@ -52,7 +94,7 @@ pub mod squads_mpl {
         threshold: u16,       // threshold of members required to sign
         create_key: Pubkey,   // the public key used to seed the original multisig creation
         members: Vec<Pubkey>, // a list of members (Public Keys) to use for the multisig
-        _meta: String,        // a string of metadata that can be used to describe the multisig on-chain as a memo ie. '{"name":"My Multisig","description":"This is a my multisig"}'
+        meta: String,         // a string of metadata describing the multisig, stored in the MsMetadata account ie. '{"name":"My Multisig","description":"This is a my multisig"}'
     ) -> Result<()> {
         // sort the members and remove duplicates
         let mut members = members;
@@ -78,9 +120,23 @@ pub mod squads_mpl {
         ctx.accounts.multisig.init(
             threshold,
             create_key,
-            members,
+            members.clone(),
             *ctx.bumps.get("multisig").unwrap(),
-        )
+        )?;
+        // persist the metadata of the multisig
+        ctx.accounts.metadata.init(
+            ctx.accounts.multisig.key(),
+            meta,
+            *ctx.bumps.get("metadata").unwrap(),
+        )?;
+
+        emit!(MultisigCreated {
+            multisig: ctx.accounts.multisig.key(),
+            creator: ctx.accounts.creator.key(),
+            threshold,
+            members,
+        });
+        Ok(())
     }
 
     /// The instruction to add a new member to the multisig.
@@ -93,52 +149,264 @@ pub mod squads_mpl {
             return err!(MsError::MaxMembersReached);
         }
 
-        // check if realloc is needed
-        let multisig_account_info = ctx.accounts.multisig.to_account_info();
-        if *multisig_account_info.owner != squads_mpl::ID {
-            return err!(MsError::InvalidInstructionAccount);
+        // check if realloc is needed for the new key
+        ctx.accounts.realloc_if_needed(32)?;
+        ctx.accounts.multisig.add_member(new_member)?;
+        let new_index = ctx.accounts.multisig.transaction_index;
+        // set the change index, which will deprecate any active transactions
+        ctx.accounts.multisig.set_change_index(new_index)?;
+
+        emit!(MemberAdded {
+            multisig: ctx.accounts.multisig.key(),
+            member: new_member,
+            threshold: ctx.accounts.multisig.threshold,
+            change_index: new_index,
+        });
+        Ok(())
+    }
+
+    /// The instruction to add a new member with a voting weight other than the default of 1.
+    /// If the member is already in the multisig, only their weight is changed.
+    pub fn add_member_with_weight<'info>(
+        ctx: Context<'_, '_, '_, 'info, MsAuthRealloc<'info>>,
+        new_member: Pubkey,
+        weight: u16,
+    ) -> Result<()> {
+        add_member(
+            Context::new(
+                ctx.program_id,
+                ctx.accounts,
+                ctx.remaining_accounts,
+                ctx.bumps.clone(),
+            ),
+            new_member,
+        )?;
+        change_member_weight(ctx, new_member, weight)
+    }
+
+    /// The instruction to change the voting weight of a member.
+    /// Approvals, rejections and cancellations are counted by the combined weight
+    /// of the members, and the threshold is lowered if it can no longer be reached.
+    pub fn change_member_weight(
+        ctx: Context<MsAuthRealloc>,
+        member: Pubkey,
+        weight: u16,
+    ) -> Result<()> {
+        if ctx.accounts.multisig.is_member(member).is_none() {
+            return err!(MsError::KeyNotInMultisig);
+        }
+        if weight < 1 {
+            return err!(MsError::InvalidMemberWeight);
         }
-        let curr_data_size = multisig_account_info.data.borrow().len();
-        let spots_left =
-            ((curr_data_size - Ms::SIZE_WITHOUT_MEMBERS) / 32) - ctx.accounts.multisig.keys.len();
 
-        // if not enough, add (10 * 32) to size - bump it up by 10 accounts
-        if spots_left < 1 {
-            // add space for 10 more keys
-            let needed_len = curr_data_size + (10 * 32);
-            // reallocate more space
-            AccountInfo::realloc(&multisig_account_info, needed_len, false)?;
-            // if more lamports are needed, transfer them to the account
-            let rent_exempt_lamports = ctx.accounts.rent.minimum_balance(needed_len).max(1);
-            let top_up_lamports = rent_exempt_lamports
-                .saturating_sub(ctx.accounts.multisig.to_account_info().lamports());
-            if top_up_lamports > 0 {
-                return err!(MsError::NotEnoughLamports);
-            }
+        // only weights other than the default take up space in the multisig
+        let is_new_weight = weight != Ms::DEFAULT_MEMBER_WEIGHT && ctx
+            .accounts
+            .multisig
+            .member_weights
+            .binary_search_by_key(&member, |w| w.key)
+            .is_err();
+        if is_new_weight {
+            ctx.accounts.realloc_if_needed(MemberWeight::SIZE)?;
+        }
+        ctx.accounts.multisig.set_member_weight(member, weight)?;
+
+        // if the combined weight is now less than the threshold, adjust it
+        let max_threshold = ctx.accounts.multisig.max_threshold();
+        let lower_threshold = max_threshold < ctx.accounts.multisig.threshold;
+        if lower_threshold {
+            ctx.accounts.multisig.change_threshold(max_threshold)?;
         }
-        ctx.accounts.multisig.reload()?;
-        ctx.accounts.multisig.add_member(new_member)?;
         let new_index = ctx.accounts.multisig.transaction_index;
-        // set the change index, which will deprecate any active transactions
-        ctx.accounts.multisig.set_change_index(new_index)
+        // update the change index to deprecate any active transactions
+        ctx.accounts.multisig.set_change_index(new_index)?;
+
+        emit!(MemberWeightChanged {
+            multisig: ctx.accounts.multisig.key(),
+            member,
+            weight,
+            change_index: new_index,
+        });
+        if lower_threshold {
+            emit!(ThresholdChanged {
+                multisig: ctx.accounts.multisig.key(),
+                threshold: max_threshold,
+                change_index: new_index,
+            });
+        }
+        Ok(())
+    }
+
+    /// The instruction to change the permissions of a member (Initiate, Vote, Execute).
+    /// Only members with the vote permission count towards the threshold, and the
+    /// threshold is lowered if it can no longer be reached.
+    pub fn change_member_permissions(
+        ctx: Context<MsAuthRealloc>,
+        member: Pubkey,
+        permissions: u8,
+    ) -> Result<()> {
+        if ctx.accounts.multisig.is_member(member).is_none() {
+            return err!(MsError::KeyNotInMultisig);
+        }
+        if permissions & !MemberPermissions::ALL != 0 {
+            return err!(MsError::InvalidPermissions);
+        }
+
+        // only masks other than all permissions take up space in the multisig
+        let is_new_mask = permissions != MemberPermissions::ALL && ctx
+            .accounts
+            .multisig
+            .member_permissions
+            .binary_search_by_key(&member, |p| p.key)
+            .is_err();
+        if is_new_mask {
+            ctx.accounts.realloc_if_needed(MemberPermissions::SIZE)?;
+        }
+        ctx.accounts.multisig.set_member_permissions(member, permissions)?;
+
+        // at least one member needs to be able to vote
+        let max_threshold = ctx.accounts.multisig.max_threshold();
+        if max_threshold < 1 {
+            return err!(MsError::InvalidPermissions);
+        }
+        // if the combined weight of the voters is now less than the threshold, adjust it
+        let lower_threshold = max_threshold < ctx.accounts.multisig.threshold;
+        if lower_threshold {
+            ctx.accounts.multisig.change_threshold(max_threshold)?;
+        }
+        let new_index = ctx.accounts.multisig.transaction_index;
+        // update the change index to deprecate any active transactions
+        ctx.accounts.multisig.set_change_index(new_index)?;
+
+        emit!(MemberPermissionsChanged {
+            multisig: ctx.accounts.multisig.key(),
+            member,
+            permissions,
+            change_index: new_index,
+        });
+        if lower_threshold {
+            emit!(ThresholdChanged {
+                multisig: ctx.accounts.multisig.key(),
+                threshold: max_threshold,
+                change_index: new_index,
+            });
+        }
+        Ok(())
     }
 
-    /// The instruction to remove a member from the multisig
-    pub fn remove_member(ctx: Context<MsAuth>, old_member: Pubkey) -> Result<()> {
+    /// The instruction to remove a member from the multisig.
+    /// All of the AuthorityPolicy accounts of the multisig must be passed as writable
+    /// remaining accounts, in order of their authority index, and policy thresholds
+    /// that can no longer be reached are lowered along with the multisig threshold.
+    pub fn remove_member<'info>(
+        ctx: Context<'_, '_, '_, 'info, MsAuth<'info>>,
+        old_member: Pubkey,
+    ) -> Result<()> {
         // if there is only one key in this multisig, reject the removal
         if ctx.accounts.multisig.keys.len() == 1 {
             return err!(MsError::CannotRemoveSoloMember);
         }
         ctx.accounts.multisig.remove_member(old_member)?;
 
-        // if the number of keys is now less than the threshold, adjust it
-        if ctx.accounts.multisig.keys.len() < usize::from(ctx.accounts.multisig.threshold) {
-            let new_threshold: u16 = ctx.accounts.multisig.keys.len().try_into().unwrap();
-            ctx.accounts.multisig.change_threshold(new_threshold)?;
+        // if the combined weight of the keys is now less than the threshold, adjust it
+        let max_threshold = ctx.accounts.multisig.max_threshold();
+        if max_threshold < ctx.accounts.multisig.threshold {
+            ctx.accounts.multisig.change_threshold(max_threshold)?;
         }
         let new_index = ctx.accounts.multisig.transaction_index;
         // update the change index to deprecate any active transactions
-        ctx.accounts.multisig.set_change_index(new_index)
+        ctx.accounts.multisig.set_change_index(new_index)?;
+
+        // lower the authority policy thresholds the same way
+        let ms_key = ctx.accounts.multisig.key();
+        if ctx.remaining_accounts.len() != usize::from(ctx.accounts.multisig.authority_policy_count) {
+            return err!(MsError::InvalidAuthorityPolicy);
+        }
+        let mut last_authority_index = 0;
+        for policy_info in ctx.remaining_accounts.iter() {
+            if policy_info.owner != ctx.program_id || !policy_info.is_writable {
+                return err!(MsError::InvalidAuthorityPolicy);
+            }
+            let mut policy: Account<AuthorityPolicy> = Account::try_from(policy_info)?;
+            // in order of the authority index, so no policy can be passed twice
+            if policy.ms != ms_key || policy.authority_index <= last_authority_index {
+                return err!(MsError::InvalidAuthorityPolicy);
+            }
+            last_authority_index = policy.authority_index;
+            if policy.threshold > max_threshold {
+                policy.change_threshold(max_threshold)?;
+                policy.exit(ctx.program_id)?;
+
+                emit!(AuthorityPolicyUpdated {
+                    multisig: ms_key,
+                    authority_index: policy.authority_index,
+                    threshold: max_threshold,
+                    change_index: new_index,
+                });
+            }
+        }
+
+        emit!(MemberRemoved {
+            multisig: ctx.accounts.multisig.key(),
+            member: old_member,
+            threshold: ctx.accounts.multisig.threshold,
+            change_index: new_index,
+        });
+        Ok(())
+    }
+
+    /// The instruction for a member to rotate their key, signed by both the old and new key.
+    /// The key is replaced in place (keeping its weight and permissions), and unlike
+    /// removing and adding a member, the change index is not updated. Pending transactions
+    /// passed as remaining accounts have the votes (and creator) of the old key moved to the new key,
+    /// and SpendingLimit accounts passed along with them have the old key replaced in their members.
+    /// The creator of a TransactionMeta (txmeta program) isn't changed, its rent still goes to the old key.
+    pub fn rotate_member_key<'info>(
+        ctx: Context<'_, '_, '_, 'info, RotateMemberKey<'info>>,
+    ) -> Result<()> {
+        let ms_key = ctx.accounts.multisig.key();
+        let old_member = ctx.accounts.old_member.key();
+        let new_member = ctx.accounts.new_member.key();
+        ctx.accounts.multisig.rotate_member(old_member, new_member)?;
+
+        // carry the votes over on the pending transactions, and move the key in the spending limits
+        for account_info in ctx.remaining_accounts.iter() {
+            if account_info.owner != ctx.program_id {
+                return err!(MsError::InvalidInstructionAccount);
+            }
+            let is_spending_limit = account_info
+                .try_borrow_data()?
+                .starts_with(&SpendingLimit::DISCRIMINATOR);
+            if is_spending_limit {
+                let mut spending_limit: Account<SpendingLimit> = Account::try_from(account_info)?;
+                if spending_limit.ms != ms_key {
+                    return err!(MsError::InvalidInstructionAccount);
+                }
+                spending_limit.rotate_member(old_member, new_member)?;
+                spending_limit.exit(ctx.program_id)?;
+                continue;
+            }
+            let mut transaction: Account<MsTransaction> = Account::try_from(account_info)?;
+            if transaction.ms != ms_key {
+                return err!(MsError::InvalidInstructionAccount);
+            }
+            if !matches!(
+                transaction.status,
+                MsTransactionStatus::Draft | MsTransactionStatus::Active | MsTransactionStatus::ExecuteReady
+            ) {
+                return err!(MsError::InvalidTransactionState);
+            }
+            let available_space = account_info.data_len().saturating_sub(8);
+            transaction.rotate_member(old_member, new_member, Clock::get()?.slot, available_space)?;
+            transaction.exit(ctx.program_id)?;
+        }
+
+        emit!(MemberKeyRotated {
+            multisig: ms_key,
+            old_member,
+            new_member,
+        });
+        Ok(())
     }
 
     /// The instruction to change the threshold of the multisig and simultaneously remove a member
@@ -177,8 +445,8 @@ pub mod squads_mpl {
         )?;
 
         // check that the threshold value is valid
-        if ctx.accounts.multisig.keys.len() < usize::from(new_threshold) {
-            let new_threshold: u16 = ctx.accounts.multisig.keys.len().try_into().unwrap();
+        if ctx.accounts.multisig.max_threshold() < new_threshold {
+            let new_threshold: u16 = ctx.accounts.multisig.max_threshold();
             ctx.accounts.multisig.change_threshold(new_threshold)?;
         } else if new_threshold < 1 {
             return err!(MsError::InvalidThreshold);
@@ -187,14 +455,21 @@ pub mod squads_mpl {
         }
         let new_index = ctx.accounts.multisig.transaction_index;
         // update the change index to deprecate any active transactions
-        ctx.accounts.multisig.set_change_index(new_index)
+        ctx.accounts.multisig.set_change_index(new_index)?;
+
+        emit!(ThresholdChanged {
+            multisig: ctx.accounts.multisig.key(),
+            threshold: ctx.accounts.multisig.threshold,
+            change_index: new_index,
+        });
+        Ok(())
     }
 
     /// The instruction to change the threshold of the multisig
     pub fn change_threshold(ctx: Context<MsAuth>, new_threshold: u16) -> Result<()> {
         // if the new threshold value is valid
-        if ctx.accounts.multisig.keys.len() < usize::from(new_threshold) {
-            let new_threshold: u16 = ctx.accounts.multisig.keys.len().try_into().unwrap();
+        if ctx.accounts.multisig.max_threshold() < new_threshold {
+            let new_threshold: u16 = ctx.accounts.multisig.max_threshold();
             ctx.accounts.multisig.change_threshold(new_threshold)?;
         } else if new_threshold < 1 {
             return err!(MsError::InvalidThreshold);
@@ -203,25 +478,763 @@ pub mod squads_mpl {
         }
         let new_index = ctx.accounts.multisig.transaction_index;
         // update the change index to deprecate any active transactions
-        ctx.accounts.multisig.set_change_index(new_index)
-    }
+        ctx.accounts.multisig.set_change_index(new_index)?;
+
+        emit!(ThresholdChanged {
+            multisig: ctx.accounts.multisig.key(),
+            threshold: ctx.accounts.multisig.threshold,
+            change_index: new_index,
+        });
+        Ok(())
+    }
+
+    /// The instruction to change the reject threshold of the multisig, the combined weight
+    /// of rejections that rejects a transaction (ie. a minority veto). A value of 0 disables it,
+    /// so transactions are only rejected once the threshold can no longer be reached.
+    pub fn change_reject_threshold(ctx: Context<MsAuth>, reject_threshold: u16) -> Result<()> {
+        if reject_threshold > ctx.accounts.multisig.max_threshold() {
+            return err!(MsError::InvalidThreshold);
+        }
+        ctx.accounts.multisig.change_reject_threshold(reject_threshold)?;
+        let new_index = ctx.accounts.multisig.transaction_index;
+        // update the change index to deprecate any active transactions
+        ctx.accounts.multisig.set_change_index(new_index)?;
+
+        emit!(RejectThresholdChanged {
+            multisig: ctx.accounts.multisig.key(),
+            reject_threshold,
+            change_index: new_index,
+        });
+        Ok(())
+    }
+
+    /// The instruction to change the time lock of the multisig.
+    /// Transactions that become ExecuteReady will need to wait the time lock
+    /// (in seconds) before they can be executed, giving members a window to cancel.
+    pub fn change_time_lock(ctx: Context<MsAuth>, time_lock: u32) -> Result<()> {
+        ctx.accounts.multisig.change_time_lock(time_lock)?;
+        let new_index = ctx.accounts.multisig.transaction_index;
+        // update the change index to deprecate any active transactions
+        ctx.accounts.multisig.set_change_index(new_index)?;
+
+        emit!(TimeLockChanged {
+            multisig: ctx.accounts.multisig.key(),
+            time_lock,
+            change_index: new_index,
+        });
+        Ok(())
+    }
+
+    /// The instruction to change the default time to live (in seconds) of new transactions.
+    /// A value of 0 means transactions will not expire unless created with an explicit expiry.
+    pub fn change_default_ttl(ctx: Context<MsAuth>, default_ttl: u32) -> Result<()> {
+        ctx.accounts.multisig.change_default_ttl(default_ttl)?;
+        let new_index = ctx.accounts.multisig.transaction_index;
+        // update the change index to deprecate any active transactions
+        ctx.accounts.multisig.set_change_index(new_index)?;
+
+        emit!(DefaultTtlChanged {
+            multisig: ctx.accounts.multisig.key(),
+            default_ttl,
+            change_index: new_index,
+        });
+        Ok(())
+    }
+
+    /// The instruction to create the metadata of a multisig created before the MsMetadata
+    /// account existed. The payer funds the metadata account, and needs to sign the execution.
+    pub fn init_metadata(ctx: Context<InitMetadata>, meta: String) -> Result<()> {
+        ctx.accounts.metadata.init(
+            ctx.accounts.multisig.key(),
+            meta,
+            *ctx.bumps.get("metadata").unwrap(),
+        )
+    }
+
+    /// The instruction to update the metadata of the multisig (ie. name and description).
+    /// The metadata account is resized to fit, with the payer covering any additional rent.
+    pub fn update_metadata(ctx: Context<UpdateMetadata>, meta: String) -> Result<()> {
+        ctx.accounts.metadata.set_meta(meta)
+    }
+
+    /// The instruction to set the recovery config of the multisig. Guardians can propose
+    /// a replacement member set, which takes effect if the guardian threshold approves it
+    /// and no member objects within the delay (at least RecoveryConfig::MIN_DELAY seconds).
+    pub fn set_recovery_config(
+        ctx: Context<MsAuthRealloc>,
+        guardians: Vec<Pubkey>,
+        threshold: u16,
+        delay: u32,
+    ) -> Result<()> {
+        // sort the guardians and remove duplicates
+        let mut guardians = guardians;
+        guardians.sort();
+        guardians.dedup();
+
+        if guardians.is_empty()
+            || guardians.len() > RecoveryConfig::MAX_GUARDIANS
+            || threshold < 1
+            || usize::from(threshold) > guardians.len()
+            || delay < RecoveryConfig::MIN_DELAY
+        {
+            return err!(MsError::InvalidRecoveryConfig);
+        }
+
+        let recovery = RecoveryConfig {
+            guardians: guardians.clone(),
+            threshold,
+            delay,
+            // ties pending recovery proposals to this config
+            change_index: ctx.accounts.multisig.transaction_index,
+        };
+        let curr_size = ctx
+            .accounts
+            .multisig
+            .recovery
+            .as_ref()
+            .map_or(0, |recovery| recovery.size());
+        ctx.accounts
+            .realloc_if_needed(recovery.size().saturating_sub(curr_size))?;
+        ctx.accounts.multisig.set_recovery(Some(recovery))?;
+        let new_index = ctx.accounts.multisig.transaction_index;
+        // update the change index to deprecate any active transactions
+        ctx.accounts.multisig.set_change_index(new_index)?;
+
+        emit!(RecoveryConfigSet {
+            multisig: ctx.accounts.multisig.key(),
+            guardians,
+            threshold,
+            delay,
+            change_index: new_index,
+        });
+        Ok(())
+    }
+
+    /// The instruction to remove the recovery config of the multisig.
+    pub fn remove_recovery_config(ctx: Context<MsAuth>) -> Result<()> {
+        ctx.accounts.multisig.set_recovery(None)?;
+        let new_index = ctx.accounts.multisig.transaction_index;
+        // update the change index to deprecate any active transactions
+        ctx.accounts.multisig.set_change_index(new_index)?;
+
+        emit!(RecoveryConfigRemoved {
+            multisig: ctx.accounts.multisig.key(),
+            change_index: new_index,
+        });
+        Ok(())
+    }
+
+    /// instruction to increase the authority value tracked in the multisig
+    /// Transactions can only be created for authorities up to the tracked index,
+    /// which can also be used to track commonly used authorities in a UI (ie, vault 1, vault 2, etc.)
+    /// An Authority account describing the new authority can be added with register_authority.
+    pub fn add_authority(ctx: Context<MsAuth>) -> Result<()> {
+        ctx.accounts.multisig.add_authority()?;
+
+        emit!(AuthorityAdded {
+            multisig: ctx.accounts.multisig.key(),
+            authority_index: ctx.accounts.multisig.authority_index,
+        });
+        Ok(())
+    }
+
+    /// The instruction to register an authority (vault) of the multisig, creating its
+    /// Authority account with a label describing what it is used for. The authority
+    /// must already be tracked by the multisig (see add_authority), and is enabled.
+    /// The payer funds the Authority account, and needs to sign the execution.
+    pub fn register_authority(
+        ctx: Context<RegisterAuthority>,
+        authority_index: u32,
+        label: String,
+    ) -> Result<()> {
+        // authority 0 is reserved for internal transactions
+        if authority_index < 1 || authority_index > u32::from(ctx.accounts.multisig.authority_index) {
+            return err!(MsError::InvalidAuthorityIndex);
+        }
+        ctx.accounts.authority.init(
+            ctx.accounts.multisig.key(),
+            authority_index,
+            label,
+            Clock::get()?.unix_timestamp,
+            *ctx.bumps.get("authority").unwrap(),
+        )?;
+
+        emit!(AuthorityUpdated {
+            multisig: ctx.accounts.multisig.key(),
+            authority_index,
+            label: ctx.accounts.authority.label.clone(),
+            enabled: ctx.accounts.authority.enabled,
+        });
+        Ok(())
+    }
+
+    /// The instruction to update the label of an authority, and enable or disable it.
+    /// Transactions can't be created for a disabled authority, existing transactions
+    /// are not affected.
+    pub fn update_authority(
+        ctx: Context<UpdateAuthority>,
+        label: String,
+        enabled: bool,
+    ) -> Result<()> {
+        if ctx.accounts.authority.enabled != enabled {
+            ctx.accounts.multisig.set_authority_enabled(enabled)?;
+            ctx.accounts.authority.enabled = enabled;
+        }
+        ctx.accounts.authority.set_label(label)?;
+
+        emit!(AuthorityUpdated {
+            multisig: ctx.accounts.multisig.key(),
+            authority_index: ctx.accounts.authority.authority_index,
+            label: ctx.accounts.authority.label.clone(),
+            enabled,
+        });
+        Ok(())
+    }
+
+    /// The instruction to add a policy for an authority (vault), which overrides
+    /// the threshold of the multisig for transactions using that authority.
+    /// The payer funds the AuthorityPolicy account, and needs to sign the execution.
+    pub fn add_authority_policy(
+        ctx: Context<AddAuthorityPolicy>,
+        authority_index: u32,
+        threshold: u16,
+    ) -> Result<()> {
+        // authority 0 is reserved for internal transactions, which always use the multisig threshold
+        if authority_index < 1 {
+            return err!(MsError::InvalidAuthorityIndex);
+        }
+        if threshold < 1 || threshold > ctx.accounts.multisig.max_threshold() {
+            return err!(MsError::InvalidThreshold);
+        }
+        ctx.accounts.policy.init(
+            ctx.accounts.multisig.key(),
+            authority_index,
+            threshold,
+            *ctx.bumps.get("policy").unwrap(),
+        )?;
+        ctx.accounts.multisig.add_authority_policy()?;
+        let new_index = ctx.accounts.multisig.transaction_index;
+        // update the change index to deprecate any active transactions
+        ctx.accounts.multisig.set_change_index(new_index)?;
+
+        emit!(AuthorityPolicyUpdated {
+            multisig: ctx.accounts.multisig.key(),
+            authority_index,
+            threshold,
+            change_index: new_index,
+        });
+        Ok(())
+    }
+
+    /// The instruction to change the threshold of an authority policy
+    pub fn change_authority_policy(
+        ctx: Context<ChangeAuthorityPolicy>,
+        threshold: u16,
+    ) -> Result<()> {
+        if threshold < 1 || threshold > ctx.accounts.multisig.max_threshold() {
+            return err!(MsError::InvalidThreshold);
+        }
+        ctx.accounts.policy.change_threshold(threshold)?;
+        let new_index = ctx.accounts.multisig.transaction_index;
+        // update the change index to deprecate any active transactions
+        ctx.accounts.multisig.set_change_index(new_index)?;
+
+        emit!(AuthorityPolicyUpdated {
+            multisig: ctx.accounts.multisig.key(),
+            authority_index: ctx.accounts.policy.authority_index,
+            threshold,
+            change_index: new_index,
+        });
+        Ok(())
+    }
+
+    /// The instruction to remove an authority policy, the authority will
+    /// use the multisig threshold again.
+    pub fn remove_authority_policy(ctx: Context<RemoveAuthorityPolicy>) -> Result<()> {
+        ctx.accounts.multisig.remove_authority_policy()?;
+        let new_index = ctx.accounts.multisig.transaction_index;
+        // update the change index to deprecate any active transactions
+        ctx.accounts.multisig.set_change_index(new_index)?;
+
+        emit!(AuthorityPolicyRemoved {
+            multisig: ctx.accounts.multisig.key(),
+            authority_index: ctx.accounts.policy.authority_index,
+            change_index: new_index,
+        });
+        Ok(())
+    }
+
+    /// The instruction to add a program policy to an authority (vault), which limits the
+    /// programs that instructions of transactions using that authority can invoke, to the
+    /// listed programs (Allow) or to any but the listed programs (Deny).
+    /// The payer funds the ProgramPolicy account, and needs to sign the execution.
+    pub fn add_program_policy(
+        ctx: Context<AddProgramPolicy>,
+        authority_index: u32,
+        mode: ProgramPolicyMode,
+        programs: Vec<Pubkey>,
+    ) -> Result<()> {
+        // authority 0 is reserved for internal transactions, which can only invoke this program
+        if authority_index < 1 {
+            return err!(MsError::InvalidAuthorityIndex);
+        }
+        ctx.accounts.policy.init(
+            ctx.accounts.multisig.key(),
+            authority_index,
+            mode,
+            programs,
+            *ctx.bumps.get("policy").unwrap(),
+        )?;
+        ctx.accounts.multisig.add_program_policy()?;
+        let new_index = ctx.accounts.multisig.transaction_index;
+        // update the change index to deprecate any active transactions
+        ctx.accounts.multisig.set_change_index(new_index)?;
+
+        emit!(ProgramPolicyUpdated {
+            multisig: ctx.accounts.multisig.key(),
+            authority_index,
+            mode,
+            programs: ctx.accounts.policy.programs.clone(),
+            change_index: new_index,
+        });
+        Ok(())
+    }
+
+    /// The instruction to change the mode and programs of a program policy.
+    /// Attached instructions are checked against the policy again when executed.
+    pub fn change_program_policy(
+        ctx: Context<ChangeProgramPolicy>,
+        mode: ProgramPolicyMode,
+        programs: Vec<Pubkey>,
+    ) -> Result<()> {
+        ctx.accounts.policy.set_programs(mode, programs)?;
+        let new_index = ctx.accounts.multisig.transaction_index;
+        // update the change index to deprecate any active transactions
+        ctx.accounts.multisig.set_change_index(new_index)?;
+
+        emit!(ProgramPolicyUpdated {
+            multisig: ctx.accounts.multisig.key(),
+            authority_index: ctx.accounts.policy.authority_index,
+            mode,
+            programs: ctx.accounts.policy.programs.clone(),
+            change_index: new_index,
+        });
+        Ok(())
+    }
+
+    /// The instruction to remove a program policy, the authority can
+    /// invoke any program again.
+    pub fn remove_program_policy(ctx: Context<RemoveProgramPolicy>) -> Result<()> {
+        ctx.accounts.multisig.remove_program_policy()?;
+        let new_index = ctx.accounts.multisig.transaction_index;
+        // update the change index to deprecate any active transactions
+        ctx.accounts.multisig.set_change_index(new_index)?;
+
+        emit!(ProgramPolicyRemoved {
+            multisig: ctx.accounts.multisig.key(),
+            authority_index: ctx.accounts.policy.authority_index,
+            change_index: new_index,
+        });
+        Ok(())
+    }
+
+    /// The instruction to add a spending limit to an authority (vault).
+    /// The members of the spending limit can transfer up to the amount of the mint
+    /// (or native SOL if the mint is the default pubkey) from the authority per period,
+    /// without creating a transaction. The payer funds the SpendingLimit account,
+    /// and needs to sign the execution.
+    pub fn add_spending_limit(
+        ctx: Context<AddSpendingLimit>,
+        authority_index: u32,
+        mint: Pubkey,
+        amount: u64,
+        period: i64,
+        members: Vec<Pubkey>,
+    ) -> Result<()> {
+        // authority 0 is the multisig itself, which can't be spent from
+        if authority_index < 1 || authority_index > u32::from(ctx.accounts.multisig.authority_index) {
+            return err!(MsError::InvalidAuthorityIndex);
+        }
+        if amount < 1 || period < 0 {
+            return err!(MsError::InvalidSpendingLimit);
+        }
+
+        // sort the members and remove duplicates
+        let mut members = members;
+        members.sort();
+        members.dedup();
+        if members.is_empty() {
+            return err!(MsError::EmptyMembers);
+        }
+        // only multisig members can use the spending limit
+        if members.iter().any(|m| ctx.accounts.multisig.is_member(*m).is_none()) {
+            return err!(MsError::KeyNotInMultisig);
+        }
+
+        let (_, authority_bump) = Pubkey::find_program_address(
+            &[
+                b"squad",
+                ctx.accounts.multisig.key().as_ref(),
+                &authority_index.to_le_bytes(),
+                b"authority",
+            ],
+            ctx.program_id,
+        );
+        ctx.accounts.spending_limit.init(
+            ctx.accounts.multisig.key(),
+            authority_index,
+            authority_bump,
+            mint,
+            *ctx.bumps.get("spending_limit").unwrap(),
+        )?;
+        ctx.accounts.spending_limit.set_limit(
+            amount,
+            period,
+            members.clone(),
+            Clock::get()?.unix_timestamp,
+        )?;
+
+        emit!(SpendingLimitAdded {
+            multisig: ctx.accounts.multisig.key(),
+            spending_limit: ctx.accounts.spending_limit.key(),
+            authority_index,
+            mint,
+            amount,
+            period,
+            members,
+        });
+        Ok(())
+    }
+
+    /// The instruction to remove a spending limit from the multisig
+    pub fn remove_spending_limit(ctx: Context<RemoveSpendingLimit>) -> Result<()> {
+        emit!(SpendingLimitRemoved {
+            multisig: ctx.accounts.multisig.key(),
+            spending_limit: ctx.accounts.spending_limit.key(),
+            authority_index: ctx.accounts.spending_limit.authority_index,
+        });
+        Ok(())
+    }
+
+    /// Instruction to transfer native SOL from an authority (vault) within a spending limit.
+    /// Can be used by any member of the spending limit without creating a transaction.
+    /// The authority can't be disabled.
+    pub fn use_spending_limit(ctx: Context<UseSpendingLimit>, amount: u64) -> Result<()> {
+        // spending limits can't be used on a disabled authority
+        Authority::check_enabled(
+            &ctx.accounts.multisig,
+            ctx.accounts.spending_limit.authority_index,
+            ctx.remaining_accounts,
+        )?;
+        let spending_limit = &mut ctx.accounts.spending_limit;
+        spending_limit.reset_if_needed(Clock::get()?.unix_timestamp)?;
+        spending_limit.spend(amount)?;
+
+        let ms_key = ctx.accounts.multisig.key();
+        let authority_seeds = [
+            b"squad",
+            ms_key.as_ref(),
+            &spending_limit.authority_index.to_le_bytes(),
+            b"authority",
+            &[spending_limit.authority_bump],
+        ];
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.authority.to_account_info(),
+                    to: ctx.accounts.destination.to_account_info(),
+                },
+                &[&authority_seeds],
+            ),
+            amount,
+        )?;
+
+        emit!(SpendingLimitUsed {
+            multisig: ms_key,
+            spending_limit: ctx.accounts.spending_limit.key(),
+            member: ctx.accounts.member.key(),
+            destination: ctx.accounts.destination.key(),
+            amount,
+            remaining_amount: ctx.accounts.spending_limit.remaining_amount,
+        });
+        Ok(())
+    }
+
+    /// Instruction to transfer tokens from an authority (vault) within a spending limit.
+    /// Can be used by any member of the spending limit without creating a transaction.
+    /// The authority can't be disabled.
+    pub fn use_token_spending_limit(ctx: Context<UseTokenSpendingLimit>, amount: u64) -> Result<()> {
+        // spending limits can't be used on a disabled authority
+        Authority::check_enabled(
+            &ctx.accounts.multisig,
+            ctx.accounts.spending_limit.authority_index,
+            ctx.remaining_accounts,
+        )?;
+        let spending_limit = &mut ctx.accounts.spending_limit;
+        spending_limit.reset_if_needed(Clock::get()?.unix_timestamp)?;
+        spending_limit.spend(amount)?;
+
+        let ms_key = ctx.accounts.multisig.key();
+        let authority_seeds = [
+            b"squad",
+            ms_key.as_ref(),
+            &spending_limit.authority_index.to_le_bytes(),
+            b"authority",
+            &[spending_limit.authority_bump],
+        ];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token::Transfer {
+                    from: ctx.accounts.authority_token_account.to_account_info(),
+                    to: ctx.accounts.destination_token_account.to_account_info(),
+                    authority: ctx.accounts.authority.to_account_info(),
+                },
+                &[&authority_seeds],
+            ),
+            amount,
+        )?;
+
+        emit!(SpendingLimitUsed {
+            multisig: ms_key,
+            spending_limit: ctx.accounts.spending_limit.key(),
+            member: ctx.accounts.member.key(),
+            destination: ctx.accounts.destination_token_account.key(),
+            amount,
+            remaining_amount: ctx.accounts.spending_limit.remaining_amount,
+        });
+        Ok(())
+    }
+
+    /// Instruction to migrate a multisig created with the legacy Ms layout.
+    /// Reallocates the account so the trailing settings fields fit, with the
+    /// member covering any additional rent, and clears any stale bytes after
+    /// the keys so the new settings default to zero values. This is a no-op if
+    /// the multisig already uses the current layout. Every other instruction requires
+    /// a migrated multisig, so no settings can be set before the migration.
+    pub fn migrate_multisig(ctx: Context<MigrateMultisig>) -> Result<()> {
+        let multisig_account_info = ctx.accounts.multisig.to_account_info();
+        let mut legacy_ms = {
+            let data = multisig_account_info.try_borrow_data()?;
+            if data.len() < 8 || data[..8] != Ms::DISCRIMINATOR {
+                return err!(MsError::InvalidInstructionAccount);
+            }
+            LegacyMs::deserialize(&mut &data[8..])?
+        };
+
+        // make sure this is the multisig PDA
+        let ms_pda = Pubkey::create_program_address(
+            &[
+                b"squad",
+                legacy_ms.create_key.as_ref(),
+                b"multisig",
+                &[legacy_ms.bump],
+            ],
+            ctx.program_id,
+        )
+        .map_err(|_| MsError::InvalidInstructionAccount)?;
+        if &ms_pda != multisig_account_info.key {
+            return err!(MsError::InvalidInstructionAccount);
+        }
+
+        // only members can migrate the multisig
+        if legacy_ms.keys.binary_search(ctx.accounts.member.key).is_err() {
+            return err!(MsError::KeyNotInMultisig);
+        }
+
+        // the settings of a migrated multisig must not be reset
+        if legacy_ms.migrated {
+            return Ok(());
+        }
+
+        let curr_data_size = multisig_account_info.data_len();
+        let needed_len = Ms::SIZE_WITHOUT_MEMBERS + (legacy_ms.keys.len() * 32);
+        if curr_data_size < needed_len {
+            // transfer any lamports needed to keep the account rent exempt
+            let rent_exempt_lamports = ctx.accounts.rent.minimum_balance(needed_len).max(1);
+            let top_up_lamports =
+                rent_exempt_lamports.saturating_sub(multisig_account_info.lamports());
+            if top_up_lamports > 0 {
+                system_program::transfer(
+                    CpiContext::new(
+                        ctx.accounts.system_program.to_account_info(),
+                        system_program::Transfer {
+                            from: ctx.accounts.member.to_account_info(),
+                            to: multisig_account_info.clone(),
+                        },
+                    ),
+                    top_up_lamports,
+                )?;
+            }
+            AccountInfo::realloc(&multisig_account_info, needed_len, true)?;
+        }
+
+        // mark the multisig as migrated and zero everything after the keys,
+        // removed members leave stale key bytes that would be read as settings
+        legacy_ms.migrated = true;
+        let legacy_data = legacy_ms.try_to_vec()?;
+        let mut data = multisig_account_info.try_borrow_mut_data()?;
+        data[8..8 + legacy_data.len()].copy_from_slice(&legacy_data);
+        data[8 + legacy_data.len()..].fill(0);
+        Ok(())
+    }
+
+    /// Instruction for a guardian to propose a replacement member set and threshold.
+    /// The proposal needs to be approved by the guardian threshold, after which
+    /// the members have the recovery delay to object before it can be executed.
+    pub fn propose_recovery(
+        ctx: Context<ProposeRecovery>,
+        new_members: Vec<Pubkey>,
+        new_threshold: u16,
+    ) -> Result<()> {
+        // sort the members and remove duplicates
+        let mut new_members = new_members;
+        new_members.sort();
+        new_members.dedup();
+
+        if new_members.is_empty() {
+            return err!(MsError::EmptyMembers);
+        }
+        if new_members.len() > usize::from(u16::MAX) {
+            return err!(MsError::MaxMembersReached);
+        }
+        if new_threshold < 1 || usize::from(new_threshold) > new_members.len() {
+            return err!(MsError::InvalidThreshold);
+        }
+
+        let recovery = ctx
+            .accounts
+            .multisig
+            .recovery
+            .as_ref()
+            .ok_or(MsError::InvalidRecoveryConfig)?;
+        let guardian = ctx.accounts.guardian.key();
+        let proposal = &mut ctx.accounts.proposal;
+        proposal.init(
+            ctx.accounts.multisig.key(),
+            guardian,
+            new_members.clone(),
+            new_threshold,
+            recovery.change_index,
+            *ctx.bumps.get("proposal").unwrap(),
+        )?;
+        proposal.approve(guardian, recovery, Clock::get()?.unix_timestamp)?;
+
+        emit!(RecoveryProposed {
+            multisig: ctx.accounts.multisig.key(),
+            guardian,
+            new_members,
+            new_threshold,
+        });
+        Ok(())
+    }
+
+    /// Instruction for a guardian to approve a recovery proposal. Once the guardian
+    /// threshold is reached, the recovery delay starts.
+    pub fn approve_recovery(ctx: Context<ApproveRecovery>) -> Result<()> {
+        let recovery = ctx
+            .accounts
+            .multisig
+            .recovery
+            .as_ref()
+            .ok_or(MsError::InvalidRecoveryConfig)?;
+        ctx.accounts.proposal.check_config(recovery)?;
+        let guardian = ctx.accounts.guardian.key();
+        ctx.accounts
+            .proposal
+            .approve(guardian, recovery, Clock::get()?.unix_timestamp)?;
+
+        emit!(RecoveryApproved {
+            multisig: ctx.accounts.multisig.key(),
+            guardian,
+            ready_at: ctx.accounts.proposal.ready_at,
+        });
+        Ok(())
+    }
+
+    /// Instruction for a member to object to a recovery proposal, which closes it.
+    pub fn cancel_recovery(ctx: Context<CancelRecovery>) -> Result<()> {
+        emit!(RecoveryCancelled {
+            multisig: ctx.accounts.multisig.key(),
+            member: ctx.accounts.member.key(),
+        });
+        Ok(())
+    }
+
+    /// Instruction to execute a recovery proposal once the delay has passed without
+    /// an objection. The members and threshold of the multisig are replaced, resetting
+    /// any weights and permissions, and the change index is updated to deprecate any
+    /// active transactions.
+    pub fn execute_recovery(ctx: Context<ExecuteRecovery>) -> Result<()> {
+        let recovery = ctx
+            .accounts
+            .multisig
+            .recovery
+            .as_ref()
+            .ok_or(MsError::InvalidRecoveryConfig)?;
+        ctx.accounts.proposal.check_config(recovery)?;
+        if !ctx
+            .accounts
+            .proposal
+            .is_ready(recovery, Clock::get()?.unix_timestamp)
+        {
+            return err!(MsError::RecoveryNotReady);
+        }
+
+        // make sure the multisig has room for the new members
+        let multisig_account_info = ctx.accounts.multisig.to_account_info();
+        let needed_len = Ms::SIZE_WITHOUT_MEMBERS
+            + (ctx.accounts.proposal.new_members.len() * 32)
+            + recovery.size();
+        if multisig_account_info.data_len() < needed_len {
+            // transfer any lamports needed to keep the account rent exempt
+            let rent_exempt_lamports = ctx.accounts.rent.minimum_balance(needed_len).max(1);
+            let top_up_lamports =
+                rent_exempt_lamports.saturating_sub(multisig_account_info.lamports());
+            if top_up_lamports > 0 {
+                system_program::transfer(
+                    CpiContext::new(
+                        ctx.accounts.system_program.to_account_info(),
+                        system_program::Transfer {
+                            from: ctx.accounts.payer.to_account_info(),
+                            to: multisig_account_info.clone(),
+                        },
+                    ),
+                    top_up_lamports,
+                )?;
+            }
+            AccountInfo::realloc(&multisig_account_info, needed_len, false)?;
+        }
+
+        let new_members = ctx.accounts.proposal.new_members.clone();
+        let new_threshold = ctx.accounts.proposal.new_threshold;
+        ctx.accounts.multisig.replace_members(new_members.clone(), new_threshold)?;
+        let new_index = ctx.accounts.multisig.transaction_index;
+        // update the change index to deprecate any active transactions
+        ctx.accounts.multisig.set_change_index(new_index)?;
 
-    /// instruction to increase the authority value tracked in the multisig
-    /// This is optional, as authorities are simply PDAs, however it may be helpful
-    /// to keep track of commonly used authorities in a UI.
-    /// This has no functional impact on the multisig or its functionality, but
-    /// can be used to track commonly used authorities (ie, vault 1, vault 2, etc.)
-    pub fn add_authority(ctx: Context<MsAuth>) -> Result<()> {
-        ctx.accounts.multisig.add_authority()
+        emit!(RecoveryExecuted {
+            multisig: ctx.accounts.multisig.key(),
+            new_members,
+            threshold: new_threshold,
+            change_index: new_index,
+        });
+        Ok(())
     }
 
     /// Instruction to create a multisig transaction.
     /// Each transaction is tied to a single authority, and must be specified when
     /// creating the instruction below. authority 0 is reserved for internal
     /// instructions, whereas authorities 1 or greater refer to a vault,
-    /// upgrade authority, or other.
+    /// upgrade authority, or other. The authority can't be above the authority
+    /// index tracked by the multisig, or disabled.
     pub fn create_transaction(ctx: Context<CreateTransaction>, authority_index: u32) -> Result<()> {
         let ms = &mut ctx.accounts.multisig;
+        if authority_index > u32::from(ms.authority_index) {
+            return err!(MsError::InvalidAuthorityIndex);
+        }
+        Authority::check_enabled(ms, authority_index, ctx.remaining_accounts)?;
         let authority_bump = match authority_index {
             1.. => {
                 let (_, auth_bump) = Pubkey::find_program_address(
@@ -246,13 +1259,80 @@ pub mod squads_mpl {
             *ctx.bumps.get("transaction").unwrap(),
             authority_index,
             authority_bump,
-        )
+        )?;
+        // transactions expire based on the multisig default ttl, if set
+        ctx.accounts.transaction.expires_at = ms.default_expiry(Clock::get()?.unix_timestamp);
+
+        emit!(TransactionCreated {
+            multisig: ms.key(),
+            transaction: ctx.accounts.transaction.key(),
+            transaction_index: ms.transaction_index,
+            authority_index,
+            creator: ctx.accounts.creator.key(),
+            status: ctx.accounts.transaction.status.clone(),
+        });
+        Ok(())
+    }
+
+    /// Instruction to create a multisig transaction with optional settings.
+    /// An explicit expiry must be in the future, and if the multisig has a default ttl
+    /// it can't be later than the default expiry.
+    /// Ephemeral signers are PDAs derived from [b"squad", transaction, index, b"ephemeral_signer"],
+    /// for index 0 up to the number declared (at most MAX_EPHEMERAL_SIGNERS), and are added as
+    /// signers when the transaction is executed - eg. for creating accounts that need to sign
+    /// for their own creation.
+    pub fn create_transaction_with_options<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateTransaction<'info>>,
+        authority_index: u32,
+        expires_at: Option<i64>,
+        ephemeral_signers: Option<u8>,
+    ) -> Result<()> {
+        create_transaction(
+            Context::new(
+                ctx.program_id,
+                ctx.accounts,
+                ctx.remaining_accounts,
+                ctx.bumps.clone(),
+            ),
+            authority_index,
+        )?;
+
+        if let Some(expires_at) = expires_at {
+            let now = Clock::get()?.unix_timestamp;
+            if expires_at <= now {
+                return err!(MsError::InvalidExpiry);
+            }
+            // the default expiry acts as the upper bound if the multisig has one
+            let default_expiry = ctx.accounts.multisig.default_expiry(now);
+            if default_expiry > 0 && expires_at > default_expiry {
+                return err!(MsError::InvalidExpiry);
+            }
+            ctx.accounts.transaction.expires_at = expires_at;
+        }
+
+        if let Some(ephemeral_signers) = ephemeral_signers {
+            // each ephemeral signer is derived again when executing
+            if ephemeral_signers > MsTransaction::MAX_EPHEMERAL_SIGNERS {
+                return err!(MsError::TooManyEphemeralSigners);
+            }
+            ctx.accounts.transaction.ephemeral_signers = ephemeral_signers;
+        }
+        Ok(())
     }
 
     /// Instruction to set the state of a transaction "active".
     /// "active" transactions can then be signed off by multisig members
     pub fn activate_transaction(ctx: Context<ActivateTransaction>) -> Result<()> {
-        ctx.accounts.transaction.activate()
+        ctx.accounts.transaction.activate()?;
+
+        emit!(TransactionActivated {
+            multisig: ctx.accounts.multisig.key(),
+            transaction: ctx.accounts.transaction.key(),
+            transaction_index: ctx.accounts.transaction.transaction_index,
+            creator: ctx.accounts.creator.key(),
+            status: ctx.accounts.transaction.status.clone(),
+        });
+        Ok(())
     }
 
     /// Instruction to attach an instruction to a transaction.
@@ -268,6 +1348,9 @@ pub mod squads_mpl {
         if tx.authority_index == 0 && &incoming_instruction.program_id != ctx.program_id {
             return err!(MsError::InvalidAuthorityIndex);
         }
+        // the authority's program policy (if any) must allow the invoked program
+        let program_policy = ProgramPolicy::load(&ctx.accounts.multisig, tx.authority_index, ctx.remaining_accounts)?;
+        ProgramPolicy::check(&program_policy, &incoming_instruction.program_id)?;
         tx.instruction_index = tx.instruction_index.checked_add(1).unwrap();
         ctx.accounts.instruction.init(
             tx.instruction_index,
@@ -276,69 +1359,313 @@ pub mod squads_mpl {
         )
     }
 
-    /// Instruction to approve a transaction on behalf of a member.
-    /// The transaction must have an "active" status
-    pub fn approve_transaction(ctx: Context<VoteTransaction>) -> Result<()> {
-        // if they have previously voted to reject, remove that item (change vote check)
-        if let Some(ind) = ctx
-            .accounts
-            .transaction
-            .has_voted_reject(ctx.accounts.member.key())
-        {
-            ctx.accounts.transaction.remove_reject(ind)?;
+    /// Instruction to start uploading an instruction that is too large for add_instruction.
+    /// Creates the instruction buffer of the transaction, sized for data_len bytes of instruction data,
+    /// which is then uploaded with append_instruction_data. Only one buffer can be used at a time.
+    /// Transactions must be in the "draft" status.
+    pub fn create_instruction_buffer(
+        ctx: Context<CreateInstructionBuffer>,
+        instruction_program_id: Pubkey,
+        keys: Vec<MsAccountMeta>,
+        data_len: u32,
+    ) -> Result<()> {
+        // make sure internal transactions have a matching program id for attached instructions
+        if ctx.accounts.transaction.authority_index == 0 && &instruction_program_id != ctx.program_id {
+            return err!(MsError::InvalidAuthorityIndex);
         }
+        // the authority's program policy (if any) must allow the invoked program
+        let program_policy = ProgramPolicy::load(
+            &ctx.accounts.multisig,
+            ctx.accounts.transaction.authority_index,
+            ctx.remaining_accounts,
+        )?;
+        ProgramPolicy::check(&program_policy, &instruction_program_id)?;
+        ctx.accounts.instruction_buffer.init(
+            instruction_program_id,
+            keys,
+            data_len,
+            *ctx.bumps.get("instruction_buffer").unwrap(),
+        )
+    }
 
-        // if they haven't already approved
-        if ctx
-            .accounts
-            .transaction
-            .has_voted_approve(ctx.accounts.member.key())
-            .is_none()
-        {
-            ctx.accounts.transaction.sign(ctx.accounts.member.key())?;
+    /// Instruction to append the next part of the instruction data to the instruction buffer.
+    /// Transactions must be in the "draft" status.
+    pub fn append_instruction_data(ctx: Context<AppendInstructionData>, data: Vec<u8>) -> Result<()> {
+        ctx.accounts.instruction_buffer.append(&data)
+    }
+
+    /// Instruction to attach the uploaded instruction to the transaction once all of its data
+    /// has been appended. The instruction is saved in an MsInstruction at the next instruction
+    /// index, the same as add_instruction, and the instruction buffer is closed.
+    /// Transactions must be in the "draft" status.
+    pub fn finalize_instruction(ctx: Context<FinalizeInstruction>) -> Result<()> {
+        let tx = &mut ctx.accounts.transaction;
+        tx.instruction_index = tx.instruction_index.checked_add(1).unwrap();
+        ctx.accounts.instruction.init(
+            tx.instruction_index,
+            ctx.accounts.instruction_buffer.to_incoming_instruction(),
+            *ctx.bumps.get("instruction").unwrap(),
+        )
+    }
+
+    /// Instruction for the creator of a transaction to close its instruction buffer without
+    /// finalizing it, returning the lamports to the creator. This works in any transaction status.
+    pub fn close_instruction_buffer(_ctx: Context<CloseInstructionBuffer>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Instruction to replace an instruction attached to a transaction.
+    /// Transactions must be in the "draft" status, and the instruction keeps its
+    /// instruction_index, so the order of execution is unchanged.
+    /// Only MsInstruction accounts can be replaced, not batches or lookup instructions.
+    pub fn replace_instruction(
+        ctx: Context<ReplaceInstruction>,
+        incoming_instruction: IncomingInstruction,
+    ) -> Result<()> {
+        // make sure internal transactions have a matching program id for attached instructions
+        if ctx.accounts.transaction.authority_index == 0 && &incoming_instruction.program_id != ctx.program_id {
+            return err!(MsError::InvalidAuthorityIndex);
+        }
+        // the authority's program policy (if any) must allow the invoked program
+        let program_policy = ProgramPolicy::load(
+            &ctx.accounts.multisig,
+            ctx.accounts.transaction.authority_index,
+            ctx.remaining_accounts,
+        )?;
+        ProgramPolicy::check(&program_policy, &incoming_instruction.program_id)?;
+        let instruction = &mut ctx.accounts.instruction;
+        let (instruction_index, bump) = (instruction.instruction_index, instruction.bump);
+        instruction.init(instruction_index, incoming_instruction, bump)
+    }
+
+    /// Instruction to remove an instruction attached to a transaction.
+    /// Transactions must be in the "draft" status. The MsInstruction accounts after the
+    /// removed one must be passed in order as remaining accounts, and each is moved down
+    /// one index so the instruction PDAs stay sequential. The last instruction account is
+    /// closed, and the instruction_index of the transaction is decreased.
+    /// Only MsInstruction accounts can be removed or moved, not batches or lookup instructions.
+    pub fn remove_instruction<'info>(
+        ctx: Context<'_, '_, '_, 'info, RemoveInstruction<'info>>,
+        instruction_index: u8,
+    ) -> Result<()> {
+        let transaction_key = ctx.accounts.transaction.key();
+        let last_index = ctx.accounts.transaction.instruction_index;
+        // every instruction after the removed one has to be moved
+        if ctx.remaining_accounts.len() != usize::from(last_index.saturating_sub(instruction_index)) {
+            return err!(MsError::InvalidInstructionAccount);
+        }
+
+        let mut following: Vec<Account<MsInstruction>> = Vec::with_capacity(ctx.remaining_accounts.len());
+        let mut expected_index = instruction_index;
+        for ix_info in ctx.remaining_accounts.iter() {
+            // batches and lookup instructions don't deserialize as an MsInstruction
+            let ms_ix: Account<MsInstruction> = Account::try_from(ix_info)?;
+            expected_index = expected_index.checked_add(1).unwrap();
+            let ix_pda = Pubkey::create_program_address(
+                &[
+                    b"squad",
+                    transaction_key.as_ref(),
+                    &expected_index.to_le_bytes(),
+                    b"instruction",
+                    &[ms_ix.bump],
+                ],
+                ctx.program_id,
+            )
+            .map_err(|_| MsError::InvalidInstructionAccount)?;
+            if &ix_pda != ix_info.key || !ix_info.is_writable {
+                return err!(MsError::InvalidInstructionAccount);
+            }
+            following.push(ms_ix);
         }
 
-        // if current number of signers reaches threshold, mark the transaction as execute ready
-        if ctx.accounts.transaction.approved.len() >= usize::from(ctx.accounts.multisig.threshold) {
-            ctx.accounts.transaction.ready_to_execute()?;
+        // each account takes the instruction of the next one, keeping its own index and bump
+        let moved_instructions: Vec<IncomingInstruction> = following
+            .iter()
+            .map(|ms_ix| IncomingInstruction {
+                program_id: ms_ix.program_id,
+                keys: ms_ix.keys.clone(),
+                data: ms_ix.data.clone(),
+            })
+            .collect();
+        let creator = ctx.accounts.creator.to_account_info();
+        if let Some(next_instruction) = moved_instructions.first() {
+            ctx.accounts
+                .resize_instruction(&ctx.accounts.instruction.to_account_info(), next_instruction)?;
+            let bump = ctx.accounts.instruction.bump;
+            ctx.accounts
+                .instruction
+                .init(instruction_index, next_instruction.clone(), bump)?;
+        } else {
+            ctx.accounts.instruction.close(creator.clone())?;
         }
+        for (ind, next_instruction) in moved_instructions.iter().enumerate().skip(1) {
+            let ms_ix = &mut following[ind - 1];
+            ctx.accounts
+                .resize_instruction(&ms_ix.to_account_info(), next_instruction)?;
+            let (ix_index, bump) = (ms_ix.instruction_index, ms_ix.bump);
+            ms_ix.init(ix_index, next_instruction.clone(), bump)?;
+            ms_ix.exit(ctx.program_id)?;
+        }
+        if let Some(last_instruction) = following.last() {
+            last_instruction.close(creator)?;
+        }
+
+        let tx = &mut ctx.accounts.transaction;
+        tx.instruction_index = tx.instruction_index.checked_sub(1).unwrap();
         Ok(())
     }
 
-    /// Instruction to reject a transaction.
-    /// The transaction must have an "active" status.
-    pub fn reject_transaction(ctx: Context<VoteTransaction>) -> Result<()> {
-        // if they have previously voted to approve, remove that item (change vote check)
-        if let Some(ind) = ctx
-            .accounts
-            .transaction
-            .has_voted_approve(ctx.accounts.member.key())
+    /// Instruction to attach several instructions to a transaction at once.
+    /// The instructions are stored in a single MsInstructionBatch account, which uses
+    /// the PDA of the first instruction index it holds. The instruction_index of the
+    /// transaction is increased by the number of instructions in the batch.
+    /// Transactions must be in the "draft" status, and batched instructions can
+    /// only be executed with execute_transaction.
+    pub fn add_instructions(
+        ctx: Context<AddInstructions>,
+        incoming_instructions: Vec<IncomingInstruction>,
+    ) -> Result<()> {
+        let program_id = ctx.program_id;
+        let tx = &mut ctx.accounts.transaction;
+        if incoming_instructions.is_empty() {
+            return err!(MsError::InvalidNumberOfAccounts);
+        }
+        // make sure internal transactions have a matching program id for attached instructions
+        if tx.authority_index == 0
+            && incoming_instructions.iter().any(|ix| &ix.program_id != program_id)
         {
-            ctx.accounts.transaction.remove_approve(ind)?;
+            return err!(MsError::InvalidAuthorityIndex);
         }
+        // the authority's program policy (if any) must allow the invoked program
+        let program_policy = ProgramPolicy::load(&ctx.accounts.multisig, tx.authority_index, ctx.remaining_accounts)?;
+        for ix in incoming_instructions.iter() {
+            ProgramPolicy::check(&program_policy, &ix.program_id)?;
+        }
+        let first_index = tx.instruction_index.checked_add(1).unwrap();
+        let batch_len: u8 = incoming_instructions
+            .len()
+            .try_into()
+            .map_err(|_| MsError::MaxInstructionsReached)?;
+        tx.instruction_index = tx
+            .instruction_index
+            .checked_add(batch_len)
+            .ok_or(MsError::MaxInstructionsReached)?;
+        ctx.accounts.instruction_batch.init(
+            first_index,
+            incoming_instructions,
+            *ctx.bumps.get("instruction_batch").unwrap(),
+        )
+    }
 
-        // check if they haven't already voted reject
-        if ctx
-            .accounts
-            .transaction
-            .has_voted_reject(ctx.accounts.member.key())
-            .is_none()
+    /// Instruction to attach an instruction whose account keys reference addresses in
+    /// address lookup tables. The lookup tables must be controlled by the authority of the
+    /// transaction, and are checked and resolved when the transaction is executed.
+    /// Lookup instructions can only be executed with execute_transaction.
+    pub fn add_lookup_instruction(
+        ctx: Context<AddLookupInstruction>,
+        incoming_instruction: IncomingLookupInstruction,
+    ) -> Result<()> {
+        let tx = &mut ctx.accounts.transaction;
+        // make sure internal transactions have a matching program id for attached instructions
+        if tx.authority_index == 0 && &incoming_instruction.program_id != ctx.program_id {
+            return err!(MsError::InvalidAuthorityIndex);
+        }
+        // the authority's program policy (if any) must allow the invoked program
+        let program_policy = ProgramPolicy::load(&ctx.accounts.multisig, tx.authority_index, ctx.remaining_accounts)?;
+        ProgramPolicy::check(&program_policy, &incoming_instruction.program_id)?;
+        // every key must reference one of the lookup tables of the instruction
+        if incoming_instruction.lookup_tables.is_empty()
+            || incoming_instruction
+                .keys
+                .iter()
+                .any(|key| usize::from(key.table_index) >= incoming_instruction.lookup_tables.len())
         {
-            ctx.accounts.transaction.reject(ctx.accounts.member.key())?;
+            return err!(MsError::InvalidLookupTable);
         }
+        tx.instruction_index = tx.instruction_index.checked_add(1).unwrap();
+        ctx.accounts.instruction.init(
+            tx.instruction_index,
+            incoming_instruction,
+            *ctx.bumps.get("instruction").unwrap(),
+        )
+    }
 
-        // ie total members 7, threshold 3, cutoff = 4
-        // ie total member 8, threshold 6, cutoff = 2
-        let cutoff = ctx
-            .accounts
-            .multisig
-            .keys
-            .len()
-            .checked_sub(usize::from(ctx.accounts.multisig.threshold))
-            .unwrap();
-        if ctx.accounts.transaction.rejected.len() > cutoff {
-            ctx.accounts.transaction.set_rejected()?;
+    /// Instruction to approve a transaction on behalf of a member.
+    /// The transaction must have an "active" status. If the transaction authority
+    /// has an AuthorityPolicy, its threshold is used instead of the multisig threshold.
+    pub fn approve_transaction<'info>(
+        ctx: Context<'_, '_, '_, 'info, VoteTransaction<'info>>,
+    ) -> Result<()> {
+        // expired transactions can no longer be approved
+        if ctx.accounts.transaction.is_expired(Clock::get()?.unix_timestamp) {
+            return err!(MsError::TransactionExpired);
         }
+
+        // approve, moving any previous reject or abstain vote (recorded as a vote change)
+        ctx.accounts.vote(Vote::Approve)?;
+
+        // if the combined weight of the signers reaches threshold, mark the transaction as execute ready
+        let threshold = ctx.accounts.threshold(ctx.remaining_accounts)?;
+        let approved_weight = ctx.accounts.multisig.weight_of(&ctx.accounts.transaction.approved);
+        if approved_weight >= u32::from(threshold) {
+            ctx.accounts.transaction.ready_to_execute(Clock::get()?.unix_timestamp)?;
+        }
+
+        emit!(TransactionApproved {
+            multisig: ctx.accounts.multisig.key(),
+            transaction: ctx.accounts.transaction.key(),
+            transaction_index: ctx.accounts.transaction.transaction_index,
+            member: ctx.accounts.member.key(),
+            status: ctx.accounts.transaction.status.clone(),
+        });
+        Ok(())
+    }
+
+    /// Instruction to reject a transaction.
+    /// The transaction must have an "active" status. If the transaction authority
+    /// has an AuthorityPolicy, the rejection cutoff is based on its threshold. If the
+    /// multisig has a reject threshold, reaching it rejects the transaction as well.
+    pub fn reject_transaction<'info>(
+        ctx: Context<'_, '_, '_, 'info, VoteTransaction<'info>>,
+    ) -> Result<()> {
+        // reject, moving any previous approve or abstain vote (recorded as a vote change)
+        ctx.accounts.vote(Vote::Reject)?;
+
+        // reject the transaction once the threshold can no longer be reached
+        let threshold = ctx.accounts.threshold(ctx.remaining_accounts)?;
+        ctx.accounts.reject_if_unreachable(threshold)?;
+
+        emit!(TransactionRejected {
+            multisig: ctx.accounts.multisig.key(),
+            transaction: ctx.accounts.transaction.key(),
+            transaction_index: ctx.accounts.transaction.transaction_index,
+            member: ctx.accounts.member.key(),
+            status: ctx.accounts.transaction.status.clone(),
+        });
+        Ok(())
+    }
+
+    /// Instruction to abstain from voting on a transaction.
+    /// The transaction must have an "active" status. Abstaining counts toward the
+    /// participation on the transaction but not its approval, so the transaction is
+    /// rejected once the members that haven't rejected or abstained can't reach the threshold.
+    pub fn abstain_transaction<'info>(
+        ctx: Context<'_, '_, '_, 'info, VoteTransaction<'info>>,
+    ) -> Result<()> {
+        // abstain, moving any previous approve or reject vote (recorded as a vote change)
+        ctx.accounts.vote(Vote::Abstain)?;
+
+        // reject the transaction once the threshold can no longer be reached
+        let threshold = ctx.accounts.threshold(ctx.remaining_accounts)?;
+        ctx.accounts.reject_if_unreachable(threshold)?;
+
+        emit!(TransactionAbstained {
+            multisig: ctx.accounts.multisig.key(),
+            transaction: ctx.accounts.transaction.key(),
+            transaction_index: ctx.accounts.transaction.transaction_index,
+            member: ctx.accounts.member.key(),
+            status: ctx.accounts.transaction.status.clone(),
+        });
         Ok(())
     }
 
@@ -346,8 +1673,11 @@ pub mod squads_mpl {
     /// Transactions must be in the "executeReady" status.
     /// Transaction will only be cancelled if the number of
     /// cancellations reaches the threshold. A cancelled
-    /// transaction will no longer be able to be executed.
-    pub fn cancel_transaction(ctx: Context<CancelTransaction>) -> Result<()> {
+    /// transaction will no longer be able to be executed. If the transaction authority
+    /// has an AuthorityPolicy, its threshold is used instead of the multisig threshold.
+    pub fn cancel_transaction<'info>(
+        ctx: Context<'_, '_, '_, 'info, CancelTransaction<'info>>,
+    ) -> Result<()> {
         // check if they haven't cancelled yet
         if ctx
             .accounts
@@ -358,31 +1688,160 @@ pub mod squads_mpl {
             ctx.accounts.transaction.cancel(ctx.accounts.member.key())?
         }
 
-        // if the current number of signers reaches threshold, mark the transaction as "cancelled"
-        if ctx.accounts.transaction.cancelled.len() >= usize::from(ctx.accounts.multisig.threshold)
-        {
+        // if the combined weight of the signers reaches threshold, mark the transaction as "cancelled"
+        let threshold = ctx.accounts.threshold(ctx.remaining_accounts)?;
+        let cancelled_weight = ctx.accounts.multisig.weight_of(&ctx.accounts.transaction.cancelled);
+        if cancelled_weight >= u32::from(threshold) {
             ctx.accounts.transaction.set_cancelled()?;
         }
+
+        emit!(TransactionCancelled {
+            multisig: ctx.accounts.multisig.key(),
+            transaction: ctx.accounts.transaction.key(),
+            transaction_index: ctx.accounts.transaction.transaction_index,
+            member: ctx.accounts.member.key(),
+            status: ctx.accounts.transaction.status.clone(),
+        });
+        Ok(())
+    }
+
+    /// Instruction for the creator to withdraw their own transaction.
+    /// Transactions must be in the "draft" or "active" status, and can't have any approvals yet.
+    /// The transaction is marked as "cancelled", and if close is set, the transaction and
+    /// its attached MsInstruction accounts (passed in order as remaining accounts, followed by
+    /// the instruction buffer if there is one) are closed in the same instruction,
+    /// returning the lamports to the creator.
+    pub fn withdraw_transaction<'info>(
+        ctx: Context<'_, '_, '_, 'info, WithdrawTransaction<'info>>,
+        close: bool,
+    ) -> Result<()> {
+        ctx.accounts.transaction.set_cancelled()?;
+
+        emit!(TransactionWithdrawn {
+            multisig: ctx.accounts.multisig.key(),
+            transaction: ctx.accounts.transaction.key(),
+            transaction_index: ctx.accounts.transaction.transaction_index,
+            creator: ctx.accounts.creator.key(),
+            closed: close,
+        });
+
+        if close {
+            let creator = ctx.accounts.creator.to_account_info();
+            close_instruction_accounts(
+                ctx.program_id,
+                &ctx.accounts.transaction,
+                ctx.remaining_accounts,
+                &creator,
+            )?;
+            ctx.accounts.transaction.close(creator)?;
+        }
+        Ok(())
+    }
+
+    /// Instruction to mark a transaction that has passed its expiry as "expired".
+    /// This is permissionless, and works for transactions that are in the
+    /// "draft", "active", or "executeReady" status.
+    pub fn expire_transaction(ctx: Context<ExpireTransaction>) -> Result<()> {
+        if !ctx.accounts.transaction.is_expired(Clock::get()?.unix_timestamp) {
+            return err!(MsError::TransactionNotExpired);
+        }
+        ctx.accounts.transaction.set_expired()?;
+
+        emit!(TransactionExpired {
+            multisig: ctx.accounts.multisig.key(),
+            transaction: ctx.accounts.transaction.key(),
+            transaction_index: ctx.accounts.transaction.transaction_index,
+            status: ctx.accounts.transaction.status.clone(),
+        });
         Ok(())
     }
 
+    /// Instruction to close a finished transaction and reclaim its rent.
+    /// The transaction must be "executed", "rejected", "cancelled" or "expired",
+    /// or deprecated by the multisig change index. All of the attached MsInstruction
+    /// (MsInstructionBatch and MsLookupInstruction) accounts must be passed in order as remaining accounts,
+    /// and are closed as well, along with the instruction buffer if it is passed after them.
+    /// The lamports are returned to the creator of the transaction.
+    pub fn close_transaction<'info>(
+        ctx: Context<'_, '_, '_, 'info, CloseTransaction<'info>>,
+    ) -> Result<()> {
+        close_instruction_accounts(
+            ctx.program_id,
+            &ctx.accounts.transaction,
+            ctx.remaining_accounts,
+            &ctx.accounts.creator.to_account_info(),
+        )
+    }
+
     /// Instruction to execute a transaction.
     /// Transaction status must be "executeReady", and the account list must match
     /// the unique indexed accounts in the following manner: 
     /// [ix_1_account, ix_1_program_account, ix_1_remaining_account_1, ix_1_remaining_account_2, ...]
+    /// An MsInstructionBatch account is followed by the program and remaining accounts
+    /// of each of its instructions in order.
+    /// An MsLookupInstruction account is followed by its lookup table accounts, then the
+    /// program and the accounts resolved from the lookup tables.
+    /// 
+    /// If the transaction was partially executed (with execute_transaction_chunk or
+    /// execute_instruction), the account list starts at the next instruction to execute.
     /// 
     /// Refer to the README for more information on how to construct the account list.
     pub fn execute_transaction<'info>(
         ctx: Context<'_, '_, '_, 'info, ExecuteTransaction<'info>>,
         account_list: Vec<u8>,
     ) -> Result<()> {
+        let end_index = ctx.accounts.transaction.instruction_index;
+        execute_transaction_chunk(ctx, account_list, end_index)
+    }
+
+    /// Instruction to execute part of a transaction, for transactions that
+    /// don't fit in the compute or account limits of a single execution.
+    /// Executes the instructions from executed_index + 1 up to and including end_index,
+    /// and records the progress in the executed_index of the transaction. The transaction
+    /// is marked as executed once its last instruction has been executed.
+    /// The account list follows the same format as execute_transaction, starting at the
+    /// first instruction of the chunk. A chunk can't end in the middle of an MsInstructionBatch.
+    pub fn execute_transaction_chunk<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteTransaction<'info>>,
+        account_list: Vec<u8>,
+        end_index: u8,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        // expired transactions can no longer be executed
+        if ctx.accounts.transaction.is_expired(now) {
+            return err!(MsError::TransactionExpired);
+        }
+
+        // the time lock must have passed since the transaction was approved
+        if !ctx
+            .accounts
+            .transaction
+            .is_time_lock_released(ctx.accounts.multisig.time_lock, now)
+        {
+            return err!(MsError::TimeLockNotReleased);
+        }
+
         // check that we are provided at least one instruction
         if ctx.accounts.transaction.instruction_index < 1 {
             // if no instructions were found, mark it as executed and move on
             ctx.accounts.transaction.set_executed()?;
+            emit!(TransactionExecuted {
+                multisig: ctx.accounts.multisig.key(),
+                transaction: ctx.accounts.transaction.key(),
+                transaction_index: ctx.accounts.transaction.transaction_index,
+                member: ctx.accounts.member.key(),
+                status: ctx.accounts.transaction.status.clone(),
+            });
             return Ok(());
         }
 
+        // the chunk must contain at least one instruction that hasn't been executed yet
+        if end_index <= ctx.accounts.transaction.executed_index
+            || end_index > ctx.accounts.transaction.instruction_index
+        {
+            return err!(MsError::PartialExecution);
+        }
+
         // use for derivation for the authority
         let ms_key = ctx.accounts.multisig.key();
 
@@ -402,6 +1861,31 @@ pub mod squads_mpl {
             &[ctx.accounts.multisig.bump],
         ];
 
+        // seeds for the ephemeral signers of the transaction, if any
+        let tx_key = ctx.accounts.transaction.key();
+        let ephemeral_signers = ctx
+            .accounts
+            .transaction
+            .ephemeral_signer_indexes_and_bumps(&tx_key, ctx.program_id);
+        let ephemeral_signer_seeds: Vec<[&[u8]; 5]> = ephemeral_signers
+            .iter()
+            .map(|signer| [&b"squad"[..], tx_key.as_ref(), &signer[0..1], &b"ephemeral_signer"[..], &signer[1..2]])
+            .collect();
+
+        // the key of the signing authority, which must control any lookup tables used
+        let authority_key = match ctx.accounts.transaction.authority_index {
+            0 => ms_key,
+            1.. => Pubkey::create_program_address(&authority_seeds, ctx.program_id)
+                .map_err(|_| MsError::InvalidAuthorityIndex)?,
+        };
+
+        // the program policy of the authority, if any, is checked for every instruction
+        let program_policy = ProgramPolicy::load(
+            &ctx.accounts.multisig,
+            ctx.accounts.transaction.authority_index,
+            ctx.remaining_accounts,
+        )?;
+
         // unroll account infos from account_list
         let mapped_remaining_accounts: Vec<AccountInfo> = account_list
             .iter()
@@ -414,8 +1898,11 @@ pub mod squads_mpl {
         // iterator for remaining accounts
         let ix_iter = &mut mapped_remaining_accounts.iter();
 
-        (1..=ctx.accounts.transaction.instruction_index).try_for_each(|i: u8| {
-            // each ix block starts with the ms_ix account
+        // number of attached instructions that have been walked through, starting after the executed ones
+        let mut ix_count: u8 = ctx.accounts.transaction.executed_index;
+        while ix_count < end_index {
+            let i: u8 = ix_count + 1;
+            // each ix block starts with the ms_ix (or ms_ix batch) account
             let ms_ix_account: &AccountInfo = next_account_info(ix_iter)?;
 
             // if the attached instruction doesn't belong to this program, throw error
@@ -423,10 +1910,6 @@ pub mod squads_mpl {
                 return err!(MsError::InvalidInstructionAccount);
             }
 
-            // deserialize the msIx
-            let mut ix_account_data: &[u8] = &ms_ix_account.try_borrow_mut_data()?;
-            let ms_ix: MsInstruction = MsInstruction::try_deserialize(&mut ix_account_data)?;
-
             // get the instruction account pda - seeded from transaction account + the transaction accounts instruction index
             let (ix_pda, _) = Pubkey::find_program_address(
                 &[
@@ -441,69 +1924,236 @@ pub mod squads_mpl {
             if &ix_pda != ms_ix_account.key {
                 return err!(MsError::InvalidInstructionAccount);
             }
-            // get the instructions program account
-            let ix_program_info: &AccountInfo = next_account_info(ix_iter)?;
-            // check that it matches the submitted account
-            if &ms_ix.program_id != ix_program_info.key {
-                return err!(MsError::InvalidInstructionAccount);
-            }
 
-            let ix_keys = ms_ix.keys.clone();
-            // create the instruction to invoke from the saved ms ix account
-            let ix: Instruction = Instruction::from(ms_ix);
-            // the instruction account vec, with the program account first
-            let mut ix_account_infos: Vec<AccountInfo> = vec![ix_program_info.clone()];
-
-            // loop through the provided remaining accounts
-            for account_index in 0..ix_keys.len() {
-                let ix_account_info = next_account_info(ix_iter)?.clone();
+            // deserialize the msIx, a batch account holds several consecutive instructions
+            let ixs: Vec<Instruction> = {
+                let mut ix_account_data: &[u8] = &ms_ix_account.try_borrow_data()?;
+                if ix_account_data.starts_with(&MsInstructionBatch::DISCRIMINATOR) {
+                    let ms_ix_batch = MsInstructionBatch::try_deserialize(&mut ix_account_data)?;
+                    ms_ix_batch.instructions.into_iter().map(Instruction::from).collect()
+                } else if ix_account_data.starts_with(&MsLookupInstruction::DISCRIMINATOR) {
+                    // a lookup instruction account is followed by its lookup table accounts
+                    let ms_lookup_ix = MsLookupInstruction::try_deserialize(&mut ix_account_data)?;
+                    let mut lookup_table_addresses: Vec<Vec<Pubkey>> = Vec::new();
+                    for lookup_table_key in ms_lookup_ix.lookup_tables.iter() {
+                        let lookup_table_info = next_account_info(ix_iter)?;
+                        if lookup_table_info.key != lookup_table_key {
+                            return err!(MsError::InvalidLookupTable);
+                        }
+                        lookup_table_addresses.push(get_lookup_table_addresses(lookup_table_info, &authority_key)?);
+                    }
+                    vec![ms_lookup_ix.resolve(&lookup_table_addresses)?]
+                } else {
+                    let ms_ix = MsInstruction::try_deserialize(&mut ix_account_data)?;
+                    vec![Instruction::from(ms_ix)]
+                }
+            };
+            ix_count = ix_count
+                .checked_add(ixs.len().try_into().unwrap())
+                .ok_or(MsError::InvalidInstructionAccount)?;
+            // a batch has to be executed as a whole
+            if ix_count > end_index {
+                return err!(MsError::PartialExecution);
+            }
 
-                // check that the ix account keys match the submitted account keys
-                if *ix_account_info.key != ix_keys[account_index].pubkey {
+            for ix in ixs {
+                // get the instructions program account
+                let ix_program_info: &AccountInfo = next_account_info(ix_iter)?;
+                // check that it matches the submitted account
+                if &ix.program_id != ix_program_info.key {
                     return err!(MsError::InvalidInstructionAccount);
                 }
+                ProgramPolicy::check(&program_policy, &ix.program_id)?;
 
-                ix_account_infos.push(ix_account_info.clone());
-            }
+                // the instruction account vec, with the program account first
+                let mut ix_account_infos: Vec<AccountInfo> = vec![ix_program_info.clone()];
 
-            // execute the ix
-            match ctx.accounts.transaction.authority_index {
-                // if its a 0 authority, use the MS pda seeds
-                0 => {
-                    if &ix.program_id != ctx.program_id {
-                        return err!(MsError::InvalidAuthorityIndex);
-                    }
-                    // Prevent recursive call on execute_transaction/instruction that could create issues
-                    let execute_transaction = Vec::from_hex("e7ad315beb184413").unwrap();
-                    let execute_instruction = Vec::from_hex("301228284b4a936e").unwrap();
-                    if Some(execute_transaction.as_slice()) == ix.data.get(0..8) ||
-                        Some(execute_instruction.as_slice()) == ix.data.get(0..8) {
-                        return err!(MsError::InvalidAuthorityIndex);
+                // loop through the provided remaining accounts
+                for ix_account_meta in ix.accounts.iter() {
+                    let ix_account_info = next_account_info(ix_iter)?.clone();
+
+                    // check that the ix account keys match the submitted account keys
+                    if *ix_account_info.key != ix_account_meta.pubkey {
+                        return err!(MsError::InvalidInstructionAccount);
                     }
 
-                    invoke_signed(&ix, &ix_account_infos, &[&ms_authority_seeds])?;
+                    ix_account_infos.push(ix_account_info.clone());
                 }
-                // if its > 1 authority, use the derived authority seeds
-                1.. => {
-                    invoke_signed(&ix, &ix_account_infos, &[&authority_seeds])?;
-                }
-            };
-            Ok(())
-        })?;
+
+                // execute the ix
+                match ctx.accounts.transaction.authority_index {
+                    // if its a 0 authority, use the MS pda seeds
+                    0 => {
+                        if &ix.program_id != ctx.program_id {
+                            return err!(MsError::InvalidAuthorityIndex);
+                        }
+                        // only config actions are allowed, which also prevents recursive calls on execute_transaction/instruction
+                        if !is_internal_instruction(&ix.data) {
+                            return err!(MsError::InstructionNotAllowed);
+                        }
+
+                        let mut signer_seeds: Vec<&[&[u8]]> = vec![&ms_authority_seeds];
+                        signer_seeds.extend(ephemeral_signer_seeds.iter().map(|seeds| &seeds[..]));
+                        invoke_signed(&ix, &ix_account_infos, &signer_seeds)?;
+                    }
+                    // if its > 1 authority, use the derived authority seeds
+                    1.. => {
+                        let mut signer_seeds: Vec<&[&[u8]]> = vec![&authority_seeds];
+                        signer_seeds.extend(ephemeral_signer_seeds.iter().map(|seeds| &seeds[..]));
+                        invoke_signed(&ix, &ix_account_infos, &signer_seeds)?;
+                    }
+                };
+            }
+        }
         // set the executed index
-        ctx.accounts.transaction.executed_index = ctx.accounts.transaction.instruction_index;
-        // mark it as executed
-        ctx.accounts.transaction.set_executed()?;
+        ctx.accounts.transaction.executed_index = end_index;
+        // mark it as executed once the last instruction has been executed
+        if end_index == ctx.accounts.transaction.instruction_index {
+            ctx.accounts.transaction.set_executed()?;
+        }
         // reload any multisig changes
         ctx.accounts.multisig.reload()?;
+
+        emit!(TransactionExecuted {
+            multisig: ctx.accounts.multisig.key(),
+            transaction: ctx.accounts.transaction.key(),
+            transaction_index: ctx.accounts.transaction.transaction_index,
+            member: ctx.accounts.member.key(),
+            status: ctx.accounts.transaction.status.clone(),
+        });
         Ok(())
     }
 
+    /// Read-only instruction that returns the accounts needed to execute a transaction,
+    /// meant to be used with a simulation. It walks the instructions from executed_index + 1
+    /// up to and including end_index (or the last instruction), and returns the deduplicated
+    /// account metas and the account_list for execute_transaction (or execute_transaction_chunk).
+    /// The instruction accounts of the transaction, and the lookup tables of any MsLookupInstruction,
+    /// are passed as remaining accounts in any order. The account metas are returned in pages
+    /// starting at offset, with at most limit accounts, as the return data is limited to 1024 bytes.
+    pub fn get_execution_accounts(
+        ctx: Context<GetExecutionAccounts>,
+        end_index: Option<u8>,
+        offset: u16,
+        limit: Option<u16>,
+    ) -> Result<ExecutionAccountsPage> {
+        let transaction = &ctx.accounts.transaction;
+        let end_index = end_index.unwrap_or(transaction.instruction_index);
+        if end_index > transaction.instruction_index {
+            return err!(MsError::PartialExecution);
+        }
+
+        let ms_key = ctx.accounts.multisig.key();
+        let tx_key = transaction.key();
+        // the authority and ephemeral signers are signed for by the program during execution
+        let mut program_signers = vec![match transaction.authority_index {
+            0 => ms_key,
+            1.. => Pubkey::create_program_address(
+                &[
+                    b"squad",
+                    ms_key.as_ref(),
+                    &transaction.authority_index.to_le_bytes(),
+                    b"authority",
+                    &[transaction.authority_bump],
+                ],
+                ctx.program_id,
+            )
+            .map_err(|_| MsError::InvalidAuthorityIndex)?,
+        }];
+        for signer in transaction.ephemeral_signer_indexes_and_bumps(&tx_key, ctx.program_id) {
+            program_signers.push(
+                Pubkey::create_program_address(
+                    &[b"squad", tx_key.as_ref(), &signer[0..1], b"ephemeral_signer", &signer[1..2]],
+                    ctx.program_id,
+                )
+                .map_err(|_| MsError::InvalidInstructionAccount)?,
+            );
+        }
+
+        // finds a passed in account by its key
+        let find_account = |key: &Pubkey| -> Result<&AccountInfo> {
+            ctx.remaining_accounts
+                .iter()
+                .find(|account| account.key == key)
+                .ok_or_else(|| error!(MsError::InvalidNumberOfAccounts))
+        };
+
+        let mut execution_accounts = ExecutionAccounts::default();
+        let mut ix_count: u8 = transaction.executed_index;
+        while ix_count < end_index {
+            let i: u8 = ix_count + 1;
+            let (ix_pda, _) = Pubkey::find_program_address(
+                &[
+                    b"squad",
+                    tx_key.as_ref(),
+                    &i.to_le_bytes(),
+                    b"instruction",
+                ],
+                ctx.program_id,
+            );
+            let ms_ix_account = find_account(&ix_pda)?;
+            if ms_ix_account.owner != ctx.program_id {
+                return err!(MsError::InvalidInstructionAccount);
+            }
+            execution_accounts.add(ix_pda, false, false)?;
+
+            // deserialize the msIx in the same way as execute_transaction
+            let ixs: Vec<Instruction> = {
+                let mut ix_account_data: &[u8] = &ms_ix_account.try_borrow_data()?;
+                if ix_account_data.starts_with(&MsInstructionBatch::DISCRIMINATOR) {
+                    let ms_ix_batch = MsInstructionBatch::try_deserialize(&mut ix_account_data)?;
+                    ms_ix_batch.instructions.into_iter().map(Instruction::from).collect()
+                } else if ix_account_data.starts_with(&MsLookupInstruction::DISCRIMINATOR) {
+                    let ms_lookup_ix = MsLookupInstruction::try_deserialize(&mut ix_account_data)?;
+                    let mut lookup_table_addresses: Vec<Vec<Pubkey>> = Vec::new();
+                    for lookup_table_key in ms_lookup_ix.lookup_tables.iter() {
+                        let lookup_table_info = find_account(lookup_table_key)?;
+                        execution_accounts.add(*lookup_table_key, false, false)?;
+                        lookup_table_addresses.push(get_lookup_table_addresses(lookup_table_info, &program_signers[0])?);
+                    }
+                    vec![ms_lookup_ix.resolve(&lookup_table_addresses)?]
+                } else {
+                    let ms_ix = MsInstruction::try_deserialize(&mut ix_account_data)?;
+                    vec![Instruction::from(ms_ix)]
+                }
+            };
+            ix_count = ix_count
+                .checked_add(ixs.len().try_into().unwrap())
+                .ok_or(MsError::InvalidInstructionAccount)?;
+            // a batch has to be executed as a whole
+            if ix_count > end_index {
+                return err!(MsError::PartialExecution);
+            }
+
+            for ix in ixs {
+                execution_accounts.add(ix.program_id, false, false)?;
+                for meta in ix.accounts {
+                    let is_signer = meta.is_signer && !program_signers.contains(&meta.pubkey);
+                    execution_accounts.add(meta.pubkey, is_signer, meta.is_writable)?;
+                }
+            }
+        }
+
+        // the program policy PDA is passed along with the accounts, but isn't part of the account list
+        if ctx.accounts.multisig.program_policy_count > 0 && transaction.authority_index > 0 {
+            let policy_pda = ProgramPolicy::address(&ms_key, transaction.authority_index);
+            if !execution_accounts.accounts.iter().any(|meta| meta.pubkey == policy_pda) {
+                execution_accounts.accounts.push(MsAccountMeta {
+                    pubkey: policy_pda,
+                    is_signer: false,
+                    is_writable: false,
+                });
+            }
+        }
+        execution_accounts.page(offset, limit)
+    }
+
     /// Instruction to sequentially execute attached instructions.
     /// Instructions executed in this matter must be executed in order,
     /// this may be helpful for processing large batch transfers.
     /// This instruction can only be used for transactions with an authority
-    /// index of 1 or greater.
+    /// index of 1 or greater, and not for instructions attached as a batch
+    /// or with lookup tables.
     /// 
     /// NOTE - do not use this instruction if there is not total clarity around
     /// potential side effects, as this instruction implies that the approved
@@ -524,6 +2174,17 @@ pub mod squads_mpl {
             return err!(MsError::InvalidAuthorityIndex);
         }
 
+        let now = Clock::get()?.unix_timestamp;
+        // expired transactions can no longer be executed
+        if tx.is_expired(now) {
+            return err!(MsError::TransactionExpired);
+        }
+
+        // the time lock must have passed since the transaction was approved
+        if !tx.is_time_lock_released(ctx.accounts.multisig.time_lock, now) {
+            return err!(MsError::TimeLockNotReleased);
+        }
+
         // setup the authority seeds
         let authority_seeds = [
             b"squad",
@@ -571,8 +2232,21 @@ pub mod squads_mpl {
         if tx.authority_index < 1 && &ix.program_id != ctx.program_id {
             return err!(MsError::InvalidAuthorityIndex);
         }
+        // the authority's program policy (if any) must allow the invoked program
+        let program_policy = ProgramPolicy::load(&ctx.accounts.multisig, tx.authority_index, ctx.remaining_accounts)?;
+        ProgramPolicy::check(&program_policy, &ix.program_id)?;
 
-        invoke_signed(&ix, &ix_account_infos, &[&authority_seeds])?;
+        // add the ephemeral signers of the transaction, if any
+        let tx_key = tx.key();
+        let ephemeral_signers = tx.ephemeral_signer_indexes_and_bumps(&tx_key, ctx.program_id);
+        let ephemeral_signer_seeds: Vec<[&[u8]; 5]> = ephemeral_signers
+            .iter()
+            .map(|signer| [&b"squad"[..], tx_key.as_ref(), &signer[0..1], &b"ephemeral_signer"[..], &signer[1..2]])
+            .collect();
+        let mut signer_seeds: Vec<&[&[u8]]> = vec![&authority_seeds];
+        signer_seeds.extend(ephemeral_signer_seeds.iter().map(|seeds| &seeds[..]));
+
+        invoke_signed(&ix, &ix_account_infos, &signer_seeds)?;
 
         // set the executed index to match
         tx.executed_index = ms_ix.instruction_index;
@@ -581,6 +2255,15 @@ pub mod squads_mpl {
         {
             ctx.accounts.transaction.set_executed()?;
         }
+
+        emit!(InstructionExecuted {
+            multisig: ctx.accounts.multisig.key(),
+            transaction: ctx.accounts.transaction.key(),
+            transaction_index: ctx.accounts.transaction.transaction_index,
+            instruction_index: ctx.accounts.instruction.instruction_index,
+            member: ctx.accounts.member.key(),
+            status: ctx.accounts.transaction.status.clone(),
+        });
         Ok(())
     }
 }
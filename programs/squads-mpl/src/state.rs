@@ -3,17 +3,21 @@
     https://github.com/squads-protocol/squads-mpl
 */
 
-use std::convert::TryInto;
+use std::convert::{TryFrom, TryInto};
 
-use anchor_lang::{prelude::*, solana_program::instruction::Instruction};
+use anchor_lang::{prelude::*, solana_program::instruction::Instruction, Discriminator};
 use anchor_lang::solana_program::borsh::get_instance_packed_len;
+use anchor_lang::solana_program::program::MAX_RETURN_DATA;
+
+use crate::errors::MsError;
 
 /// Ms is the basic state account for a multisig.
 #[account]
 pub struct Ms {
     pub threshold: u16,                 // threshold for signatures to execute.
 
-    pub authority_index: u16,           // luxury field to help track how many authorities are currently used.
+    pub authority_index: u16,           // the highest authority index in use, transactions can't be
+                                        // created for a higher authority index.
 
     pub transaction_index: u32,         // look up and seed reference for transactions.
 
@@ -27,9 +31,36 @@ pub struct Ms {
 
     pub create_key: Pubkey,             // random key(or not) used to seed the multisig pda.
                                    
-    pub allow_external_execute: bool,   // DEPRECATED - allow non-member keys to execute txs
+    pub migrated: bool,                 // set once the account uses the current layout, replaces the
+                                        // DEPRECATED allow_external_execute flag which was always false.
 
     pub keys: Vec<Pubkey>,              // keys of the members/owners of the multisig.
+
+    pub time_lock: u32,                 // seconds an approved transaction must wait before
+                                        // it can be executed, 0 disables the time lock.
+
+    pub default_ttl: u32,               // seconds a new transaction stays valid before it expires,
+                                        // 0 means transactions don't expire by default.
+
+    pub member_weights: Vec<MemberWeight>, // voting weights of members that don't have the default
+                                           // weight of 1, sorted by key.
+
+    pub authority_policy_count: u16,    // number of authorities with an AuthorityPolicy, while any exist
+                                        // votes need to reference the policy of the transaction authority.
+
+    pub member_permissions: Vec<MemberPermissions>, // permissions of members that don't have all of the
+                                                    // permissions, sorted by key.
+
+    pub recovery: Option<RecoveryConfig>, // guardians that can replace the members if keys are lost.
+
+    pub reject_threshold: u16,          // weight of rejections that rejects a transaction, 0 rejects
+                                        // only once the threshold can no longer be reached.
+
+    pub program_policy_count: u16,      // number of authorities with a ProgramPolicy, while any exist
+                                        // attaching and executing instructions needs to reference the policy.
+
+    pub disabled_authority_count: u16,  // number of disabled authorities, while any exist creating a
+                                        // transaction needs to reference the Authority account.
 }
 
 impl Ms {
@@ -40,8 +71,20 @@ impl Ms {
     4 +         // processed internal transaction index
     1 +         // PDA bump
     32 +        // creator
-    1 +         // allow external execute
-    4;          // for vec length
+    1 +         // migrated
+    4 +         // for vec length
+    4 +         // time lock
+    4 +         // default transaction ttl
+    4 +         // for member weights vec length
+    2 +         // authority policy count
+    4 +         // for member permissions vec length
+    1 +         // recovery config option
+    2 +         // reject threshold
+    2 +         // program policy count
+    2;          // disabled authority count
+
+    /// Voting weight of members without an entry in member_weights
+    pub const DEFAULT_MEMBER_WEIGHT: u16 = 1;
 
     /// Initializes the new multisig account
     pub fn init (&mut self, threshold: u16, create_key: Pubkey, members: Vec<Pubkey>, bump: u8) -> Result<()> {
@@ -52,10 +95,28 @@ impl Ms {
         self.ms_change_index= 0;
         self.bump = bump;
         self.create_key = create_key;
-        self.allow_external_execute = false;
+        self.migrated = true;
+        self.time_lock = 0;
+        self.default_ttl = 0;
+        self.member_weights = Vec::new();
+        self.authority_policy_count = 0;
+        self.member_permissions = Vec::new();
+        self.recovery = None;
+        self.reject_threshold = 0;
+        self.program_policy_count = 0;
+        self.disabled_authority_count = 0;
         Ok(())
     }
 
+    /// The space currently used by the serialized multisig
+    pub fn space_used(&self) -> usize {
+        Ms::SIZE_WITHOUT_MEMBERS +
+        (self.keys.len() * 32) +
+        (self.member_weights.len() * MemberWeight::SIZE) +
+        (self.member_permissions.len() * MemberPermissions::SIZE) +
+        self.recovery.as_ref().map_or(0, |recovery| recovery.size())
+    }
+
     /// Checks to see if the key is a member of the multisig
     pub fn is_member(&self, member: Pubkey) -> Option<usize> {
         match self.keys.binary_search(&member) {
@@ -64,6 +125,72 @@ impl Ms {
         }
     }
 
+    /// Gets the permissions mask of a key, 0 if it is not a member of the multisig
+    pub fn member_permissions(&self, member: Pubkey) -> u8 {
+        if self.is_member(member).is_none() {
+            return 0;
+        }
+        match self.member_permissions.binary_search_by_key(&member, |p| p.key) {
+            Ok(ind) => self.member_permissions[ind].mask,
+            _ => MemberPermissions::ALL
+        }
+    }
+
+    /// Checks to see if the key is a member with the given permission
+    pub fn has_permission(&self, member: Pubkey, permission: u8) -> bool {
+        self.member_permissions(member) & permission == permission
+    }
+
+    /// Sets the permissions mask of a member, only masks other than all permissions are stored
+    pub fn set_member_permissions(&mut self, member: Pubkey, mask: u8) -> Result<()>{
+        match self.member_permissions.binary_search_by_key(&member, |p| p.key) {
+            Ok(ind) if mask == MemberPermissions::ALL => {
+                self.member_permissions.remove(ind);
+            },
+            Ok(ind) => {
+                self.member_permissions[ind].mask = mask;
+            },
+            Err(_) if mask == MemberPermissions::ALL => {},
+            Err(ind) => {
+                self.member_permissions.insert(ind, MemberPermissions { key: member, mask });
+            }
+        }
+        Ok(())
+    }
+
+    /// Gets the voting weight of a key, 0 if it is not a member of the multisig
+    /// or doesn't have the vote permission
+    pub fn member_weight(&self, member: Pubkey) -> u16 {
+        if !self.has_permission(member, MemberPermissions::VOTE) {
+            return 0;
+        }
+        match self.member_weights.binary_search_by_key(&member, |w| w.key) {
+            Ok(ind) => self.member_weights[ind].weight,
+            _ => Ms::DEFAULT_MEMBER_WEIGHT
+        }
+    }
+
+    /// Gets the combined voting weight of a list of keys
+    pub fn weight_of(&self, members: &[Pubkey]) -> u32 {
+        members.iter().map(|m| u32::from(self.member_weight(*m))).sum()
+    }
+
+    /// Gets the combined voting weight of all the members that can vote
+    pub fn total_weight(&self) -> u32 {
+        self.weight_of(&self.keys)
+    }
+
+    /// The highest threshold the members can reach with their combined weight
+    pub fn max_threshold(&self) -> u16 {
+        self.total_weight().try_into().unwrap_or(u16::MAX)
+    }
+
+    /// Sets the reject threshold, 0 disables it
+    pub fn change_reject_threshold(&mut self, reject_threshold: u16) -> Result<()>{
+        self.reject_threshold = reject_threshold;
+        Ok(())
+    }
+
     /// Updates the change index, deprecating any active/draft transactions
     /// that have an index lower than the change index
     pub fn set_change_index(&mut self, index: u32) -> Result<()>{
@@ -72,8 +199,8 @@ impl Ms {
     }
 
     /// bumps up the authority tracking index for the multisig.
-    /// This has no effect on the multisig functionality, but is used
-    /// to track authorities for clients to use (ie, vault 1, vault 2, program authority 3, etc).
+    /// Transactions can only be created for authorities up to this index,
+    /// which clients also use to track authorities (ie, vault 1, vault 2, program authority 3, etc).
     pub fn add_authority(&mut self) -> Result<()>{
         self.authority_index = self.authority_index.checked_add(1).unwrap();
         Ok(())
@@ -88,12 +215,79 @@ impl Ms {
         Ok(())
     }
 
+    /// Sets the voting weight of a member, only weights other than the default are stored.
+    pub fn set_member_weight(&mut self, member: Pubkey, weight: u16) -> Result<()>{
+        match self.member_weights.binary_search_by_key(&member, |w| w.key) {
+            Ok(ind) if weight == Ms::DEFAULT_MEMBER_WEIGHT => {
+                self.member_weights.remove(ind);
+            },
+            Ok(ind) => {
+                self.member_weights[ind].weight = weight;
+            },
+            Err(_) if weight == Ms::DEFAULT_MEMBER_WEIGHT => {},
+            Err(ind) => {
+                self.member_weights.insert(ind, MemberWeight { key: member, weight });
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks to see if the key is a guardian of the recovery config
+    pub fn is_guardian(&self, guardian: Pubkey) -> bool {
+        matches!(&self.recovery, Some(recovery) if recovery.is_guardian(guardian))
+    }
+
+    /// Sets or removes the recovery config
+    pub fn set_recovery(&mut self, recovery: Option<RecoveryConfig>) -> Result<()>{
+        self.recovery = recovery;
+        Ok(())
+    }
+
+    /// Replaces the members and threshold of the multisig (from a recovery).
+    /// Weights and permissions are reset, so every new member has the defaults.
+    pub fn replace_members(&mut self, members: Vec<Pubkey>, threshold: u16) -> Result<()>{
+        self.keys = members;
+        self.member_weights = Vec::new();
+        self.member_permissions = Vec::new();
+        self.threshold = threshold;
+        Ok(())
+    }
+
+    /// Replaces the key of a member, keeping their weight and permissions.
+    /// The new key must not already be a member of the multisig.
+    pub fn rotate_member(&mut self, old_member: Pubkey, new_member: Pubkey) -> Result<()>{
+        let ind = self.is_member(old_member).ok_or(MsError::KeyNotInMultisig)?;
+        if self.is_member(new_member).is_some() {
+            return err!(MsError::KeyAlreadyInMultisig);
+        }
+        // take the stored weight and permissions of the old key, if any
+        let weight = match self.member_weights.binary_search_by_key(&old_member, |w| w.key) {
+            Ok(ind) => self.member_weights.remove(ind).weight,
+            _ => Ms::DEFAULT_MEMBER_WEIGHT
+        };
+        let mask = match self.member_permissions.binary_search_by_key(&old_member, |p| p.key) {
+            Ok(ind) => self.member_permissions.remove(ind).mask,
+            _ => MemberPermissions::ALL
+        };
+        self.keys[ind] = new_member;
+        self.keys.sort();
+        self.set_member_weight(new_member, weight)?;
+        self.set_member_permissions(new_member, mask)?;
+        Ok(())
+    }
+
     /// Removes a member from the multisig. Is a no-op if the member is not in the multisig.
     pub fn remove_member(&mut self, member: Pubkey) -> Result<()>{
         if let Some(ind) = self.is_member(member) {
+            self.set_member_weight(member, Ms::DEFAULT_MEMBER_WEIGHT)?;
+            self.set_member_permissions(member, MemberPermissions::ALL)?;
             self.keys.remove(ind);
-            if self.keys.len() < usize::from(self.threshold) {
-                self.threshold = self.keys.len().try_into().unwrap();
+            // at least one member needs to be able to vote
+            if self.max_threshold() < 1 {
+                return err!(MsError::CannotRemoveSoloMember);
+            }
+            if self.max_threshold() < self.threshold {
+                self.threshold = self.max_threshold();
             }
         }
         Ok(())
@@ -105,6 +299,626 @@ impl Ms {
         Ok(())
     }
 
+    /// sets the delay (in seconds) between a transaction becoming ExecuteReady and
+    /// when it can be executed.
+    pub fn change_time_lock(&mut self, time_lock: u32) -> Result<()>{
+        self.time_lock = time_lock;
+        Ok(())
+    }
+
+    /// sets the default time to live (in seconds) for newly created transactions.
+    pub fn change_default_ttl(&mut self, default_ttl: u32) -> Result<()>{
+        self.default_ttl = default_ttl;
+        Ok(())
+    }
+
+    /// tracks a newly added authority policy
+    pub fn add_authority_policy(&mut self) -> Result<()>{
+        self.authority_policy_count = self.authority_policy_count.checked_add(1).unwrap();
+        Ok(())
+    }
+
+    /// tracks a removed authority policy
+    pub fn remove_authority_policy(&mut self) -> Result<()>{
+        self.authority_policy_count = self.authority_policy_count.saturating_sub(1);
+        Ok(())
+    }
+
+    /// tracks an authority being enabled or disabled
+    pub fn set_authority_enabled(&mut self, enabled: bool) -> Result<()>{
+        self.disabled_authority_count = match enabled {
+            true => self.disabled_authority_count.saturating_sub(1),
+            false => self.disabled_authority_count.checked_add(1).unwrap(),
+        };
+        Ok(())
+    }
+
+    /// tracks a newly added program policy
+    pub fn add_program_policy(&mut self) -> Result<()>{
+        self.program_policy_count = self.program_policy_count.checked_add(1).unwrap();
+        Ok(())
+    }
+
+    /// tracks a removed program policy
+    pub fn remove_program_policy(&mut self) -> Result<()>{
+        self.program_policy_count = self.program_policy_count.saturating_sub(1);
+        Ok(())
+    }
+
+    /// Gets the expiry timestamp for a transaction created now, 0 if there is no default ttl
+    pub fn default_expiry(&self, now: i64) -> i64 {
+        match self.default_ttl {
+            0 => 0,
+            ttl => now.saturating_add(i64::from(ttl)),
+        }
+    }
+
+}
+
+/// The MsMetadata stores the metadata (ie. name and description) of a multisig,
+/// passed when creating it and updated with internal transactions.
+#[account]
+pub struct MsMetadata {
+    pub ms: Pubkey,                     // the multisig this belongs to
+    pub meta: String,                   // the metadata, ie. '{"name":"My Multisig","description":"This is my multisig"}'
+    pub bump: u8,                       // bump for the seed
+}
+
+impl MsMetadata {
+    /// Maximum length of the metadata in bytes
+    pub const MAX_META_LEN: usize = 512;
+
+    /// The space needed to store the given metadata
+    pub fn size(meta: &str) -> usize {
+        8 +                             // Anchor discriminator
+        32 +                            // the multisig key
+        4 + meta.len() +                // the metadata string
+        1                               // PDA bump
+    }
+
+    /// Derives the address of the metadata account of a multisig
+    pub fn address(multisig: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[b"squad", multisig.as_ref(), b"metadata"], &crate::ID)
+    }
+
+    /// Initializes the metadata account
+    pub fn init(&mut self, multisig: Pubkey, meta: String, bump: u8) -> Result<()> {
+        self.ms = multisig;
+        self.bump = bump;
+        self.set_meta(meta)
+    }
+
+    /// Sets the metadata, which can't be longer than MAX_META_LEN
+    pub fn set_meta(&mut self, meta: String) -> Result<()> {
+        if meta.len() > MsMetadata::MAX_META_LEN {
+            return err!(MsError::MetadataTooLong);
+        }
+        self.meta = meta;
+        Ok(())
+    }
+}
+
+/// The AuthorityPolicy overrides the multisig threshold for transactions
+/// that use the authority (vault) it was created for.
+#[account]
+pub struct AuthorityPolicy {
+    pub ms: Pubkey,                     // the multisig this belongs to
+    pub authority_index: u32,           // the authority the policy applies to
+    pub threshold: u16,                 // threshold used instead of the multisig threshold
+    pub bump: u8,                       // bump for the seed
+}
+
+impl AuthorityPolicy {
+    pub const SIZE: usize = 8 +         // Anchor discriminator
+        32 +                            // the multisig key
+        4 +                             // the authority index
+        2 +                             // threshold value
+        1;                              // PDA bump
+
+    /// Initializes the authority policy account
+    pub fn init(&mut self, multisig: Pubkey, authority_index: u32, threshold: u16, bump: u8) -> Result<()> {
+        self.ms = multisig;
+        self.authority_index = authority_index;
+        self.threshold = threshold;
+        self.bump = bump;
+        Ok(())
+    }
+
+    /// sets the threshold for the authority
+    pub fn change_threshold(&mut self, threshold: u16) -> Result<()> {
+        self.threshold = threshold;
+        Ok(())
+    }
+
+    /// The address of the policy of an authority index
+    pub fn address(multisig: &Pubkey, authority_index: u32) -> Pubkey {
+        Pubkey::find_program_address(
+            &[
+                b"squad",
+                multisig.as_ref(),
+                &authority_index.to_le_bytes(),
+                b"policy",
+            ],
+            &crate::ID,
+        ).0
+    }
+
+    /// Gets the threshold for transactions of an authority, which is the threshold of
+    /// its AuthorityPolicy if it has one, otherwise the multisig threshold. While the
+    /// multisig has any policies, the policy PDA must be in the remaining accounts.
+    pub fn threshold_for<'info>(
+        ms: &Ms,
+        ms_key: &Pubkey,
+        authority_index: u32,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<u16> {
+        // internal transactions always use the multisig threshold
+        if ms.authority_policy_count < 1 || authority_index < 1 {
+            return Ok(ms.threshold);
+        }
+
+        let policy_pda = AuthorityPolicy::address(ms_key, authority_index);
+        let policy_account = remaining_accounts
+            .iter()
+            .find(|account| account.key == &policy_pda)
+            .ok_or(MsError::InvalidAuthorityPolicy)?;
+
+        // the authority doesn't have a policy
+        if policy_account.owner != &crate::ID {
+            return Ok(ms.threshold);
+        }
+        let policy: Account<AuthorityPolicy> = Account::try_from(policy_account)?;
+        // the policy threshold can't be higher than what the members can reach
+        Ok(policy.threshold.min(ms.max_threshold()))
+    }
+}
+
+/// The Authority account describes an authority (vault) of the multisig,
+/// seeded from [b"squad", multisig, authority_index, b"authority_info"].
+#[account]
+pub struct Authority {
+    pub ms: Pubkey,                     // the multisig this belongs to
+    pub authority_index: u32,           // the authority this describes
+    pub label: String,                  // what the authority is used for, ie. "treasury"
+    pub created_at: i64,                // unix timestamp the authority was registered
+    pub enabled: bool,                  // transactions can't be created for a disabled authority
+    pub bump: u8,                       // bump for the seed
+}
+
+impl Authority {
+    pub const MAX_LABEL_LEN: usize = 64;
+
+    /// The space needed for an authority account with the given label
+    pub fn size(label: &str) -> usize {
+        8 +                             // Anchor discriminator
+        32 +                            // the multisig key
+        4 +                             // the authority index
+        4 + label.len() +               // the label
+        8 +                             // created at
+        1 +                             // enabled
+        1                               // PDA bump
+    }
+
+    /// The address of the Authority account of an authority index
+    pub fn address(multisig: &Pubkey, authority_index: u32) -> Pubkey {
+        Pubkey::find_program_address(
+            &[
+                b"squad",
+                multisig.as_ref(),
+                &authority_index.to_le_bytes(),
+                b"authority_info",
+            ],
+            &crate::ID,
+        ).0
+    }
+
+    /// Initializes the authority account, enabled
+    pub fn init(&mut self, multisig: Pubkey, authority_index: u32, label: String, created_at: i64, bump: u8) -> Result<()> {
+        self.ms = multisig;
+        self.authority_index = authority_index;
+        self.created_at = created_at;
+        self.enabled = true;
+        self.bump = bump;
+        self.set_label(label)
+    }
+
+    /// Sets the label, checking it against the size limit
+    pub fn set_label(&mut self, label: String) -> Result<()> {
+        if label.len() > Authority::MAX_LABEL_LEN {
+            return err!(MsError::InvalidAuthorityLabel);
+        }
+        self.label = label;
+        Ok(())
+    }
+
+    /// Checks that the authority isn't disabled. While the multisig has any disabled
+    /// authorities, the Authority account of the authority must be passed in,
+    /// even if the authority hasn't been registered.
+    pub fn check_enabled(multisig: &Account<Ms>, authority_index: u32, accounts: &[AccountInfo]) -> Result<()> {
+        // internal transactions always use the multisig itself
+        if multisig.disabled_authority_count < 1 || authority_index < 1 {
+            return Ok(());
+        }
+        let authority_pda = Authority::address(&multisig.key(), authority_index);
+        let authority_account = accounts
+            .iter()
+            .find(|account| account.key == &authority_pda)
+            .ok_or(MsError::InvalidAuthorityIndex)?;
+
+        // the authority hasn't been registered
+        if authority_account.owner != &crate::ID {
+            return Ok(());
+        }
+        let authority: Account<Authority> = Account::try_from(authority_account)?;
+        if !authority.enabled {
+            return err!(MsError::AuthorityDisabled);
+        }
+        Ok(())
+    }
+}
+
+/// Whether the programs of a ProgramPolicy are the only ones allowed, or the ones denied
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ProgramPolicyMode {
+    Allow,
+    Deny,
+}
+
+/// The ProgramPolicy restricts the programs that instructions of transactions using the
+/// authority (vault) it was created for can invoke, to an allowlist or with a denylist.
+#[account]
+pub struct ProgramPolicy {
+    pub ms: Pubkey,                     // the multisig this belongs to
+    pub authority_index: u32,           // the authority the policy applies to
+    pub mode: ProgramPolicyMode,        // allowlist or denylist
+    pub programs: Vec<Pubkey>,          // the program ids of the list, sorted
+    pub bump: u8,                       // bump for the seed
+}
+
+impl ProgramPolicy {
+    pub const MAX_PROGRAMS: usize = 64;
+
+    /// The space needed for a policy with the given number of programs
+    pub fn size(programs_len: usize) -> usize {
+        8 +                             // Anchor discriminator
+        32 +                            // the multisig key
+        4 +                             // the authority index
+        1 +                             // the mode
+        4 + programs_len * 32 +         // the program ids
+        1                               // PDA bump
+    }
+
+    /// Initializes the program policy account
+    pub fn init(&mut self, multisig: Pubkey, authority_index: u32, mode: ProgramPolicyMode, programs: Vec<Pubkey>, bump: u8) -> Result<()> {
+        self.ms = multisig;
+        self.authority_index = authority_index;
+        self.bump = bump;
+        self.set_programs(mode, programs)
+    }
+
+    /// Sets the mode and programs of the policy
+    pub fn set_programs(&mut self, mode: ProgramPolicyMode, mut programs: Vec<Pubkey>) -> Result<()> {
+        if programs.len() > ProgramPolicy::MAX_PROGRAMS {
+            return err!(MsError::InvalidProgramPolicy);
+        }
+        programs.sort();
+        programs.dedup();
+        self.mode = mode;
+        self.programs = programs;
+        Ok(())
+    }
+
+    /// Checks if the policy allows invoking the program
+    pub fn allows(&self, program_id: &Pubkey) -> bool {
+        let listed = self.programs.binary_search(program_id).is_ok();
+        match self.mode {
+            ProgramPolicyMode::Allow => listed,
+            ProgramPolicyMode::Deny => !listed,
+        }
+    }
+
+    /// The address of the program policy of an authority
+    pub fn address(multisig: &Pubkey, authority_index: u32) -> Pubkey {
+        Pubkey::find_program_address(
+            &[
+                b"squad",
+                multisig.as_ref(),
+                &authority_index.to_le_bytes(),
+                b"program_policy",
+            ],
+            &crate::ID,
+        ).0
+    }
+
+    /// Loads the ProgramPolicy of the authority from the given accounts, if it has one.
+    /// While the multisig has any program policies, the policy PDA of the authority
+    /// must be passed in, even if the authority doesn't have a policy.
+    pub fn load(multisig: &Account<Ms>, authority_index: u32, accounts: &[AccountInfo]) -> Result<Option<ProgramPolicy>> {
+        // internal transactions can't have a program policy
+        if multisig.program_policy_count < 1 || authority_index < 1 {
+            return Ok(None);
+        }
+        let policy_pda = ProgramPolicy::address(&multisig.key(), authority_index);
+        let policy_account = accounts
+            .iter()
+            .find(|account| account.key == &policy_pda)
+            .ok_or(MsError::InvalidProgramPolicy)?;
+
+        // the authority doesn't have a policy
+        if policy_account.owner != &crate::ID {
+            return Ok(None);
+        }
+        let policy: Account<ProgramPolicy> = Account::try_from(policy_account)?;
+        Ok(Some(policy.into_inner()))
+    }
+
+    /// Checks that the loaded policy, if any, allows invoking the program
+    pub fn check(policy: &Option<ProgramPolicy>, program_id: &Pubkey) -> Result<()> {
+        match policy {
+            Some(policy) if !policy.allows(program_id) => err!(MsError::ProgramNotAllowed),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// The SpendingLimit allows a set of members to transfer up to an amount of SOL or
+/// tokens from an authority (vault) per period, without creating a transaction.
+#[account]
+pub struct SpendingLimit {
+    pub ms: Pubkey,                     // the multisig this belongs to
+    pub authority_index: u32,           // the authority the funds are transferred from
+    pub authority_bump: u8,             // the bump corresponding to the PDA (authority)
+    pub mint: Pubkey,                   // the token mint, or the default pubkey for native SOL
+    pub amount: u64,                    // the amount that can be spent per period
+    pub period: i64,                    // seconds until the amount resets, 0 never resets
+    pub remaining_amount: u64,          // the amount left to spend in the current period
+    pub last_reset: i64,                // unix timestamp the current period started
+    pub members: Vec<Pubkey>,           // keys of the members that can use the spending limit
+    pub bump: u8,                       // bump for the seed
+}
+
+impl SpendingLimit {
+    // the minimum size without the members vec
+    pub const MINIMUM_SIZE: usize = 8 + // Anchor discriminator
+        32 +                            // the multisig key
+        4 +                             // the authority index
+        1 +                             // the authority bump
+        32 +                            // the mint
+        8 +                             // amount per period
+        8 +                             // period
+        8 +                             // remaining amount
+        8 +                             // last reset timestamp
+        4 +                             // for vec length
+        1;                              // PDA bump
+
+    pub fn size_with_members(members_len: usize) -> usize {
+        SpendingLimit::MINIMUM_SIZE + (members_len * 32)
+    }
+
+    /// Initializes the spending limit account
+    pub fn init(&mut self, multisig: Pubkey, authority_index: u32, authority_bump: u8, mint: Pubkey, bump: u8) -> Result<()> {
+        self.ms = multisig;
+        self.authority_index = authority_index;
+        self.authority_bump = authority_bump;
+        self.mint = mint;
+        self.bump = bump;
+        Ok(())
+    }
+
+    /// Sets the amount, period and members of the spending limit, starting a new period
+    pub fn set_limit(&mut self, amount: u64, period: i64, members: Vec<Pubkey>, now: i64) -> Result<()> {
+        self.amount = amount;
+        self.period = period;
+        self.remaining_amount = amount;
+        self.last_reset = now;
+        self.members = members;
+        Ok(())
+    }
+
+    /// Checks to see if the key can use the spending limit
+    pub fn is_member(&self, member: Pubkey) -> Option<usize> {
+        self.members.binary_search(&member).ok()
+    }
+
+    /// Replaces a rotated member key, is a no-op if the old key isn't a member of the spending limit
+    pub fn rotate_member(&mut self, old_member: Pubkey, new_member: Pubkey) -> Result<()> {
+        if let Some(ind) = self.is_member(old_member) {
+            self.members[ind] = new_member;
+            self.members.sort();
+        }
+        Ok(())
+    }
+
+    /// Resets the remaining amount if one or more periods have passed since the last reset
+    pub fn reset_if_needed(&mut self, now: i64) -> Result<()> {
+        if self.period > 0 && now.saturating_sub(self.last_reset) >= self.period {
+            let periods_passed = (now - self.last_reset) / self.period;
+            self.last_reset += periods_passed * self.period;
+            self.remaining_amount = self.amount;
+        }
+        Ok(())
+    }
+
+    /// Deducts the amount from what is left to spend in the current period
+    pub fn spend(&mut self, amount: u64) -> Result<()> {
+        self.remaining_amount = self
+            .remaining_amount
+            .checked_sub(amount)
+            .ok_or(MsError::SpendingLimitExceeded)?;
+        Ok(())
+    }
+}
+
+/// The voting weight of a multisig member.
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone)]
+pub struct MemberWeight {
+    pub key: Pubkey,
+    pub weight: u16,
+}
+
+impl MemberWeight {
+    pub const SIZE: usize = 32 + 2;
+}
+
+/// The recovery configuration of a multisig. If enough guardians approve
+/// a recovery proposal, and no member objects within the delay, the members
+/// of the multisig are replaced.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RecoveryConfig {
+    pub guardians: Vec<Pubkey>,         // keys of the guardians, sorted
+    pub threshold: u16,                 // guardian approvals needed for a recovery
+    pub delay: u32,                     // seconds members have to object once approved
+    pub change_index: u32,              // the transaction index of the multisig when the config was set
+}
+
+impl RecoveryConfig {
+    /// The shortest delay members can be given to object to a recovery
+    pub const MIN_DELAY: u32 = 7 * 24 * 60 * 60;
+    /// The most guardians a config can have
+    pub const MAX_GUARDIANS: usize = 10;
+
+    /// The space used by the serialized config
+    pub fn size(&self) -> usize {
+        4 + (self.guardians.len() * 32) +   // guardians vec
+        2 +                                 // threshold
+        4 +                                 // delay
+        4                                   // change index
+    }
+
+    /// Checks to see if the key is a guardian
+    pub fn is_guardian(&self, guardian: Pubkey) -> bool {
+        self.guardians.binary_search(&guardian).is_ok()
+    }
+
+    /// Counts the approvals that are from current guardians
+    pub fn approvals(&self, approvals: &[Pubkey]) -> usize {
+        approvals.iter().filter(|key| self.is_guardian(**key)).count()
+    }
+}
+
+/// The RecoveryProposal holds a replacement member set proposed by guardians, and is
+/// seeded from [b"squad", multisig, b"recovery"]. Only one recovery can be proposed at a time.
+#[account]
+pub struct RecoveryProposal {
+    pub ms: Pubkey,                     // the multisig this belongs to
+    pub proposer: Pubkey,               // the guardian that proposed it and paid the rent
+    pub new_members: Vec<Pubkey>,       // the members replacing the current ones, sorted
+    pub new_threshold: u16,             // the threshold to use with the new members
+    pub approvals: Vec<Pubkey>,         // the guardians that have approved
+    pub ready_at: i64,                  // unix timestamp it can be executed, 0 until approved
+    pub config_index: u32,              // the change index of the recovery config it was proposed under
+    pub bump: u8,                       // bump for the seed
+}
+
+impl RecoveryProposal {
+    /// The space needed for the proposal
+    pub fn size(members_len: usize) -> usize {
+        8 +                             // Anchor discriminator
+        32 +                            // the multisig key
+        32 +                            // the proposer key
+        4 + (members_len * 32) +        // the new members
+        2 +                             // the new threshold
+        4 + (RecoveryConfig::MAX_GUARDIANS * 32) + // the approvals
+        8 +                             // ready timestamp
+        4 +                             // recovery config change index
+        1                               // PDA bump
+    }
+
+    /// Initializes the proposal
+    pub fn init(&mut self, multisig: Pubkey, proposer: Pubkey, new_members: Vec<Pubkey>, new_threshold: u16, config_index: u32, bump: u8) -> Result<()> {
+        self.ms = multisig;
+        self.proposer = proposer;
+        self.new_members = new_members;
+        self.new_threshold = new_threshold;
+        self.approvals = Vec::new();
+        self.ready_at = 0;
+        self.config_index = config_index;
+        self.bump = bump;
+        Ok(())
+    }
+
+    /// Checks that the proposal was made under the current recovery config
+    pub fn check_config(&self, recovery: &RecoveryConfig) -> Result<()> {
+        if self.config_index != recovery.change_index {
+            return err!(MsError::RecoveryConfigChanged);
+        }
+        Ok(())
+    }
+
+    /// Adds the approval of a guardian, and starts the delay once the guardian threshold is reached
+    pub fn approve(&mut self, guardian: Pubkey, recovery: &RecoveryConfig, now: i64) -> Result<()> {
+        if let Err(ind) = self.approvals.binary_search(&guardian) {
+            self.approvals.insert(ind, guardian);
+        }
+        if self.ready_at == 0 && recovery.approvals(&self.approvals) >= usize::from(recovery.threshold) {
+            self.ready_at = now.checked_add(i64::from(recovery.delay)).ok_or(MsError::InvalidRecoveryConfig)?;
+        }
+        Ok(())
+    }
+
+    /// Checks that the delay has passed, and that the approvals still meet the guardian threshold
+    pub fn is_ready(&self, recovery: &RecoveryConfig, now: i64) -> bool {
+        self.ready_at > 0 &&
+        now >= self.ready_at &&
+        recovery.approvals(&self.approvals) >= usize::from(recovery.threshold)
+    }
+}
+
+/// The permissions mask of a multisig member.
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone)]
+pub struct MemberPermissions {
+    pub key: Pubkey,
+    pub mask: u8,
+}
+
+impl MemberPermissions {
+    pub const SIZE: usize = 32 + 1;
+
+    /// Can create transactions
+    pub const INITIATE: u8 = 1 << 0;
+    /// Can approve, reject and cancel transactions
+    pub const VOTE: u8 = 1 << 1;
+    /// Can execute transactions
+    pub const EXECUTE: u8 = 1 << 2;
+    /// The permissions of members without an entry in member_permissions
+    pub const ALL: u8 = MemberPermissions::INITIATE | MemberPermissions::VOTE | MemberPermissions::EXECUTE;
+}
+
+/// The Ms layout prior to the trailing settings fields (time lock, etc.).
+/// Multisigs created with this layout may not have the space needed to
+/// deserialize into the current Ms, or may have stale bytes after the keys
+/// left by removed members, and are read with this struct when migrating them.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LegacyMs {
+    pub threshold: u16,
+    pub authority_index: u16,
+    pub transaction_index: u32,
+    pub ms_change_index: u32,
+    pub bump: u8,
+    pub create_key: Pubkey,
+    pub migrated: bool,                 // the DEPRECATED allow_external_execute flag in this layout
+    pub keys: Vec<Pubkey>,
+}
+
+/// A vote of a member on a transaction.
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, PartialEq, Eq)]
+pub enum Vote {
+    Approve,
+    Reject,
+    Abstain,
+}
+
+/// A change of a member's vote on a transaction, kept for auditing.
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone)]
+pub struct VoteChange {
+    pub member: Pubkey,
+    pub old_vote: Vote,
+    pub new_vote: Vote,
+    pub slot: u64,                      // the slot the vote was changed in
+}
+
+impl VoteChange {
+    pub const SIZE: usize = 32 + 1 + 1 + 8;
 }
 
 /// MsTransactionStatus enum of the current status of the Multisig Transaction.
@@ -116,6 +930,7 @@ pub enum MsTransactionStatus {
     Executed,       // Transaction has been executed
     Rejected,       // Transaction has been rejected
     Cancelled,      // Transaction has been cancelled
+    Expired,        // Transaction has passed its expiry before being executed
 }
 
 /// The MsTransaction is the state account for a multisig transaction
@@ -132,8 +947,14 @@ pub struct MsTransaction {
     pub approved: Vec<Pubkey>,          // keys that have approved/signed
     pub rejected: Vec<Pubkey>,          // keys that have rejected
     pub cancelled: Vec<Pubkey>,         // keys that have cancelled (ExecuteReady only)
-    pub executed_index: u8              // if Tx is executed sequentially, tracks which ix
+    pub executed_index: u8,             // if Tx is executed sequentially, tracks which ix
                                         // has been executed so far.
+    pub ready_at: i64,                  // unix timestamp of when the transaction became ExecuteReady
+    pub expires_at: i64,                // unix timestamp after which the transaction can't be
+                                        // approved or executed, 0 means it never expires.
+    pub ephemeral_signers: u8,          // number of ephemeral signer PDAs the transaction signs for
+    pub abstained: Vec<Pubkey>,         // keys that have abstained
+    pub vote_changes: Vec<VoteChange>,  // the latest vote changes, oldest first
 }
 
 impl MsTransaction {
@@ -146,7 +967,18 @@ impl MsTransaction {
         (1 + 12) +                          // the enum size
         1 +                                 // the number of instructions (attached)
         1 +                                 // space for tx bump
-        1;                                  // track index if executed sequentially
+        1 +                                 // track index if executed sequentially
+        8 +                                 // timestamp the transaction became execute ready
+        8 +                                 // timestamp the transaction expires
+        1 +                                 // number of ephemeral signers
+        4 +                                 // abstained vec length (keys share the space of the other votes)
+        4 + (MsTransaction::MAX_VOTE_CHANGES * VoteChange::SIZE); // vote change history
+
+    /// the number of vote changes kept in the history
+    pub const MAX_VOTE_CHANGES: usize = 10;
+
+    /// the maximum number of ephemeral signers, their bumps are derived when executing
+    pub const MAX_EPHEMERAL_SIGNERS: u8 = 8;
 
     pub fn initial_size_with_members(members_len: usize) -> usize {
         MsTransaction::MINIMUM_SIZE + (3 * (4 + (members_len * 32) ) )
@@ -166,21 +998,51 @@ impl MsTransaction {
         self.cancelled = Vec::new();
         self.bump = bump;
         self.executed_index = 0;
+        self.ready_at = 0;
+        self.expires_at = 0;
+        self.ephemeral_signers = 0;
+        self.abstained = Vec::new();
+        self.vote_changes = Vec::new();
         Ok(())
     }
 
+    /// derives the index and bump of each ephemeral signer of the transaction,
+    /// which are seeded from [b"squad", transaction, index, b"ephemeral_signer"]
+    pub fn ephemeral_signer_indexes_and_bumps(&self, transaction: &Pubkey, program_id: &Pubkey) -> Vec<[u8; 2]> {
+        (0..self.ephemeral_signers)
+            .map(|index| {
+                let (_, bump) = Pubkey::find_program_address(
+                    &[b"squad", transaction.as_ref(), &index.to_le_bytes(), b"ephemeral_signer"],
+                    program_id,
+                );
+                [index, bump]
+            })
+            .collect()
+    }
+
     /// change status to Active
     pub fn activate(&mut self)-> Result<()>{
         self.status = MsTransactionStatus::Active;
         Ok(())
     }
 
-    /// change status to ExecuteReady
-    pub fn ready_to_execute(&mut self)-> Result<()>{
+    /// change status to ExecuteReady and record when it happened
+    pub fn ready_to_execute(&mut self, ready_at: i64)-> Result<()>{
         self.status = MsTransactionStatus::ExecuteReady;
+        self.ready_at = ready_at;
         Ok(())
     }
 
+    /// check if the time lock of the multisig has passed since the transaction became ExecuteReady
+    pub fn is_time_lock_released(&self, time_lock: u32, now: i64) -> bool {
+        // ready_at isn't recorded for transactions approved before it existed,
+        // these can only be executed while the time lock is disabled
+        if self.ready_at == 0 {
+            return time_lock == 0;
+        }
+        self.ready_at.saturating_add(i64::from(time_lock)) <= now
+    }
+
     /// set status to Rejected
     pub fn set_rejected(&mut self) -> Result<()>{
         self.status = MsTransactionStatus::Rejected;
@@ -199,6 +1061,31 @@ impl MsTransaction {
         Ok(())
     }
 
+    /// set status to Expired
+    pub fn set_expired(&mut self) -> Result<()>{
+        self.status = MsTransactionStatus::Expired;
+        Ok(())
+    }
+
+    /// check if the transaction is finished and its accounts can be closed.
+    /// Draft and Active transactions can be closed once deprecated by the change index.
+    pub fn is_closeable(&self, ms_change_index: u32) -> bool {
+        match self.status {
+            MsTransactionStatus::Executed |
+            MsTransactionStatus::Rejected |
+            MsTransactionStatus::Cancelled |
+            MsTransactionStatus::Expired => true,
+            MsTransactionStatus::Draft |
+            MsTransactionStatus::Active => self.transaction_index <= ms_change_index,
+            MsTransactionStatus::ExecuteReady => false,
+        }
+    }
+
+    /// check if the transaction has passed its expiry
+    pub fn is_expired(&self, now: i64) -> bool {
+        self.expires_at > 0 && now >= self.expires_at
+    }
+
     /// sign to approve a transaction
     pub fn sign(&mut self, member: Pubkey) -> Result<()>{
         self.approved.push(member);
@@ -221,11 +1108,110 @@ impl MsTransaction {
     }
 
 
+    /// replaces a key in the votes of the transaction, and as the creator.
+    /// If the new key already has a vote (ie. it was rotated away before), that vote is
+    /// kept and the vote of the old key is dropped so the weight isn't counted twice,
+    /// which is recorded as a vote change if the votes differ.
+    pub fn rotate_member(&mut self, old_member: Pubkey, new_member: Pubkey, slot: u64, available_space: usize) -> Result<()> {
+        let old_vote = self.current_vote(old_member);
+        let new_vote = self.current_vote(new_member);
+        if let Some(old_vote) = old_vote {
+            let votes = self.votes_mut(old_vote);
+            if let Ok(ind) = votes.binary_search(&old_member) {
+                votes.remove(ind);
+            }
+            if new_vote.is_none() {
+                let votes = self.votes_mut(old_vote);
+                if let Err(ind) = votes.binary_search(&new_member) {
+                    votes.insert(ind, new_member);
+                }
+            }
+        }
+        if let Ok(ind) = self.cancelled.binary_search(&old_member) {
+            self.cancelled.remove(ind);
+            if let Err(ind) = self.cancelled.binary_search(&new_member) {
+                self.cancelled.insert(ind, new_member);
+            }
+        }
+        if let (Some(old_vote), Some(new_vote)) = (old_vote, new_vote) {
+            if old_vote != new_vote {
+                self.record_vote_change(VoteChange { member: new_member, old_vote, new_vote, slot }, available_space);
+            }
+        }
+        if self.creator == old_member {
+            self.creator = new_member;
+        }
+        Ok(())
+    }
+
     /// check if a user has voted already
     pub fn has_voted(&self, member: Pubkey) -> bool {
-        let approved = self.approved.binary_search(&member).is_ok();
-        let rejected = self.rejected.binary_search(&member).is_ok();
-        approved || rejected
+        self.current_vote(member).is_some()
+    }
+
+    /// gets the current vote of a member, if they have voted
+    pub fn current_vote(&self, member: Pubkey) -> Option<Vote> {
+        if self.approved.binary_search(&member).is_ok() {
+            Some(Vote::Approve)
+        } else if self.rejected.binary_search(&member).is_ok() {
+            Some(Vote::Reject)
+        } else if self.abstained.binary_search(&member).is_ok() {
+            Some(Vote::Abstain)
+        } else {
+            None
+        }
+    }
+
+    /// sets the vote of a member, moving it from any previous vote.
+    /// Changes to a previous vote are recorded in the vote change history,
+    /// which keeps the latest changes that fit in the available space.
+    pub fn set_vote(&mut self, member: Pubkey, vote: Vote, slot: u64, available_space: usize) -> Result<()> {
+        let old_vote = self.current_vote(member);
+        if old_vote == Some(vote) {
+            return Ok(());
+        }
+        if let Some(old_vote) = old_vote {
+            let votes = self.votes_mut(old_vote);
+            if let Ok(ind) = votes.binary_search(&member) {
+                votes.remove(ind);
+            }
+        }
+        let votes = self.votes_mut(vote);
+        if let Err(ind) = votes.binary_search(&member) {
+            votes.insert(ind, member);
+        }
+
+        if let Some(old_vote) = old_vote {
+            self.record_vote_change(VoteChange { member, old_vote, new_vote: vote, slot }, available_space);
+        }
+        Ok(())
+    }
+
+    /// adds a vote change to the history, dropping the oldest changes that don't fit
+    fn record_vote_change(&mut self, change: VoteChange, available_space: usize) {
+        self.vote_changes.push(change);
+        while !self.vote_changes.is_empty() && (
+            self.vote_changes.len() > MsTransaction::MAX_VOTE_CHANGES ||
+            self.space_used() > available_space
+        ) {
+            self.vote_changes.remove(0);
+        }
+    }
+
+    fn votes_mut(&mut self, vote: Vote) -> &mut Vec<Pubkey> {
+        match vote {
+            Vote::Approve => &mut self.approved,
+            Vote::Reject => &mut self.rejected,
+            Vote::Abstain => &mut self.abstained,
+        }
+    }
+
+    /// the space currently used by the serialized transaction (without the discriminator)
+    pub fn space_used(&self) -> usize {
+        MsTransaction::MINIMUM_SIZE - (MsTransaction::MAX_VOTE_CHANGES * VoteChange::SIZE) +
+        (3 * 4) +                           // approved, rejected, cancelled vec lengths
+        ((self.approved.len() + self.rejected.len() + self.cancelled.len() + self.abstained.len()) * 32) +
+        (self.vote_changes.len() * VoteChange::SIZE)
     }
 
     /// check if a user has signed to approve
@@ -306,6 +1292,321 @@ impl From<MsInstruction> for Instruction {
     }
 }
 
+/// The state account for several consecutive instructions that are attached to a
+/// transaction at once. Uses the PDA of the first instruction index it holds.
+#[account]
+pub struct MsInstructionBatch {
+    pub instruction_index: u8,              // index of the first instruction in the batch
+    pub bump: u8,
+    pub instructions: Vec<IncomingInstruction>,
+}
+
+impl MsInstructionBatch {
+    /// Calculates how much space will be needed to allocate to the batch account
+    pub fn get_max_size(instructions: &[IncomingInstruction]) -> usize {
+        1 +     // the instruction index
+        1 +     // the bump
+        4 +     // for vec length
+        instructions
+            .iter()
+            .map(|ix| get_instance_packed_len(ix).unwrap_or_default())
+            .sum::<usize>()
+    }
+
+    /// Initializes the instruction batch account
+    pub fn init(&mut self, instruction_index: u8, instructions: Vec<IncomingInstruction>, bump: u8) -> Result<()> {
+        self.instruction_index = instruction_index;
+        self.bump = bump;
+        self.instructions = instructions;
+        Ok(())
+    }
+}
+
+/// The state account used to upload an instruction that is too large to be attached
+/// with add_instruction in a single transaction. The account is sized for the full
+/// instruction data when it's created, the data is then appended over several transactions,
+/// and the account is turned into an MsInstruction by finalize_instruction.
+/// Seeded from [b"squad", transaction, b"instruction_buffer"].
+#[account]
+pub struct MsInstructionBuffer {
+    pub program_id: Pubkey,
+    pub keys: Vec<MsAccountMeta>,
+    pub data_len: u32,              // the length of the instruction data once complete
+    pub data: Vec<u8>,
+    pub bump: u8,
+}
+
+impl MsInstructionBuffer {
+    /// Calculates how much space will be needed to allocate to the buffer account
+    pub fn size(keys: &[MsAccountMeta], data_len: u32) -> usize {
+        8 +                                             // Anchor discriminator
+        32 +                                            // the program id
+        4 + keys.len() * 34 +                           // the account metas
+        4 +                                             // the data length
+        4 + usize::try_from(data_len).unwrap() +        // the data
+        1                                               // PDA bump
+    }
+
+    /// Initializes the buffer account, without any data
+    pub fn init(&mut self, program_id: Pubkey, keys: Vec<MsAccountMeta>, data_len: u32, bump: u8) -> Result<()> {
+        self.program_id = program_id;
+        self.keys = keys;
+        self.data_len = data_len;
+        self.data = Vec::new();
+        self.bump = bump;
+        Ok(())
+    }
+
+    /// Appends the next part of the instruction data
+    pub fn append(&mut self, data: &[u8]) -> Result<()> {
+        let new_len = self.data.len()
+            .checked_add(data.len())
+            .ok_or(MsError::InstructionBufferOverflow)?;
+        if new_len > usize::try_from(self.data_len).unwrap() {
+            return err!(MsError::InstructionBufferOverflow);
+        }
+        self.data.extend_from_slice(data);
+        Ok(())
+    }
+
+    /// Checks if all of the instruction data has been uploaded
+    pub fn is_complete(&self) -> bool {
+        self.data.len() == usize::try_from(self.data_len).unwrap()
+    }
+
+    /// The uploaded instruction, to be saved in an MsInstruction
+    pub fn to_incoming_instruction(&self) -> IncomingInstruction {
+        IncomingInstruction {
+            program_id: self.program_id,
+            keys: self.keys.clone(),
+            data: self.data.clone(),
+        }
+    }
+}
+
+impl From<IncomingInstruction> for Instruction {
+    /// Converts the IncomingInstruction to a native Instruction
+    fn from(instruction: IncomingInstruction) -> Self {
+        Instruction {
+            program_id: instruction.program_id,
+            accounts: instruction
+                .keys
+                .iter()
+                .map(|account| AccountMeta {
+                    pubkey: account.pubkey,
+                    is_signer: account.is_signer,
+                    is_writable: account.is_writable,
+                })
+                .collect(),
+            data: instruction.data,
+        }
+    }
+}
+
+/// The address lookup table program, used to resolve the accounts of MsLookupInstructions
+pub mod address_lookup_table_program {
+    anchor_lang::declare_id!("AddressLookupTab1e1111111111111111111111111");
+}
+
+/// The size of the metadata at the start of an address lookup table account,
+/// the addresses of the table follow it.
+pub const LOOKUP_TABLE_META_SIZE: usize = 56;
+
+/// Reads the addresses stored in an address lookup table account.
+/// The lookup table must be owned by the lookup table program, and its
+/// authority must be the given key (ie. the authority of the transaction).
+pub fn get_lookup_table_addresses(lookup_table: &AccountInfo, authority: &Pubkey) -> Result<Vec<Pubkey>> {
+    if lookup_table.owner != &address_lookup_table_program::ID {
+        return err!(MsError::InvalidLookupTable);
+    }
+    let data = lookup_table.try_borrow_data()?;
+    if data.len() < LOOKUP_TABLE_META_SIZE {
+        return err!(MsError::InvalidLookupTable);
+    }
+    // lookup table state discriminator (u32) of 1, and authority option set to Some
+    if data[0..4] != 1u32.to_le_bytes() || data[21] != 1 || &data[22..54] != authority.as_ref() {
+        return err!(MsError::InvalidLookupTable);
+    }
+    Ok(data[LOOKUP_TABLE_META_SIZE..]
+        .chunks_exact(32)
+        .filter_map(|address| <[u8; 32]>::try_from(address).ok())
+        .map(Pubkey::new_from_array)
+        .collect())
+}
+
+/// Closes every instruction account (MsInstruction, MsInstructionBatch or MsLookupInstruction)
+/// attached to the transaction, sending the lamports to the receiver. The instruction accounts
+/// must be passed in order, and all of them have to be closed.
+pub fn close_instruction_accounts<'info>(
+    program_id: &Pubkey,
+    transaction: &Account<'info, MsTransaction>,
+    instruction_accounts: &[AccountInfo<'info>],
+    receiver: &AccountInfo<'info>,
+) -> Result<()> {
+    let tx_key = transaction.key();
+    let ix_iter = &mut instruction_accounts.iter();
+
+    // number of attached instructions that have been closed
+    let mut ix_count: u8 = 0;
+    while ix_count < transaction.instruction_index {
+        let i: u8 = ix_count + 1;
+        let ms_ix_account = next_account_info(ix_iter)?;
+        if ms_ix_account.owner != program_id {
+            return err!(MsError::InvalidInstructionAccount);
+        }
+
+        // check the instruction account key matches the derived pda
+        let check_ix_pda = |bump: u8| -> Result<()> {
+            let ix_pda = Pubkey::create_program_address(
+                &[
+                    b"squad",
+                    tx_key.as_ref(),
+                    &i.to_le_bytes(),
+                    b"instruction",
+                    &[bump],
+                ],
+                program_id,
+            )
+            .map_err(|_| MsError::InvalidInstructionAccount)?;
+            if &ix_pda != ms_ix_account.key {
+                return err!(MsError::InvalidInstructionAccount);
+            }
+            Ok(())
+        };
+
+        // checks the discriminator of the instruction (batch or lookup) account
+        let is_batch = ms_ix_account
+            .try_borrow_data()?
+            .starts_with(&MsInstructionBatch::DISCRIMINATOR);
+        let is_lookup = ms_ix_account
+            .try_borrow_data()?
+            .starts_with(&MsLookupInstruction::DISCRIMINATOR);
+        let ix_len = if is_batch {
+            let ms_ix_batch: Account<MsInstructionBatch> = Account::try_from(ms_ix_account)?;
+            check_ix_pda(ms_ix_batch.bump)?;
+            ms_ix_batch.close(receiver.clone())?;
+            ms_ix_batch.instructions.len()
+        } else if is_lookup {
+            let ms_lookup_ix: Account<MsLookupInstruction> = Account::try_from(ms_ix_account)?;
+            check_ix_pda(ms_lookup_ix.bump)?;
+            ms_lookup_ix.close(receiver.clone())?;
+            1
+        } else {
+            let ms_ix: Account<MsInstruction> = Account::try_from(ms_ix_account)?;
+            check_ix_pda(ms_ix.bump)?;
+            ms_ix.close(receiver.clone())?;
+            1
+        };
+        ix_count = ix_count
+            .checked_add(ix_len.try_into().unwrap())
+            .ok_or(MsError::InvalidInstructionAccount)?;
+    }
+
+    // an instruction buffer that was never finalized can be passed after the instruction accounts
+    if let Some(buffer_account) = ix_iter.next() {
+        if buffer_account.owner != program_id {
+            return err!(MsError::InvalidInstructionAccount);
+        }
+        let instruction_buffer: Account<MsInstructionBuffer> = Account::try_from(buffer_account)?;
+        let buffer_pda = Pubkey::create_program_address(
+            &[
+                b"squad",
+                tx_key.as_ref(),
+                b"instruction_buffer",
+                &[instruction_buffer.bump],
+            ],
+            program_id,
+        )
+        .map_err(|_| MsError::InvalidInstructionAccount)?;
+        if &buffer_pda != buffer_account.key {
+            return err!(MsError::InvalidInstructionAccount);
+        }
+        instruction_buffer.close(receiver.clone())?;
+    }
+
+    // every attached instruction account needs to be closed with the transaction
+    if ix_iter.next().is_some() {
+        return err!(MsError::InvalidNumberOfAccounts);
+    }
+    Ok(())
+}
+
+/// The state account for an instruction whose accounts are resolved from address
+/// lookup tables controlled by the transaction authority when it's executed.
+#[account]
+pub struct MsLookupInstruction {
+    pub program_id: Pubkey,
+    pub lookup_tables: Vec<Pubkey>,
+    pub keys: Vec<MsLookupAccountMeta>,
+    pub data: Vec<u8>,
+    pub instruction_index: u8,
+    pub bump: u8,
+}
+
+impl MsLookupInstruction {
+    /// Initializes the lookup instruction account
+    pub fn init(&mut self, instruction_index: u8, incoming_instruction: IncomingLookupInstruction, bump: u8) -> Result<()> {
+        self.bump = bump;
+        self.instruction_index = instruction_index;
+        self.program_id = incoming_instruction.program_id;
+        self.lookup_tables = incoming_instruction.lookup_tables;
+        self.keys = incoming_instruction.keys;
+        self.data = incoming_instruction.data;
+        Ok(())
+    }
+
+    /// Converts the lookup instruction to a native Instruction, using the
+    /// addresses of its lookup tables (in the same order as lookup_tables)
+    pub fn resolve(&self, lookup_table_addresses: &[Vec<Pubkey>]) -> Result<Instruction> {
+        let accounts = self
+            .keys
+            .iter()
+            .map(|account| {
+                let pubkey = lookup_table_addresses
+                    .get(usize::from(account.table_index))
+                    .and_then(|addresses| addresses.get(usize::from(account.address_index)))
+                    .ok_or(MsError::InvalidLookupTable)?;
+                Ok(AccountMeta {
+                    pubkey: *pubkey,
+                    is_signer: account.is_signer,
+                    is_writable: account.is_writable,
+                })
+            })
+            .collect::<Result<Vec<AccountMeta>>>()?;
+        Ok(Instruction {
+            program_id: self.program_id,
+            accounts,
+            data: self.data.clone(),
+        })
+    }
+}
+
+/// Account meta for a MsLookupInstruction, referencing an address in one of its lookup tables
+#[derive(AnchorSerialize,AnchorDeserialize, Copy, Clone)]
+pub struct MsLookupAccountMeta {
+    pub table_index: u8,        // index of the lookup table in the instruction's lookup_tables
+    pub address_index: u8,      // index of the address in the lookup table
+    pub is_signer: bool,
+    pub is_writable: bool
+}
+
+/// Incoming lookup instruction schema, used as an argument in add_lookup_instruction.
+#[derive(AnchorSerialize,AnchorDeserialize, Clone)]
+pub struct IncomingLookupInstruction {
+    pub program_id: Pubkey,
+    pub lookup_tables: Vec<Pubkey>,
+    pub keys: Vec<MsLookupAccountMeta>,
+    pub data: Vec<u8>
+}
+
+impl IncomingLookupInstruction {
+    /// Calculates how much space will be needed to allocate to the lookup instruction
+    /// there are 2 extra bytes in a saved lookup instruction account: index, bump
+    pub fn get_max_size(&self) -> usize {
+        get_instance_packed_len(&self).unwrap_or_default().checked_add(2).unwrap_or_default()
+    }
+}
+
 /// Wrapper for our internal MsInstruction key serialization schema
 /// MsAccount meta is identical to the AccountMeta struct, but defined
 /// here for serialization purposes.
@@ -316,6 +1617,76 @@ pub struct MsAccountMeta {
     pub is_writable: bool
 }
 
+impl MsAccountMeta {
+    pub const SIZE: usize = 32 + 1 + 1;
+}
+
+/// The accounts needed to execute a transaction, returned by get_execution_accounts.
+/// The accounts are deduplicated and are passed as the remaining accounts of
+/// execute_transaction, along with the account_list, which indexes into them.
+#[derive(AnchorSerialize,AnchorDeserialize, Clone, Default)]
+pub struct ExecutionAccounts {
+    pub accounts: Vec<MsAccountMeta>,
+    pub account_list: Vec<u8>,
+}
+
+impl ExecutionAccounts {
+    /// Adds the next account of the account list, merging it with
+    /// any previous occurrence of the same key
+    pub fn add(&mut self, pubkey: Pubkey, is_signer: bool, is_writable: bool) -> Result<()> {
+        let index = match self.accounts.iter().position(|meta| meta.pubkey == pubkey) {
+            Some(index) => {
+                let meta = &mut self.accounts[index];
+                meta.is_signer |= is_signer;
+                meta.is_writable |= is_writable;
+                index
+            },
+            None => {
+                self.accounts.push(MsAccountMeta { pubkey, is_signer, is_writable });
+                self.accounts.len() - 1
+            }
+        };
+        // the account list indexes into the accounts with a u8
+        let index = u8::try_from(index).map_err(|_| MsError::InvalidNumberOfAccounts)?;
+        self.account_list.push(index);
+        Ok(())
+    }
+
+    /// Splits out a page of the accounts, starting at offset, that fits in the return data
+    /// along with the full account_list. limit caps the number of accounts in the page.
+    pub fn page(self, offset: u16, limit: Option<u16>) -> Result<ExecutionAccountsPage> {
+        let total_accounts = u16::try_from(self.accounts.len()).map_err(|_| MsError::InvalidNumberOfAccounts)?;
+        let offset = usize::from(offset).min(self.accounts.len());
+        // the space left for the accounts once the account_list and total are serialized
+        let available_space = MAX_RETURN_DATA
+            .checked_sub(4 + self.account_list.len() + 4 + 2)
+            .ok_or(MsError::InvalidNumberOfAccounts)?;
+        let max_accounts = available_space / MsAccountMeta::SIZE;
+        let page_len = limit
+            .map_or(max_accounts, |limit| usize::from(limit).min(max_accounts))
+            .min(self.accounts.len() - offset);
+        // the page has to make progress, unless there are no accounts left
+        if page_len < 1 && offset < self.accounts.len() {
+            return err!(MsError::InvalidNumberOfAccounts);
+        }
+        Ok(ExecutionAccountsPage {
+            accounts: self.accounts[offset..offset + page_len].to_vec(),
+            account_list: self.account_list,
+            total_accounts,
+        })
+    }
+}
+
+/// A page of the ExecutionAccounts returned by get_execution_accounts. The return data
+/// of an instruction is limited to 1024 bytes, so the accounts are fetched in pages by
+/// passing the offset of the next page until all total_accounts have been returned.
+#[derive(AnchorSerialize,AnchorDeserialize, Clone, Default)]
+pub struct ExecutionAccountsPage {
+    pub accounts: Vec<MsAccountMeta>,
+    pub account_list: Vec<u8>,
+    pub total_accounts: u16,
+}
+
 /// Incoming instruction schema, used as an argument in the attach_instruction.
 /// Identical to the solana struct for Instruction, but uses the MsAccountMeta.
 /// Provided for de/serialization purposes.
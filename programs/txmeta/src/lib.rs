@@ -1,11 +1,14 @@
 use anchor_lang::prelude::*;
-use squads_mpl::state::{Ms, MsTransaction};
+use squads_mpl::state::{Ms, MsTransaction, MsTransactionStatus};
 declare_id!("SMPL5bz5ERMdweouWrXtk3jmb6FnjZkWf7pHDsE6Zwz");
 
 #[program]
 pub mod txmeta {
     use super::*;
 
+    /// Logs the meta string and remaining account keys for a transaction.
+    /// The logs are only available as long as the RPC retains them, prefer
+    /// create_transaction_meta to store the description on-chain.
     #[access_control(Track::validate(&ctx))]
     pub fn track_meta(ctx: Context<Track>, meta: String) -> Result<()> {
         let remaining = ctx.remaining_accounts;
@@ -15,6 +18,52 @@ pub mod txmeta {
         msg!("Track Meta: {:?}", meta);
         Ok(())
     }
+
+    /// Creates the TransactionMeta account for a [squads_mpl::state::MsTransaction],
+    /// holding its title, description and links. Only the transaction creator can
+    /// create it, while the transaction is in a Draft or Active state.
+    pub fn create_transaction_meta(
+        ctx: Context<CreateTransactionMeta>,
+        title: String,
+        description: String,
+        links: Vec<String>,
+    ) -> Result<()> {
+        let transaction_meta = &mut ctx.accounts.transaction_meta;
+        transaction_meta.transaction = ctx.accounts.transaction.key();
+        transaction_meta.creator = ctx.accounts.creator.key();
+        transaction_meta.bump = *ctx.bumps.get("transaction_meta").unwrap();
+        transaction_meta.set_meta(title, description, links)
+    }
+
+    /// Updates the title, description and links of a TransactionMeta account.
+    /// Only the transaction creator can update it, while the transaction is in a
+    /// Draft or Active state. The account is resized to fit the new content.
+    pub fn update_transaction_meta(
+        ctx: Context<UpdateTransactionMeta>,
+        title: String,
+        description: String,
+        links: Vec<String>,
+    ) -> Result<()> {
+        ctx.accounts.transaction_meta.set_meta(title, description, links)
+    }
+
+    /// Closes a TransactionMeta account once its transaction is finalized (executed,
+    /// rejected, cancelled or expired) or has been closed, returning the rent to the creator.
+    pub fn close_transaction_meta(ctx: Context<CloseTransactionMeta>) -> Result<()> {
+        let transaction_info = ctx.accounts.transaction.to_account_info();
+        // a transaction that has been closed no longer holds any data
+        if transaction_info.owner == &squads_mpl::ID && !transaction_info.data_is_empty() {
+            let transaction = Account::<MsTransaction>::try_from(&transaction_info)?;
+            match transaction.status {
+                MsTransactionStatus::Executed
+                | MsTransactionStatus::Rejected
+                | MsTransactionStatus::Cancelled
+                | MsTransactionStatus::Expired => {}
+                _ => return err!(ErrorCode::InvalidTransactionState),
+            }
+        }
+        Ok(())
+    }
 }
 
 #[derive(Accounts)]
@@ -50,6 +99,121 @@ impl<'info> Track<'info> {
     }
 }
 
+#[derive(Accounts)]
+#[instruction(title: String, description: String, links: Vec<String>)]
+pub struct CreateTransactionMeta<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        constraint = transaction.creator == creator.key() @ ErrorCode::InvalidTransaction,
+        constraint = TransactionMeta::is_editable(&transaction.status) @ ErrorCode::InvalidTransactionState,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = TransactionMeta::size(&title, &description, &links),
+        seeds = [b"squad", transaction.key().as_ref(), b"transaction_meta"], bump
+    )]
+    pub transaction_meta: Account<'info, TransactionMeta>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(title: String, description: String, links: Vec<String>)]
+pub struct UpdateTransactionMeta<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        constraint = transaction.creator == creator.key() @ ErrorCode::InvalidTransaction,
+        constraint = TransactionMeta::is_editable(&transaction.status) @ ErrorCode::InvalidTransactionState,
+    )]
+    pub transaction: Account<'info, MsTransaction>,
+
+    #[account(
+        mut,
+        seeds = [b"squad", transaction.key().as_ref(), b"transaction_meta"], bump = transaction_meta.bump,
+        realloc = TransactionMeta::size(&title, &description, &links),
+        realloc::payer = creator,
+        realloc::zero = false,
+    )]
+    pub transaction_meta: Account<'info, TransactionMeta>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CloseTransactionMeta<'info> {
+    /// CHECK: the transaction the meta belongs to, which may have been closed already
+    #[account(address = transaction_meta.transaction @ ErrorCode::InvalidTransaction)]
+    pub transaction: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"squad", transaction.key().as_ref(), b"transaction_meta"], bump = transaction_meta.bump,
+        close = creator
+    )]
+    pub transaction_meta: Account<'info, TransactionMeta>,
+
+    /// CHECK: the creator of the transaction meta, who receives the rent
+    #[account(mut, address = transaction_meta.creator @ ErrorCode::Unauthorized)]
+    pub creator: UncheckedAccount<'info>,
+}
+
+/// The TransactionMeta describes a [squads_mpl::state::MsTransaction], and is
+/// seeded from [b"squad", transaction, b"transaction_meta"].
+#[account]
+pub struct TransactionMeta {
+    pub transaction: Pubkey,            // the transaction this describes
+    pub creator: Pubkey,                // the creator of the transaction, who paid the rent
+    pub title: String,
+    pub description: String,
+    pub links: Vec<String>,             // optional links, ie. to a forum discussion
+    pub bump: u8,
+}
+
+impl TransactionMeta {
+    pub const MAX_TITLE_LEN: usize = 64;
+    pub const MAX_DESCRIPTION_LEN: usize = 512;
+    pub const MAX_LINKS: usize = 4;
+    pub const MAX_LINK_LEN: usize = 128;
+
+    /// The space needed to store the given content
+    pub fn size(title: &str, description: &str, links: &[String]) -> usize {
+        8 +                                                     // Anchor discriminator
+        32 +                                                    // the transaction key
+        32 +                                                    // the creator key
+        4 + title.len() +                                       // the title
+        4 + description.len() +                                 // the description
+        4 + links.iter().map(|link| 4 + link.len()).sum::<usize>() + // the links
+        1                                                       // PDA bump
+    }
+
+    /// The meta can only be changed before the transaction is approved or finalized
+    pub fn is_editable(status: &MsTransactionStatus) -> bool {
+        matches!(status, MsTransactionStatus::Draft | MsTransactionStatus::Active)
+    }
+
+    /// Sets the content, checking it against the size limits
+    pub fn set_meta(&mut self, title: String, description: String, links: Vec<String>) -> Result<()> {
+        if title.len() > TransactionMeta::MAX_TITLE_LEN
+            || description.len() > TransactionMeta::MAX_DESCRIPTION_LEN
+            || links.len() > TransactionMeta::MAX_LINKS
+            || links.iter().any(|link| link.len() > TransactionMeta::MAX_LINK_LEN)
+        {
+            return err!(ErrorCode::MetaTooLong);
+        }
+        self.title = title;
+        self.description = description;
+        self.links = links;
+        Ok(())
+    }
+}
+
 #[error_code]
 pub enum ErrorCode {
     #[msg("Signer is not a member of the specified multisig.")]
@@ -58,4 +222,8 @@ pub enum ErrorCode {
     InvalidOwner,
     #[msg("The transaction is either not associated with the supplied multisig or it's creator is not the supplied signer")]
     InvalidTransaction,
+    #[msg("The transaction is not in a state that allows this action.")]
+    InvalidTransactionState,
+    #[msg("The title, description or links exceed the size limits.")]
+    MetaTooLong,
 }
@@ -12,6 +12,16 @@ export const getMsPDA = (create_key: PublicKey, programId: PublicKey) =>
     programId
   );
 
+export const getMetadataPDA = (msPDA: PublicKey, programId: PublicKey) =>
+  PublicKey.findProgramAddressSync(
+    [
+      utils.bytes.utf8.encode("squad"),
+      msPDA.toBuffer(),
+      utils.bytes.utf8.encode("metadata"),
+    ],
+    programId
+  );
+
 export const getTxPDA = (
   msPDA: PublicKey,
   txIndexBN: BN,
@@ -96,3 +106,65 @@ export const getProgramUpgradePDA = (
     ],
     programId
   );
+
+export const getPolicyPDA = (
+  msPDA: PublicKey,
+  authorityIndexBN: BN,
+  programId: PublicKey
+) =>
+  PublicKey.findProgramAddressSync(
+    [
+      utils.bytes.utf8.encode("squad"),
+      msPDA.toBuffer(),
+      authorityIndexBN.toArrayLike(Buffer, "le", 4),
+      utils.bytes.utf8.encode("policy"),
+    ],
+    programId
+  );
+
+export const getProgramPolicyPDA = (
+  msPDA: PublicKey,
+  authorityIndexBN: BN,
+  programId: PublicKey
+) =>
+  PublicKey.findProgramAddressSync(
+    [
+      utils.bytes.utf8.encode("squad"),
+      msPDA.toBuffer(),
+      authorityIndexBN.toArrayLike(Buffer, "le", 4),
+      utils.bytes.utf8.encode("program_policy"),
+    ],
+    programId
+  );
+
+export const getAuthorityInfoPDA = (
+  msPDA: PublicKey,
+  authorityIndexBN: BN,
+  programId: PublicKey
+) =>
+  PublicKey.findProgramAddressSync(
+    [
+      utils.bytes.utf8.encode("squad"),
+      msPDA.toBuffer(),
+      authorityIndexBN.toArrayLike(Buffer, "le", 4),
+      utils.bytes.utf8.encode("authority_info"),
+    ],
+    programId
+  );
+
+export const getSpendingLimitPDA = (
+  msPDA: PublicKey,
+  authorityIndexBN: BN,
+  mint: PublicKey,
+  programId: PublicKey
+) =>
+  PublicKey.findProgramAddressSync(
+    [
+      utils.bytes.utf8.encode("squad"),
+      msPDA.toBuffer(),
+      authorityIndexBN.toArrayLike(Buffer, "le", 4),
+      mint.toBuffer(),
+      utils.bytes.utf8.encode("spending_limit"),
+    ],
+    programId
+  );
@@ -5,6 +5,7 @@ import {
   ConnectionConfig,
   TransactionInstruction,
   Signer,
+  AccountMeta,
 } from "@solana/web3.js";
 import {
   DEFAULT_MULTISIG_PROGRAM_ID,
@@ -27,11 +28,16 @@ import {
 } from "./types";
 import {
   getAuthorityPDA,
+  getAuthorityInfoPDA,
   getIxPDA,
   getManagedProgramPDA,
   getMsPDA,
+  getMetadataPDA,
+  getPolicyPDA,
   getProgramManagerPDA,
+  getProgramPolicyPDA,
   getProgramUpgradePDA,
+  getSpendingLimitPDA,
   getTxPDA,
 } from "./address";
 import BN from "bn.js";
@@ -158,6 +164,54 @@ class Squads {
     );
   }
 
+  // the AuthorityPolicy PDA needed to vote, while the multisig has any authority policies
+  private _getAuthorityPolicyAccounts(
+      multisig: MultisigAccount,
+      authorityIndex: number
+  ): AccountMeta[] {
+    if (multisig.authorityPolicyCount < 1 || authorityIndex < 1) {
+      return [];
+    }
+    const [policyPDA] = getPolicyPDA(
+        multisig.publicKey,
+        new BN(authorityIndex, 10),
+        this.multisigProgramId
+    );
+    return [{pubkey: policyPDA, isSigner: false, isWritable: false}];
+  }
+
+  // the ProgramPolicy PDA needed to attach and execute instructions, while the multisig has any program policies
+  private _getProgramPolicyAccounts(
+      multisig: MultisigAccount,
+      authorityIndex: number
+  ): AccountMeta[] {
+    if (multisig.programPolicyCount < 1 || authorityIndex < 1) {
+      return [];
+    }
+    const [policyPDA] = getProgramPolicyPDA(
+        multisig.publicKey,
+        new BN(authorityIndex, 10),
+        this.multisigProgramId
+    );
+    return [{pubkey: policyPDA, isSigner: false, isWritable: false}];
+  }
+
+  // the Authority PDA needed to create a transaction, while the multisig has any disabled authorities
+  private _getAuthorityInfoAccounts(
+      multisig: MultisigAccount,
+      authorityIndex: number
+  ): AccountMeta[] {
+    if (multisig.disabledAuthorityCount < 1 || authorityIndex < 1) {
+      return [];
+    }
+    const [authorityInfoPDA] = getAuthorityInfoPDA(
+        multisig.publicKey,
+        new BN(authorityIndex, 10),
+        this.multisigProgramId
+    );
+    return [{pubkey: authorityInfoPDA, isSigner: false, isWritable: false}];
+  }
+
   async getTransactionBuilder(
       multisigPDA: PublicKey,
       authorityIndex: number
@@ -328,10 +382,11 @@ class Squads {
       initialMembers.push(this.wallet.publicKey);
     }
     const [multisigPDA] = getMsPDA(createKey, this.multisigProgramId);
+    const [metadataPDA] = getMetadataPDA(multisigPDA, this.multisigProgramId);
     return [
       this.multisig.methods
           .create(threshold, createKey, initialMembers, metadata)
-          .accounts({multisig: multisigPDA, creator: this.wallet.publicKey}),
+          .accounts({multisig: multisigPDA, metadata: metadataPDA, creator: this.wallet.publicKey}),
       multisigPDA,
     ];
   }
@@ -381,12 +436,13 @@ class Squads {
         new BN(transactionIndex, 10),
         this.multisigProgramId
     );
+    const multisig = await this.getMultisig(multisigPDA);
     return [
       this.multisig.methods.createTransaction(authorityIndex).accounts({
         multisig: multisigPDA,
         transaction: transactionPDA,
         creator: this.wallet.publicKey,
-      }),
+      }).remainingAccounts(this._getAuthorityInfoAccounts(multisig, authorityIndex)),
       transactionPDA,
     ];
   }
@@ -424,20 +480,27 @@ class Squads {
       multisigPDA: PublicKey,
       transactionPDA: PublicKey,
       instruction: TransactionInstruction,
-      instructionIndex: number
+      instructionIndex: number,
+      authorityIndex?: number
   ): Promise<[SquadsMethods, PublicKey]> {
     const [instructionPDA] = getIxPDA(
         transactionPDA,
         new BN(instructionIndex, 10),
         this.multisigProgramId
     );
+    const multisig = await this.getMultisig(multisigPDA);
+    let policyAccounts: AccountMeta[] = [];
+    if (multisig.programPolicyCount > 0) {
+      const index = authorityIndex ?? (await this.getTransaction(transactionPDA)).authorityIndex;
+      policyAccounts = this._getProgramPolicyAccounts(multisig, index);
+    }
     return [
       this.multisig.methods.addInstruction(instruction).accounts({
         multisig: multisigPDA,
         transaction: transactionPDA,
         instruction: instructionPDA,
         creator: this.wallet.publicKey,
-      }),
+      }).remainingAccounts(policyAccounts),
       instructionPDA,
     ];
   }
@@ -451,7 +514,8 @@ class Squads {
         transaction.ms,
         transactionPDA,
         instruction,
-        transaction.instructionIndex + 1
+        transaction.instructionIndex + 1,
+        transaction.authorityIndex
     );
     await methods.rpc();
     return await this.getInstruction(instructionPDA);
@@ -461,13 +525,15 @@ class Squads {
       multisigPDA: PublicKey,
       transactionPDA: PublicKey,
       instruction: TransactionInstruction,
-      instructionIndex: number
+      instructionIndex: number,
+      authorityIndex?: number
   ): Promise<TransactionInstruction> {
     const [methods] = await this._addInstruction(
         multisigPDA,
         transactionPDA,
         instruction,
-        instructionIndex
+        instructionIndex,
+        authorityIndex
     );
     return await methods.instruction();
   }
@@ -510,11 +576,15 @@ class Squads {
       multisigPDA: PublicKey,
       transactionPDA: PublicKey
   ): Promise<SquadsMethods> {
+    const multisig = await this.getMultisig(multisigPDA);
+    const transaction = await this.getTransaction(transactionPDA);
     return this.multisig.methods.approveTransaction().accounts({
       multisig: multisigPDA,
       transaction: transactionPDA,
       member: this.wallet.publicKey,
-    });
+    }).remainingAccounts(
+        this._getAuthorityPolicyAccounts(multisig, transaction.authorityIndex)
+    );
   }
 
   async approveTransaction(
@@ -541,11 +611,15 @@ class Squads {
       multisigPDA: PublicKey,
       transactionPDA: PublicKey
   ): Promise<SquadsMethods> {
+    const multisig = await this.getMultisig(multisigPDA);
+    const transaction = await this.getTransaction(transactionPDA);
     return this.multisig.methods.rejectTransaction().accounts({
       multisig: multisigPDA,
       transaction: transactionPDA,
       member: this.wallet.publicKey,
-    });
+    }).remainingAccounts(
+        this._getAuthorityPolicyAccounts(multisig, transaction.authorityIndex)
+    );
   }
 
   async rejectTransaction(
@@ -572,11 +646,15 @@ class Squads {
       multisigPDA: PublicKey,
       transactionPDA: PublicKey
   ): Promise<SquadsMethods> {
+    const multisig = await this.getMultisig(multisigPDA);
+    const transaction = await this.getTransaction(transactionPDA);
     return this.multisig.methods.cancelTransaction().accounts({
       multisig: multisigPDA,
       transaction: transactionPDA,
       member: this.wallet.publicKey,
-    });
+    }).remainingAccounts(
+        this._getAuthorityPolicyAccounts(multisig, transaction.authorityIndex)
+    );
   }
 
   async cancelTransaction(
@@ -599,6 +677,121 @@ class Squads {
     return await methods.instruction();
   }
 
+  private async _useSpendingLimit(
+      multisigPDA: PublicKey,
+      authorityIndex: number,
+      destination: PublicKey,
+      amount: BN
+  ): Promise<SquadsMethods> {
+    const [spendingLimitPDA] = getSpendingLimitPDA(
+        multisigPDA,
+        new BN(authorityIndex, 10),
+        PublicKey.default,
+        this.multisigProgramId
+    );
+    const multisig = await this.getMultisig(multisigPDA);
+    return this.multisig.methods.useSpendingLimit(amount).accounts({
+      multisig: multisigPDA,
+      spendingLimit: spendingLimitPDA,
+      authority: this.getAuthorityPDA(multisigPDA, authorityIndex),
+      destination,
+      member: this.wallet.publicKey,
+    }).remainingAccounts(this._getAuthorityInfoAccounts(multisig, authorityIndex));
+  }
+
+  async useSpendingLimit(
+      multisigPDA: PublicKey,
+      authorityIndex: number,
+      destination: PublicKey,
+      amount: BN
+  ): Promise<string> {
+    const methods = await this._useSpendingLimit(
+        multisigPDA,
+        authorityIndex,
+        destination,
+        amount
+    );
+    return await methods.rpc();
+  }
+
+  async buildUseSpendingLimit(
+      multisigPDA: PublicKey,
+      authorityIndex: number,
+      destination: PublicKey,
+      amount: BN
+  ): Promise<TransactionInstruction> {
+    const methods = await this._useSpendingLimit(
+        multisigPDA,
+        authorityIndex,
+        destination,
+        amount
+    );
+    return await methods.instruction();
+  }
+
+  private async _useTokenSpendingLimit(
+      multisigPDA: PublicKey,
+      authorityIndex: number,
+      mint: PublicKey,
+      authorityTokenAccount: PublicKey,
+      destinationTokenAccount: PublicKey,
+      amount: BN
+  ): Promise<SquadsMethods> {
+    const [spendingLimitPDA] = getSpendingLimitPDA(
+        multisigPDA,
+        new BN(authorityIndex, 10),
+        mint,
+        this.multisigProgramId
+    );
+    const multisig = await this.getMultisig(multisigPDA);
+    return this.multisig.methods.useTokenSpendingLimit(amount).accounts({
+      multisig: multisigPDA,
+      spendingLimit: spendingLimitPDA,
+      authority: this.getAuthorityPDA(multisigPDA, authorityIndex),
+      authorityTokenAccount,
+      destinationTokenAccount,
+      member: this.wallet.publicKey,
+    }).remainingAccounts(this._getAuthorityInfoAccounts(multisig, authorityIndex));
+  }
+
+  async useTokenSpendingLimit(
+      multisigPDA: PublicKey,
+      authorityIndex: number,
+      mint: PublicKey,
+      authorityTokenAccount: PublicKey,
+      destinationTokenAccount: PublicKey,
+      amount: BN
+  ): Promise<string> {
+    const methods = await this._useTokenSpendingLimit(
+        multisigPDA,
+        authorityIndex,
+        mint,
+        authorityTokenAccount,
+        destinationTokenAccount,
+        amount
+    );
+    return await methods.rpc();
+  }
+
+  async buildUseTokenSpendingLimit(
+      multisigPDA: PublicKey,
+      authorityIndex: number,
+      mint: PublicKey,
+      authorityTokenAccount: PublicKey,
+      destinationTokenAccount: PublicKey,
+      amount: BN
+  ): Promise<TransactionInstruction> {
+    const methods = await this._useTokenSpendingLimit(
+        multisigPDA,
+        authorityIndex,
+        mint,
+        authorityTokenAccount,
+        destinationTokenAccount,
+        amount
+    );
+    return await methods.instruction();
+  }
+
   private async _executeTransaction(
       transactionPDA: PublicKey,
       feePayer: PublicKey
@@ -667,6 +860,7 @@ class Squads {
       );
     });
 
+    const multisig = await this.getMultisig(transaction.ms);
     const executeIx = await this.multisig.methods
         .executeTransaction(Buffer.from(keyIndexMap))
         .accounts({
@@ -675,7 +869,10 @@ class Squads {
           member: feePayer,
         })
         .instruction();
-    executeIx.keys = executeIx.keys.concat(keysUnique);
+    executeIx.keys = executeIx.keys.concat(
+        keysUnique,
+        this._getProgramPolicyAccounts(multisig, transaction.authorityIndex)
+    );
     return executeIx;
   }
 
@@ -713,13 +910,15 @@ class Squads {
   ): Promise<SquadsMethods> {
     const transaction = await this.getTransaction(transactionPDA);
     const instruction = await this.getInstruction(instructionPDA);
+    const multisig = await this.getMultisig(transaction.ms);
     const remainingAccountKeys: anchor.web3.AccountMeta[] = [
       {pubkey: instruction.programId, isSigner: false, isWritable: false},
     ].concat(
         (instruction.keys as anchor.web3.AccountMeta[]).map((key) => ({
           ...key,
           isSigner: false,
-        }))
+        })),
+        this._getProgramPolicyAccounts(multisig, transaction.authorityIndex)
     );
     return this.multisig.methods
         .executeInstruction()
@@ -3,8 +3,15 @@ import {
   ProgramManagerMethodsNamespace,
   SquadsMethodsNamespace,
 } from "./types";
-import { PublicKey, TransactionInstruction } from "@solana/web3.js";
-import { getAuthorityPDA, getIxPDA, getTxPDA } from "./address";
+import { AccountMeta, PublicKey, TransactionInstruction } from "@solana/web3.js";
+import {
+  getAuthorityInfoPDA,
+  getAuthorityPDA,
+  getIxPDA,
+  getPolicyPDA,
+  getProgramPolicyPDA,
+  getTxPDA,
+} from "./address";
 import BN from "bn.js";
 import { AnchorProvider } from "@coral-xyz/anchor";
 import * as anchor from "@coral-xyz/anchor";
@@ -36,6 +43,20 @@ export class TransactionBuilder {
     this.instructions = instructions ?? [];
   }
 
+  // every AuthorityPolicy of the multisig, which removing a member lowers along with the threshold
+  private async _getAuthorityPolicyAccounts(): Promise<AccountMeta[]> {
+    if (this.multisig.authorityPolicyCount < 1) {
+      return [];
+    }
+    const policyPDAs = [...new Array(this.multisig.authorityIndex)].map((_, i) =>
+      getPolicyPDA(this.multisig.publicKey, new BN(i + 1, 10), this.programId)[0]
+    );
+    const policyAccounts = await this.provider.connection.getMultipleAccountsInfo(policyPDAs);
+    return policyPDAs
+      .filter((_, i) => policyAccounts[i] !== null)
+      .map((pubkey) => ({pubkey, isSigner: false, isWritable: true}));
+  }
+
   private async _buildAddInstruction(
     transactionPDA: PublicKey,
     instruction: TransactionInstruction,
@@ -46,6 +67,16 @@ export class TransactionBuilder {
       new BN(instructionIndex, 10),
       this.programId
     );
+    // the program policy PDA is needed while the multisig has any program policies
+    const policyAccounts: AccountMeta[] = [];
+    if (this.multisig.programPolicyCount > 0 && this.authorityIndex > 0) {
+      const [policyPDA] = getProgramPolicyPDA(
+        this.multisig.publicKey,
+        new BN(this.authorityIndex, 10),
+        this.programId
+      );
+      policyAccounts.push({ pubkey: policyPDA, isSigner: false, isWritable: false });
+    }
     return await this.methods
       .addInstruction(instruction)
       .accounts({
@@ -54,6 +85,7 @@ export class TransactionBuilder {
         instruction: instructionPDA,
         creator: this.provider.wallet.publicKey,
       })
+      .remainingAccounts(policyAccounts)
       .instruction();
   }
   private _cloneWithInstructions(
@@ -120,6 +152,7 @@ export class TransactionBuilder {
       .accounts({
         multisig: this.multisig.publicKey,
       })
+      .remainingAccounts(await this._getAuthorityPolicyAccounts())
       .instruction();
     return this.withInstruction(instruction);
   }
@@ -132,6 +165,7 @@ export class TransactionBuilder {
       .accounts({
         multisig: this.multisig.publicKey,
       })
+      .remainingAccounts(await this._getAuthorityPolicyAccounts())
       .instruction();
     return this.withInstruction(instruction);
   }
@@ -180,6 +214,16 @@ export class TransactionBuilder {
         this._buildAddInstruction(transactionPDA, rawInstruction, index + 1)
       )
     );
+    // the Authority PDA is needed while the multisig has any disabled authorities
+    const authorityInfoAccounts: AccountMeta[] = [];
+    if (this.multisig.disabledAuthorityCount > 0 && this.authorityIndex > 0) {
+      const [authorityInfoPDA] = getAuthorityInfoPDA(
+        this.multisig.publicKey,
+        new BN(this.authorityIndex, 10),
+        this.programId
+      );
+      authorityInfoAccounts.push({ pubkey: authorityInfoPDA, isSigner: false, isWritable: false });
+    }
     const createTxInstruction = await this.methods
       .createTransaction(this.authorityIndex)
       .accounts({
@@ -187,6 +231,7 @@ export class TransactionBuilder {
         transaction: transactionPDA,
         creator: this.provider.wallet.publicKey,
       })
+      .remainingAccounts(authorityInfoAccounts)
       .instruction();
     const instructions = [createTxInstruction, ...wrappedAddInstructions];
     this.instructions = [];
@@ -12,11 +12,16 @@ import {
 } from "../helpers/transactions";
 import { execSync } from "child_process";
 import { LAMPORTS_PER_SOL, ParsedAccountData, SystemProgram } from "@solana/web3.js";
+import { createMint, getAccount, getOrCreateAssociatedTokenAccount, mintTo } from "@solana/spl-token";
 import Squads, {
   getMsPDA,
   getIxPDA,
   getProgramManagerPDA,
   getAuthorityPDA,
+  getAuthorityInfoPDA,
+  getPolicyPDA,
+  getProgramPolicyPDA,
+  getSpendingLimitPDA,
   getTxPDA,
 } from "../sdk/src/index";
 import BN from "bn.js";
@@ -24,6 +29,7 @@ import { getExecuteProxyInstruction, getUserRolePDA, getUserDelegatePDA, getRole
 import { agnosticExecute } from "../helpers/sdkExecute";
 
 import {memberListApprove} from "../helpers/approve";
+import { expectProgramError } from "../helpers/errors";
 
 const BPF_UPGRADE_ID = new anchor.web3.PublicKey(
   "BPFLoaderUpgradeab1e11111111111111111111111"
@@ -112,6 +118,96 @@ describe("Programs", function(){
 
     let threshold = 1;
 
+    const sleep = (ms: number) => new Promise((resolve) => setTimeout(resolve, ms));
+
+    const airdrop = async (publicKey: anchor.web3.PublicKey, lamports = LAMPORTS_PER_SOL) => {
+      const sig = await provider.connection.requestAirdrop(publicKey, lamports);
+      await provider.connection.confirmTransaction(sig);
+    };
+
+    // creates a multisig with the wallet and the given members, prefunded for reallocs and with a funded vault
+    const createTestMultisig = async (members: anchor.web3.PublicKey[], msThreshold = 1) => {
+      const createKey = anchor.web3.Keypair.generate().publicKey;
+      await squads.createMultisig(msThreshold, createKey, [...members]);
+      const [multisigPDA] = getMsPDA(createKey, squads.multisigProgramId);
+      const fundingTx = await createBlankTransaction(squads.connection, creator.publicKey);
+      fundingTx.add(await createTestTransferTransaction(creator.publicKey, multisigPDA, 0.05 * LAMPORTS_PER_SOL));
+      fundingTx.add(await createTestTransferTransaction(creator.publicKey, squads.getAuthorityPDA(multisigPDA, 1), LAMPORTS_PER_SOL));
+      await provider.sendAndConfirm(fundingTx);
+      return multisigPDA;
+    };
+
+    // votes on (or cancels) a transaction on behalf of another member
+    const voteAs = (
+      vote: "approveTransaction" | "rejectTransaction" | "abstainTransaction" | "cancelTransaction",
+      multisigPDA: anchor.web3.PublicKey,
+      txPDA: anchor.web3.PublicKey,
+      member: anchor.web3.Keypair,
+      remainingAccounts: anchor.web3.AccountMeta[] = []
+    ) => program.methods[vote]()
+      .accounts({
+        multisig: multisigPDA,
+        transaction: txPDA,
+        member: member.publicKey,
+      })
+      .remainingAccounts(remainingAccounts)
+      .signers([member])
+      .rpc();
+
+    // creates and approves an internal (authority 0) transaction
+    const createApprovedInternal = async (
+      multisigPDA: anchor.web3.PublicKey,
+      instructions: anchor.web3.TransactionInstruction[],
+      approvers: anchor.web3.Keypair[] = []
+    ) => {
+      const txBuilder = await squads.getTransactionBuilder(multisigPDA, 0);
+      const [, txPDA] = await txBuilder.withInstructions(instructions).executeInstructions();
+      await squads.activateTransaction(txPDA);
+      await squads.approveTransaction(txPDA);
+      for (const approver of approvers) {
+        await voteAs("approveTransaction", multisigPDA, txPDA, approver);
+      }
+      return txPDA;
+    };
+
+    // creates, approves and executes an internal (authority 0) transaction
+    const executeInternal = async (
+      multisigPDA: anchor.web3.PublicKey,
+      instructions: anchor.web3.TransactionInstruction[],
+      approvers: anchor.web3.Keypair[] = []
+    ) => {
+      const txPDA = await createApprovedInternal(multisigPDA, instructions, approvers);
+      const txState = await squads.executeTransaction(txPDA);
+      expect(txState.status).to.have.property("executed");
+      return txState;
+    };
+
+    // creates and approves a vault transfer to each payee
+    const createApprovedTransfer = async (multisigPDA: anchor.web3.PublicKey, payees: anchor.web3.PublicKey[]) => {
+      const vaultPDA = squads.getAuthorityPDA(multisigPDA, 1);
+      const txState = await squads.createTransaction(multisigPDA, 1);
+      for (const payee of payees) {
+        await squads.addInstruction(txState.publicKey, await createTestTransferTransaction(vaultPDA, payee));
+      }
+      await squads.activateTransaction(txState.publicKey);
+      return await squads.approveTransaction(txState.publicKey);
+    };
+
+    // creates and activates a vault transfer back to the wallet
+    const createActiveTransfer = async (multisigPDA: anchor.web3.PublicKey) => {
+      const vaultPDA = squads.getAuthorityPDA(multisigPDA, 1);
+      const txState = await squads.createTransaction(multisigPDA, 1);
+      await squads.addInstruction(txState.publicKey, await createTestTransferTransaction(vaultPDA, creator.publicKey));
+      return await squads.activateTransaction(txState.publicKey);
+    };
+
+    const getIxPDAs = (txPDA: anchor.web3.PublicKey, count: number) => [...new Array(count)].map((_, i) =>
+      getIxPDA(txPDA, new BN(i + 1, 10), squads.multisigProgramId)[0]
+    );
+
+    const toMetas = (keys: anchor.web3.PublicKey[], isWritable: boolean) =>
+      keys.map((pubkey) => ({pubkey, isSigner: false, isWritable}));
+
     // test suite
     describe("SMPL Basic functionality", function(){
       this.beforeAll(async function(){
@@ -750,6 +846,1139 @@ describe("Programs", function(){
         expect((msState.keys as any[]).length).to.equal(startKeys + 1);
         expect(msState.threshold).to.equal(1);
       });
+
+      it(`Internal transaction with a non-config instruction fails`, async function(){
+        // expire_transaction is a squads_mpl instruction, but not one of the internal instructions
+        const targetTxState = await squads.createTransaction(msPDA, 1);
+        const expireIx = await program.methods
+          .expireTransaction()
+          .accounts({
+            multisig: msPDA,
+            transaction: targetTxState.publicKey,
+          })
+          .instruction();
+
+        let txState = await squads.createTransaction(msPDA, 0);
+        await squads.addInstruction(txState.publicKey, expireIx);
+        await squads.activateTransaction(txState.publicKey);
+        await squads.approveTransaction(txState.publicKey);
+        txState = await squads.getTransaction(txState.publicKey);
+        expect(txState.status).to.have.property("executeReady");
+
+        await expectProgramError(
+          squads.executeTransaction(txState.publicKey),
+          "InstructionNotAllowed"
+        );
+        txState = await squads.getTransaction(txState.publicKey);
+        expect(txState.status).to.have.property("executeReady");
+      });
+    });
+
+    describe("Time lock", function(){
+      let timeLockMsPDA;
+
+      this.beforeAll(async function(){
+        timeLockMsPDA = await createTestMultisig([memberList[0].publicKey]);
+      });
+
+      it(`Approved transactions wait for the time lock`, async function(){
+        await executeInternal(timeLockMsPDA, [
+          await program.methods.changeTimeLock(3).accounts({multisig: timeLockMsPDA}).instruction(),
+        ]);
+
+        const txState = await createApprovedTransfer(timeLockMsPDA, [anchor.web3.Keypair.generate().publicKey]);
+        expect(txState.status).to.have.property("executeReady");
+        expect(txState.readyAt.toNumber()).to.be.greaterThan(0);
+        await expectProgramError(squads.executeTransaction(txState.publicKey), "TimeLockNotReleased");
+        await sleep(5000);
+        const executedState = await squads.executeTransaction(txState.publicKey);
+        expect(executedState.status).to.have.property("executed");
+
+        // turning the time lock off is held by the time lock as well
+        const txPDA = await createApprovedInternal(timeLockMsPDA, [
+          await program.methods.changeTimeLock(0).accounts({multisig: timeLockMsPDA}).instruction(),
+        ]);
+        await expectProgramError(squads.executeTransaction(txPDA), "TimeLockNotReleased");
+        await sleep(5000);
+        await squads.executeTransaction(txPDA);
+        const msState = await squads.getMultisig(timeLockMsPDA);
+        expect(msState.timeLock).to.equal(0);
+      });
+    });
+
+    describe("Transaction expiry", function(){
+      let expiryMsPDA;
+
+      this.beforeAll(async function(){
+        expiryMsPDA = await createTestMultisig([memberList[0].publicKey]);
+      });
+
+      it(`Transactions expire after the default ttl`, async function(){
+        await executeInternal(expiryMsPDA, [
+          await program.methods.changeDefaultTtl(10).accounts({multisig: expiryMsPDA}).instruction(),
+        ]);
+        let msState = await squads.getMultisig(expiryMsPDA);
+        expect(msState.defaultTtl).to.equal(10);
+
+        // an explicit expiry can't be later than the default expiry
+        const nextIndex = await squads.getNextTransactionIndex(expiryMsPDA);
+        const [nextTxPDA] = getTxPDA(expiryMsPDA, new BN(nextIndex, 10), squads.multisigProgramId);
+        await expectProgramError(
+          program.methods
+            .createTransactionWithOptions(1, new BN(Math.floor(Date.now() / 1000) + 3600), null)
+            .accounts({
+              multisig: expiryMsPDA,
+              transaction: nextTxPDA,
+              creator: creator.publicKey,
+            })
+            .rpc(),
+          "InvalidExpiry"
+        );
+
+        let txState = await createActiveTransfer(expiryMsPDA);
+        expect(txState.expiresAt.toNumber()).to.be.greaterThan(0);
+        const expireTransaction = () => program.methods
+          .expireTransaction()
+          .accounts({
+            multisig: expiryMsPDA,
+            transaction: txState.publicKey,
+          })
+          .rpc();
+        await expectProgramError(expireTransaction(), "TransactionNotExpired");
+
+        await sleep(11000);
+        await expectProgramError(squads.approveTransaction(txState.publicKey), "TransactionExpired");
+        await expireTransaction();
+        txState = await squads.getTransaction(txState.publicKey);
+        expect(txState.status).to.have.property("expired");
+
+        // without a default ttl, new transactions don't expire
+        await executeInternal(expiryMsPDA, [
+          await program.methods.changeDefaultTtl(0).accounts({multisig: expiryMsPDA}).instruction(),
+        ]);
+        msState = await squads.getMultisig(expiryMsPDA);
+        expect(msState.defaultTtl).to.equal(0);
+        txState = await squads.createTransaction(expiryMsPDA, 1);
+        expect(txState.expiresAt.toNumber()).to.equal(0);
+      });
+    });
+
+    describe("Close transactions", function(){
+      let closeMsPDA;
+
+      const closeTransaction = (txPDA: anchor.web3.PublicKey, accounts: anchor.web3.PublicKey[]) => program.methods
+        .closeTransaction()
+        .accounts({
+          multisig: closeMsPDA,
+          transaction: txPDA,
+          creator: creator.publicKey,
+        })
+        .remainingAccounts(toMetas(accounts, true))
+        .rpc();
+
+      this.beforeAll(async function(){
+        closeMsPDA = await createTestMultisig([memberList[0].publicKey]);
+      });
+
+      it(`Close an executed transaction`, async function(){
+        const txState = await createApprovedTransfer(closeMsPDA, [anchor.web3.Keypair.generate().publicKey]);
+        const ixPDAs = getIxPDAs(txState.publicKey, 1);
+
+        // can't be closed while it can still be executed
+        await expectProgramError(closeTransaction(txState.publicKey, ixPDAs), "InvalidTransactionState");
+
+        await squads.executeTransaction(txState.publicKey);
+        const startLamports = await squads.connection.getBalance(creator.publicKey);
+        await closeTransaction(txState.publicKey, ixPDAs);
+
+        expect(await squads.connection.getAccountInfo(txState.publicKey)).to.equal(null);
+        expect(await squads.connection.getAccountInfo(ixPDAs[0])).to.equal(null);
+        // the rent is returned to the creator
+        expect(await squads.connection.getBalance(creator.publicKey)).to.be.greaterThan(startLamports);
+      });
+
+      it(`Close a transaction deprecated by a config change`, async function(){
+        const vaultPDA = squads.getAuthorityPDA(closeMsPDA, 1);
+        const txState = await squads.createTransaction(closeMsPDA, 1);
+        await squads.addInstruction(txState.publicKey, await createTestTransferTransaction(vaultPDA, creator.publicKey));
+
+        // any config change updates the change index
+        await executeInternal(closeMsPDA, [
+          await program.methods.changeThreshold(1).accounts({multisig: closeMsPDA}).instruction(),
+        ]);
+        await closeTransaction(txState.publicKey, getIxPDAs(txState.publicKey, 1));
+        expect(await squads.connection.getAccountInfo(txState.publicKey)).to.equal(null);
+      });
+    });
+
+    describe("Member weights", function(){
+      const weightedMember = anchor.web3.Keypair.generate();
+      let weightMsPDA;
+
+      this.beforeAll(async function(){
+        weightMsPDA = await createTestMultisig([weightedMember.publicKey], 2);
+      });
+
+      it(`Add a member with a voting weight`, async function(){
+        const newMember = anchor.web3.Keypair.generate().publicKey;
+        await executeInternal(weightMsPDA, [
+          await program.methods.addMemberWithWeight(newMember, 3).accounts({multisig: weightMsPDA}).instruction(),
+        ], [weightedMember]);
+
+        const msState = await squads.getMultisig(weightMsPDA);
+        expect((msState.keys as anchor.web3.PublicKey[]).some((key) => key.equals(newMember))).to.equal(true);
+        const memberWeight = msState.memberWeights.find((w) => w.key.equals(newMember));
+        expect(memberWeight.weight).to.equal(3);
+      });
+
+      it(`Votes are counted by member weight`, async function(){
+        await executeInternal(weightMsPDA, [
+          await program.methods.changeMemberWeight(creator.publicKey, 2).accounts({multisig: weightMsPDA}).instruction(),
+        ], [weightedMember]);
+        const msState = await squads.getMultisig(weightMsPDA);
+        expect(msState.memberWeights.length).to.equal(2);
+
+        // the weight of the creator reaches the threshold of 2 alone
+        const txState = await createApprovedTransfer(weightMsPDA, [anchor.web3.Keypair.generate().publicKey]);
+        expect(txState.status).to.have.property("executeReady");
+      });
+    });
+
+    describe("Authority policies", function(){
+      const policyMember = anchor.web3.Keypair.generate();
+      let policyMsPDA;
+      let policyPDA;
+
+      const policyAccount = (isWritable = false) => [{pubkey: policyPDA, isSigner: false, isWritable}];
+
+      this.beforeAll(async function(){
+        policyMsPDA = await createTestMultisig([policyMember.publicKey]);
+        [policyPDA] = getPolicyPDA(policyMsPDA, new BN(1, 10), squads.multisigProgramId);
+        await executeInternal(policyMsPDA, [
+          await program.methods
+            .addAuthorityPolicy(1, 2)
+            .accounts({
+              multisig: policyMsPDA,
+              policy: policyPDA,
+              payer: creator.publicKey,
+            })
+            .instruction(),
+        ]);
+      });
+
+      it(`An authority policy overrides the threshold of its authority`, async function(){
+        // the vault needs 2 approvals, while the multisig threshold stays at 1 for internal transactions
+        let txState = await createApprovedTransfer(policyMsPDA, [anchor.web3.Keypair.generate().publicKey]);
+        expect(txState.status).to.have.property("active");
+        await voteAs("approveTransaction", policyMsPDA, txState.publicKey, policyMember, policyAccount());
+        txState = await squads.getTransaction(txState.publicKey);
+        expect(txState.status).to.have.property("executeReady");
+      });
+
+      it(`Cancelling a transaction uses the authority policy threshold`, async function(){
+        let txState = await createApprovedTransfer(policyMsPDA, [anchor.web3.Keypair.generate().publicKey]);
+        await voteAs("approveTransaction", policyMsPDA, txState.publicKey, policyMember, policyAccount());
+
+        txState = await squads.cancelTransaction(txState.publicKey);
+        expect(txState.status).to.have.property("executeReady");
+        await voteAs("cancelTransaction", policyMsPDA, txState.publicKey, policyMember, policyAccount());
+        txState = await squads.getTransaction(txState.publicKey);
+        expect(txState.status).to.have.property("cancelled");
+      });
+
+      it(`Removing a member lowers the authority policy threshold`, async function(){
+        const removeMember = (remainingAccounts: anchor.web3.AccountMeta[]) => program.methods
+          .removeMember(policyMember.publicKey)
+          .accounts({multisig: policyMsPDA})
+          .remainingAccounts(remainingAccounts)
+          .instruction();
+
+        // every authority policy of the multisig has to be passed
+        const txPDA = await createApprovedInternal(policyMsPDA, [await removeMember([])]);
+        await expectProgramError(squads.executeTransaction(txPDA), "InvalidAuthorityPolicy");
+
+        await executeInternal(policyMsPDA, [await removeMember(policyAccount(true))]);
+        const policy = await program.account.authorityPolicy.fetch(policyPDA);
+        expect(policy.threshold).to.equal(1);
+      });
+    });
+
+    describe("Spending limits", function(){
+      const outsider = anchor.web3.Keypair.generate();
+      let limitMsPDA;
+      let vaultPDA;
+
+      const getLimitPDA = (mint: anchor.web3.PublicKey, authorityIndex = 1) =>
+        getSpendingLimitPDA(limitMsPDA, new BN(authorityIndex, 10), mint, squads.multisigProgramId)[0];
+      const addSpendingLimit = (mint: anchor.web3.PublicKey, amount: number, authorityIndex = 1) => program.methods
+        .addSpendingLimit(authorityIndex, mint, new BN(amount), new BN(24 * 60 * 60), [creator.publicKey])
+        .accounts({
+          multisig: limitMsPDA,
+          spendingLimit: getLimitPDA(mint, authorityIndex),
+          payer: creator.publicKey,
+        })
+        .instruction();
+
+      this.beforeAll(async function(){
+        limitMsPDA = await createTestMultisig([outsider.publicKey]);
+        vaultPDA = squads.getAuthorityPDA(limitMsPDA, 1);
+      });
+
+      it(`Use a SOL spending limit`, async function(){
+        await executeInternal(limitMsPDA, [
+          await addSpendingLimit(anchor.web3.PublicKey.default, LAMPORTS_PER_SOL / 10),
+        ]);
+        const destination = anchor.web3.Keypair.generate().publicKey;
+
+        await squads.useSpendingLimit(limitMsPDA, 1, destination, new BN(60_000_000));
+        expect(await squads.connection.getBalance(destination)).to.equal(60_000_000);
+        await expectProgramError(
+          squads.useSpendingLimit(limitMsPDA, 1, destination, new BN(60_000_000)),
+          "SpendingLimitExceeded"
+        );
+
+        // multisig members that aren't part of the spending limit can't use it
+        await expectProgramError(
+          program.methods
+            .useSpendingLimit(new BN(10_000_000))
+            .accounts({
+              multisig: limitMsPDA,
+              spendingLimit: getLimitPDA(anchor.web3.PublicKey.default),
+              authority: vaultPDA,
+              destination,
+              member: outsider.publicKey,
+            })
+            .signers([outsider])
+            .rpc(),
+          "KeyNotInSpendingLimit"
+        );
+      });
+
+      it(`Use a token spending limit`, async function(){
+        const payer = (provider.wallet as anchor.Wallet).payer;
+        const mint = await createMint(squads.connection, payer, payer.publicKey, null, 0);
+        const vaultTokenAccount = await getOrCreateAssociatedTokenAccount(squads.connection, payer, mint, vaultPDA, true);
+        const destinationTokenAccount = await getOrCreateAssociatedTokenAccount(
+          squads.connection,
+          payer,
+          mint,
+          anchor.web3.Keypair.generate().publicKey
+        );
+        await mintTo(squads.connection, payer, mint, vaultTokenAccount.address, payer, 1000);
+        await executeInternal(limitMsPDA, [await addSpendingLimit(mint, 100)]);
+
+        const useTokenSpendingLimit = () => squads.useTokenSpendingLimit(
+          limitMsPDA,
+          1,
+          mint,
+          vaultTokenAccount.address,
+          destinationTokenAccount.address,
+          new BN(60)
+        );
+        await useTokenSpendingLimit();
+        const destinationAccount = await getAccount(squads.connection, destinationTokenAccount.address);
+        expect(Number(destinationAccount.amount)).to.equal(60);
+        await expectProgramError(useTokenSpendingLimit(), "SpendingLimitExceeded");
+      });
+
+      it(`Spending limits can only be added to existing authorities`, async function(){
+        // the multisig only has the default vault (authority 1)
+        const txPDA = await createApprovedInternal(limitMsPDA, [
+          await addSpendingLimit(anchor.web3.PublicKey.default, LAMPORTS_PER_SOL / 10, 2),
+        ]);
+        await expectProgramError(squads.executeTransaction(txPDA), "InvalidAuthorityIndex");
+      });
+
+      it(`Spending limits can't be used on a disabled authority`, async function(){
+        const [authorityInfoPDA] = getAuthorityInfoPDA(limitMsPDA, new BN(1, 10), squads.multisigProgramId);
+        await executeInternal(limitMsPDA, [
+          await program.methods
+            .registerAuthority(1, "Vault")
+            .accounts({
+              multisig: limitMsPDA,
+              authority: authorityInfoPDA,
+              payer: creator.publicKey,
+            })
+            .instruction(),
+          await program.methods
+            .updateAuthority("Vault", false)
+            .accounts({
+              multisig: limitMsPDA,
+              authority: authorityInfoPDA,
+              payer: creator.publicKey,
+            })
+            .instruction(),
+        ]);
+        const authorityInfo = await program.account.authority.fetch(authorityInfoPDA);
+        expect(authorityInfo.label).to.equal("Vault");
+        expect(authorityInfo.enabled).to.equal(false);
+        const msState = await squads.getMultisig(limitMsPDA);
+        expect(msState.disabledAuthorityCount).to.equal(1);
+
+        await expectProgramError(
+          squads.useSpendingLimit(limitMsPDA, 1, creator.publicKey, new BN(10_000_000)),
+          "AuthorityDisabled"
+        );
+        await expectProgramError(squads.createTransaction(limitMsPDA, 1), "AuthorityDisabled");
+      });
+    });
+
+    describe("Member permissions", function(){
+      const initiator = anchor.web3.Keypair.generate();
+      const voter = anchor.web3.Keypair.generate();
+      let permissionsMsPDA;
+
+      const createAs = async (member: anchor.web3.Keypair) => {
+        const nextIndex = await squads.getNextTransactionIndex(permissionsMsPDA);
+        const [txPDA] = getTxPDA(permissionsMsPDA, new BN(nextIndex, 10), squads.multisigProgramId);
+        await program.methods
+          .createTransaction(1)
+          .accounts({
+            multisig: permissionsMsPDA,
+            transaction: txPDA,
+            creator: member.publicKey,
+          })
+          .signers([member])
+          .rpc();
+        return txPDA;
+      };
+
+      this.beforeAll(async function(){
+        permissionsMsPDA = await createTestMultisig([initiator.publicKey, voter.publicKey], 2);
+        await airdrop(initiator.publicKey);
+        await airdrop(voter.publicKey);
+      });
+
+      it(`Members can only act within their permissions`, async function(){
+        // the initiator can only initiate, the voter can only vote
+        await executeInternal(permissionsMsPDA, [
+          await program.methods.changeMemberPermissions(initiator.publicKey, 1).accounts({multisig: permissionsMsPDA}).instruction(),
+          await program.methods.changeMemberPermissions(voter.publicKey, 2).accounts({multisig: permissionsMsPDA}).instruction(),
+        ], [initiator]);
+        const msState = await squads.getMultisig(permissionsMsPDA);
+        expect(msState.memberPermissions.length).to.equal(2);
+
+        await expectProgramError(createAs(voter), "MissingPermission");
+        const txPDA = await createAs(initiator);
+        await program.methods
+          .activateTransaction()
+          .accounts({
+            multisig: permissionsMsPDA,
+            transaction: txPDA,
+            creator: initiator.publicKey,
+          })
+          .signers([initiator])
+          .rpc();
+
+        await expectProgramError(voteAs("approveTransaction", permissionsMsPDA, txPDA, initiator), "MissingPermission");
+        await voteAs("approveTransaction", permissionsMsPDA, txPDA, voter);
+        let txState = await squads.getTransaction(txPDA);
+        expect(txState.status).to.have.property("active");
+        txState = await squads.approveTransaction(txPDA);
+        expect(txState.status).to.have.property("executeReady");
+
+        await expectProgramError(
+          program.methods
+            .executeTransaction(Buffer.from([]))
+            .accounts({
+              multisig: permissionsMsPDA,
+              transaction: txPDA,
+              member: voter.publicKey,
+            })
+            .signers([voter])
+            .rpc(),
+          "MissingPermission"
+        );
+        txState = await squads.executeTransaction(txPDA);
+        expect(txState.status).to.have.property("executed");
+      });
+    });
+
+    describe("Member key rotation", function(){
+      const oldKey = anchor.web3.Keypair.generate();
+      const newKey = anchor.web3.Keypair.generate();
+      let rotationMsPDA;
+      let limitPDA;
+
+      this.beforeAll(async function(){
+        rotationMsPDA = await createTestMultisig([oldKey.publicKey]);
+        [limitPDA] = getSpendingLimitPDA(rotationMsPDA, new BN(1, 10), anchor.web3.PublicKey.default, squads.multisigProgramId);
+        await executeInternal(rotationMsPDA, [
+          await program.methods
+            .addSpendingLimit(1, anchor.web3.PublicKey.default, new BN(LAMPORTS_PER_SOL / 10), new BN(0), [oldKey.publicKey])
+            .accounts({
+              multisig: rotationMsPDA,
+              spendingLimit: limitPDA,
+              payer: creator.publicKey,
+            })
+            .instruction(),
+        ]);
+      });
+
+      it(`Rotating a member key moves it in the spending limits`, async function(){
+        await program.methods
+          .rotateMemberKey()
+          .accounts({
+            multisig: rotationMsPDA,
+            oldMember: oldKey.publicKey,
+            newMember: newKey.publicKey,
+          })
+          .remainingAccounts([{pubkey: limitPDA, isSigner: false, isWritable: true}])
+          .signers([oldKey, newKey])
+          .rpc();
+
+        const msState = await squads.getMultisig(rotationMsPDA);
+        expect((msState.keys as anchor.web3.PublicKey[]).some((key) => key.equals(newKey.publicKey))).to.equal(true);
+        expect((msState.keys as anchor.web3.PublicKey[]).some((key) => key.equals(oldKey.publicKey))).to.equal(false);
+        const spendingLimit = await program.account.spendingLimit.fetch(limitPDA);
+        expect((spendingLimit.members as anchor.web3.PublicKey[]).map((key) => key.toBase58()))
+          .to.deep.equal([newKey.publicKey.toBase58()]);
+
+        // the new key can use the spending limit right away
+        const destination = anchor.web3.Keypair.generate().publicKey;
+        await program.methods
+          .useSpendingLimit(new BN(10_000_000))
+          .accounts({
+            multisig: rotationMsPDA,
+            spendingLimit: limitPDA,
+            authority: squads.getAuthorityPDA(rotationMsPDA, 1),
+            destination,
+            member: newKey.publicKey,
+          })
+          .signers([newKey])
+          .rpc();
+        expect(await squads.connection.getBalance(destination)).to.equal(10_000_000);
+      });
+    });
+
+    describe("Recovery", function(){
+      // the shortest delay allowed, recoveries can't be executed on a local validator before it passes
+      const RECOVERY_DELAY = 7 * 24 * 60 * 60;
+      const guardians = [anchor.web3.Keypair.generate(), anchor.web3.Keypair.generate()];
+      const newMember = anchor.web3.Keypair.generate().publicKey;
+      let recoveryMsPDA;
+      let proposalPDA;
+
+      const setRecoveryConfig = async () => executeInternal(recoveryMsPDA, [
+        await program.methods
+          .setRecoveryConfig(guardians.map((guardian) => guardian.publicKey), 2, RECOVERY_DELAY)
+          .accounts({multisig: recoveryMsPDA})
+          .instruction(),
+      ]);
+      const proposeRecovery = (guardian: anchor.web3.Keypair) => program.methods
+        .proposeRecovery([newMember], 1)
+        .accounts({
+          multisig: recoveryMsPDA,
+          proposal: proposalPDA,
+          guardian: guardian.publicKey,
+        })
+        .signers([guardian])
+        .rpc();
+      const approveRecovery = (guardian: anchor.web3.Keypair) => program.methods
+        .approveRecovery()
+        .accounts({
+          multisig: recoveryMsPDA,
+          proposal: proposalPDA,
+          guardian: guardian.publicKey,
+        })
+        .signers([guardian])
+        .rpc();
+      const executeRecovery = () => program.methods
+        .executeRecovery()
+        .accounts({
+          multisig: recoveryMsPDA,
+          proposal: proposalPDA,
+          proposer: guardians[0].publicKey,
+          payer: creator.publicKey,
+        })
+        .rpc();
+      const cancelRecovery = () => program.methods
+        .cancelRecovery()
+        .accounts({
+          multisig: recoveryMsPDA,
+          proposal: proposalPDA,
+          proposer: guardians[0].publicKey,
+          member: creator.publicKey,
+        })
+        .rpc();
+
+      this.beforeAll(async function(){
+        recoveryMsPDA = await createTestMultisig([memberList[1].publicKey]);
+        [proposalPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+          [
+            anchor.utils.bytes.utf8.encode("squad"),
+            recoveryMsPDA.toBuffer(),
+            anchor.utils.bytes.utf8.encode("recovery"),
+          ],
+          squads.multisigProgramId
+        );
+        await airdrop(guardians[0].publicKey);
+        await setRecoveryConfig();
+      });
+
+      it(`Guardians propose and approve a recovery`, async function(){
+        const outsider = anchor.web3.Keypair.generate();
+        await airdrop(outsider.publicKey);
+        await expectProgramError(proposeRecovery(outsider), "KeyNotGuardian");
+
+        await proposeRecovery(guardians[0]);
+        let proposal = await program.account.recoveryProposal.fetch(proposalPDA);
+        expect(proposal.approvals.length).to.equal(1);
+        expect(proposal.readyAt.toNumber()).to.equal(0);
+
+        // the delay starts once the guardian threshold is reached
+        await approveRecovery(guardians[1]);
+        proposal = await program.account.recoveryProposal.fetch(proposalPDA);
+        expect(proposal.approvals.length).to.equal(2);
+        expect(proposal.readyAt.toNumber()).to.be.greaterThan(0);
+      });
+
+      it(`A recovery can't be executed before the delay`, async function(){
+        await expectProgramError(executeRecovery(), "RecoveryNotReady");
+        const msState = await squads.getMultisig(recoveryMsPDA);
+        expect((msState.keys as anchor.web3.PublicKey[]).some((key) => key.equals(newMember))).to.equal(false);
+      });
+
+      it(`A member cancels a recovery`, async function(){
+        await cancelRecovery();
+        expect(await squads.connection.getAccountInfo(proposalPDA)).to.equal(null);
+      });
+
+      it(`A proposal made under a replaced recovery config is stale`, async function(){
+        await proposeRecovery(guardians[0]);
+        await setRecoveryConfig();
+
+        await expectProgramError(approveRecovery(guardians[1]), "RecoveryConfigChanged");
+        await expectProgramError(executeRecovery(), "RecoveryConfigChanged");
+        await cancelRecovery();
+      });
+    });
+
+    describe("Abstain votes", function(){
+      const memberA = anchor.web3.Keypair.generate();
+      const memberB = anchor.web3.Keypair.generate();
+      let abstainMsPDA;
+
+      this.beforeAll(async function(){
+        abstainMsPDA = await createTestMultisig([memberA.publicKey, memberB.publicKey], 2);
+      });
+
+      it(`Abstaining counts towards rejection once the threshold can't be reached`, async function(){
+        let txState = await createActiveTransfer(abstainMsPDA);
+        await voteAs("abstainTransaction", abstainMsPDA, txState.publicKey, memberA);
+        txState = await squads.getTransaction(txState.publicKey);
+        expect(txState.status).to.have.property("active");
+        expect(txState.abstained.length).to.equal(1);
+
+        // changing the vote is kept as an audit trail
+        await voteAs("approveTransaction", abstainMsPDA, txState.publicKey, memberA);
+        txState = await squads.getTransaction(txState.publicKey);
+        expect(txState.abstained.length).to.equal(0);
+        expect(txState.approved.length).to.equal(1);
+        expect(txState.voteChanges.length).to.equal(1);
+        expect(txState.voteChanges[0].member.equals(memberA.publicKey)).to.equal(true);
+        expect(txState.voteChanges[0].oldVote).to.have.property("abstain");
+        expect(txState.voteChanges[0].newVote).to.have.property("approve");
+
+        // with 2 of the 3 members abstaining, the threshold of 2 can't be reached
+        await voteAs("abstainTransaction", abstainMsPDA, txState.publicKey, memberB);
+        txState = await squads.getTransaction(txState.publicKey);
+        expect(txState.status).to.have.property("active");
+        await program.methods
+          .abstainTransaction()
+          .accounts({
+            multisig: abstainMsPDA,
+            transaction: txState.publicKey,
+            member: creator.publicKey,
+          })
+          .rpc();
+        txState = await squads.getTransaction(txState.publicKey);
+        expect(txState.status).to.have.property("rejected");
+      });
+    });
+
+    describe("Reject threshold", function(){
+      const memberA = anchor.web3.Keypair.generate();
+      const memberB = anchor.web3.Keypair.generate();
+      let rejectMsPDA;
+
+      this.beforeAll(async function(){
+        rejectMsPDA = await createTestMultisig([memberA.publicKey, memberB.publicKey], 2);
+      });
+
+      it(`Reaching the reject threshold rejects a transaction`, async function(){
+        // without a reject threshold, a transaction is only rejected once the threshold can't be reached
+        let txState = await createActiveTransfer(rejectMsPDA);
+        await voteAs("rejectTransaction", rejectMsPDA, txState.publicKey, memberA);
+        txState = await squads.getTransaction(txState.publicKey);
+        expect(txState.status).to.have.property("active");
+
+        await executeInternal(rejectMsPDA, [
+          await program.methods.changeRejectThreshold(1).accounts({multisig: rejectMsPDA}).instruction(),
+        ], [memberA]);
+        const msState = await squads.getMultisig(rejectMsPDA);
+        expect(msState.rejectThreshold).to.equal(1);
+
+        txState = await createActiveTransfer(rejectMsPDA);
+        await voteAs("rejectTransaction", rejectMsPDA, txState.publicKey, memberA);
+        txState = await squads.getTransaction(txState.publicKey);
+        expect(txState.status).to.have.property("rejected");
+      });
+    });
+
+    describe("Withdraw transactions", function(){
+      let withdrawMsPDA;
+
+      const withdrawTransaction = (txPDA: anchor.web3.PublicKey, close: boolean, accounts: anchor.web3.PublicKey[] = []) => program.methods
+        .withdrawTransaction(close)
+        .accounts({
+          multisig: withdrawMsPDA,
+          transaction: txPDA,
+          creator: creator.publicKey,
+        })
+        .remainingAccounts(toMetas(accounts, true))
+        .rpc();
+
+      this.beforeAll(async function(){
+        withdrawMsPDA = await createTestMultisig([memberList[0].publicKey], 2);
+      });
+
+      it(`Withdraw a transaction`, async function(){
+        let txState = await createActiveTransfer(withdrawMsPDA);
+        await withdrawTransaction(txState.publicKey, false);
+        txState = await squads.getTransaction(txState.publicKey);
+        expect(txState.status).to.have.property("cancelled");
+      });
+
+      it(`Withdraw and close a transaction`, async function(){
+        const vaultPDA = squads.getAuthorityPDA(withdrawMsPDA, 1);
+        const txState = await squads.createTransaction(withdrawMsPDA, 1);
+        await squads.addInstruction(txState.publicKey, await createTestTransferTransaction(vaultPDA, creator.publicKey));
+
+        const ixPDAs = getIxPDAs(txState.publicKey, 1);
+        await withdrawTransaction(txState.publicKey, true, ixPDAs);
+        expect(await squads.connection.getAccountInfo(txState.publicKey)).to.equal(null);
+        expect(await squads.connection.getAccountInfo(ixPDAs[0])).to.equal(null);
+      });
+
+      it(`A transaction with approvals can't be withdrawn`, async function(){
+        // the threshold is 2, so the transaction stays active after the first approval
+        const txState = await createApprovedTransfer(withdrawMsPDA, [anchor.web3.Keypair.generate().publicKey]);
+        expect(txState.status).to.have.property("active");
+        await expectProgramError(withdrawTransaction(txState.publicKey, false), "TransactionAlreadyApproved");
+      });
+    });
+
+    describe("Edit draft transactions", function(){
+      let editMsPDA;
+
+      const getIxState = (ixPDA: anchor.web3.PublicKey) => program.account.msInstruction.fetch(ixPDA);
+
+      this.beforeAll(async function(){
+        editMsPDA = await createTestMultisig([memberList[0].publicKey]);
+      });
+
+      it(`Replace an instruction`, async function(){
+        const vaultPDA = squads.getAuthorityPDA(editMsPDA, 1);
+        const payee = anchor.web3.Keypair.generate().publicKey;
+        const txState = await squads.createTransaction(editMsPDA, 1);
+        await squads.addInstruction(txState.publicKey, await createTestTransferTransaction(vaultPDA, creator.publicKey));
+
+        const [ixPDA] = getIxPDAs(txState.publicKey, 1);
+        await program.methods
+          .replaceInstruction(await createTestTransferTransaction(vaultPDA, payee))
+          .accounts({
+            multisig: editMsPDA,
+            transaction: txState.publicKey,
+            instruction: ixPDA,
+            creator: creator.publicKey,
+          })
+          .rpc();
+        const ixState = await getIxState(ixPDA);
+        expect(ixState.instructionIndex).to.equal(1);
+        expect(ixState.keys[1].pubkey.equals(payee)).to.equal(true);
+      });
+
+      it(`Remove a middle instruction`, async function(){
+        const vaultPDA = squads.getAuthorityPDA(editMsPDA, 1);
+        const payees = [...new Array(3)].map(() => anchor.web3.Keypair.generate().publicKey);
+        let txState = await squads.createTransaction(editMsPDA, 1);
+        for (const payee of payees) {
+          await squads.addInstruction(txState.publicKey, await createTestTransferTransaction(vaultPDA, payee));
+        }
+
+        // the following instructions are moved down one index
+        const ixPDAs = getIxPDAs(txState.publicKey, 3);
+        await program.methods
+          .removeInstruction(2)
+          .accounts({
+            multisig: editMsPDA,
+            transaction: txState.publicKey,
+            instruction: ixPDAs[1],
+            creator: creator.publicKey,
+          })
+          .remainingAccounts(toMetas([ixPDAs[2]], true))
+          .rpc();
+
+        txState = await squads.getTransaction(txState.publicKey);
+        expect(txState.instructionIndex).to.equal(2);
+        const movedIx = await getIxState(ixPDAs[1]);
+        expect(movedIx.instructionIndex).to.equal(2);
+        expect(movedIx.keys[1].pubkey.equals(payees[2])).to.equal(true);
+        expect(await squads.connection.getAccountInfo(ixPDAs[2])).to.equal(null);
+
+        await squads.activateTransaction(txState.publicKey);
+        await squads.approveTransaction(txState.publicKey);
+        txState = await squads.executeTransaction(txState.publicKey);
+        expect(txState.status).to.have.property("executed");
+        expect(await squads.connection.getBalance(payees[0])).to.equal(1000000);
+        expect(await squads.connection.getBalance(payees[1])).to.equal(0);
+        expect(await squads.connection.getBalance(payees[2])).to.equal(1000000);
+      });
+
+      it(`Remove the last instruction`, async function(){
+        const vaultPDA = squads.getAuthorityPDA(editMsPDA, 1);
+        let txState = await squads.createTransaction(editMsPDA, 1);
+        await squads.addInstruction(txState.publicKey, await createTestTransferTransaction(vaultPDA, creator.publicKey));
+        await squads.addInstruction(txState.publicKey, await createTestTransferTransaction(vaultPDA, creator.publicKey));
+
+        const ixPDAs = getIxPDAs(txState.publicKey, 2);
+        await program.methods
+          .removeInstruction(2)
+          .accounts({
+            multisig: editMsPDA,
+            transaction: txState.publicKey,
+            instruction: ixPDAs[1],
+            creator: creator.publicKey,
+          })
+          .rpc();
+        txState = await squads.getTransaction(txState.publicKey);
+        expect(txState.instructionIndex).to.equal(1);
+        expect(await squads.connection.getAccountInfo(ixPDAs[1])).to.equal(null);
+      });
+    });
+
+    describe("Chunked execution", function(){
+      let chunkMsPDA;
+
+      const getExecutionAccounts = (
+        txPDA: anchor.web3.PublicKey,
+        ixCount: number,
+        endIndex: number | null,
+        offset = 0,
+        limit: number | null = null
+      ) => program.methods
+        .getExecutionAccounts(endIndex, offset, limit)
+        .accounts({
+          multisig: chunkMsPDA,
+          transaction: txPDA,
+        })
+        .remainingAccounts(toMetas(getIxPDAs(txPDA, ixCount), false))
+        .view();
+
+      this.beforeAll(async function(){
+        chunkMsPDA = await createTestMultisig([memberList[0].publicKey]);
+      });
+
+      it(`Execute a transaction in chunks`, async function(){
+        const payees = [anchor.web3.Keypair.generate().publicKey, anchor.web3.Keypair.generate().publicKey];
+        let txState = await createApprovedTransfer(chunkMsPDA, payees);
+        const txPDA = txState.publicKey;
+
+        const executeChunk = async (endIndex: number) => {
+          const executionAccounts = await getExecutionAccounts(txPDA, 2, endIndex);
+          await program.methods
+            .executeTransactionChunk(Buffer.from(executionAccounts.accountList), endIndex)
+            .accounts({
+              multisig: chunkMsPDA,
+              transaction: txPDA,
+              member: creator.publicKey,
+            })
+            .remainingAccounts(executionAccounts.accounts)
+            .rpc();
+        };
+
+        await executeChunk(1);
+        txState = await squads.getTransaction(txPDA);
+        expect(txState.executedIndex).to.equal(1);
+        expect(txState.status).to.have.property("executeReady");
+        expect(await squads.connection.getBalance(payees[0])).to.equal(1000000);
+        expect(await squads.connection.getBalance(payees[1])).to.equal(0);
+
+        await executeChunk(2);
+        txState = await squads.getTransaction(txPDA);
+        expect(txState.executedIndex).to.equal(2);
+        expect(txState.status).to.have.property("executed");
+        expect(await squads.connection.getBalance(payees[1])).to.equal(1000000);
+      });
+
+      it(`Get the execution accounts in pages`, async function(){
+        const payees = [anchor.web3.Keypair.generate().publicKey, anchor.web3.Keypair.generate().publicKey];
+        const txState = await createApprovedTransfer(chunkMsPDA, payees);
+
+        const allAccounts = await getExecutionAccounts(txState.publicKey, 2, null);
+        const firstPage = await getExecutionAccounts(txState.publicKey, 2, null, 0, 2);
+        const secondPage = await getExecutionAccounts(txState.publicKey, 2, null, 2);
+        expect(firstPage.accounts.length).to.equal(2);
+        expect(firstPage.totalAccounts).to.equal(allAccounts.accounts.length);
+        expect(Buffer.from(firstPage.accountList).equals(Buffer.from(allAccounts.accountList))).to.equal(true);
+        expect(
+          firstPage.accounts.concat(secondPage.accounts).map((meta) => meta.pubkey.toBase58())
+        ).to.deep.equal(allAccounts.accounts.map((meta) => meta.pubkey.toBase58()));
+      });
+    });
+
+    describe("Instruction buffers", function(){
+      let bufferMsPDA;
+
+      const getBufferPDA = (txPDA: anchor.web3.PublicKey) => anchor.web3.PublicKey.findProgramAddressSync(
+        [
+          anchor.utils.bytes.utf8.encode("squad"),
+          txPDA.toBuffer(),
+          anchor.utils.bytes.utf8.encode("instruction_buffer"),
+        ],
+        squads.multisigProgramId
+      )[0];
+      const createInstructionBuffer = (txPDA: anchor.web3.PublicKey, ix: anchor.web3.TransactionInstruction) => program.methods
+        .createInstructionBuffer(ix.programId, ix.keys, ix.data.length)
+        .accounts({
+          multisig: bufferMsPDA,
+          transaction: txPDA,
+          instructionBuffer: getBufferPDA(txPDA),
+          creator: creator.publicKey,
+        })
+        .rpc();
+
+      this.beforeAll(async function(){
+        bufferMsPDA = await createTestMultisig([memberList[0].publicKey]);
+      });
+
+      it(`Withdraw and close a transaction with an instruction buffer`, async function(){
+        const vaultPDA = squads.getAuthorityPDA(bufferMsPDA, 1);
+        const txState = await squads.createTransaction(bufferMsPDA, 1);
+        const transferIx = await createTestTransferTransaction(vaultPDA, creator.publicKey);
+        await squads.addInstruction(txState.publicKey, transferIx);
+        await createInstructionBuffer(txState.publicKey, transferIx);
+
+        const ixPDAs = getIxPDAs(txState.publicKey, 1);
+        const bufferPDA = getBufferPDA(txState.publicKey);
+        await program.methods
+          .withdrawTransaction(true)
+          .accounts({
+            multisig: bufferMsPDA,
+            transaction: txState.publicKey,
+            creator: creator.publicKey,
+          })
+          .remainingAccounts(toMetas([...ixPDAs, bufferPDA], true))
+          .rpc();
+
+        expect(await squads.connection.getAccountInfo(txState.publicKey)).to.equal(null);
+        expect(await squads.connection.getAccountInfo(ixPDAs[0])).to.equal(null);
+        expect(await squads.connection.getAccountInfo(bufferPDA)).to.equal(null);
+      });
+
+      it(`Only the creator can close an instruction buffer`, async function(){
+        const vaultPDA = squads.getAuthorityPDA(bufferMsPDA, 1);
+        const txState = await squads.createTransaction(bufferMsPDA, 1);
+        await createInstructionBuffer(txState.publicKey, await createTestTransferTransaction(vaultPDA, creator.publicKey));
+        const bufferPDA = getBufferPDA(txState.publicKey);
+        const closeBuffer = (closer: anchor.web3.PublicKey) => program.methods
+          .closeInstructionBuffer()
+          .accounts({
+            multisig: bufferMsPDA,
+            transaction: txState.publicKey,
+            instructionBuffer: bufferPDA,
+            creator: closer,
+          });
+
+        await expectProgramError(
+          closeBuffer(memberList[0].publicKey).signers([memberList[0]]).rpc(),
+          "ConstraintRaw"
+        );
+        await closeBuffer(creator.publicKey).rpc();
+        expect(await squads.connection.getAccountInfo(bufferPDA)).to.equal(null);
+      });
+    });
+
+    describe("Program policies", function(){
+      let programPolicyMsPDA;
+      let programPolicyPDA;
+
+      this.beforeAll(async function(){
+        programPolicyMsPDA = await createTestMultisig([memberList[0].publicKey]);
+        [programPolicyPDA] = getProgramPolicyPDA(programPolicyMsPDA, new BN(1, 10), squads.multisigProgramId);
+      });
+
+      it(`A program policy restricts the programs an authority invokes`, async function(){
+        const vaultPDA = squads.getAuthorityPDA(programPolicyMsPDA, 1);
+        await executeInternal(programPolicyMsPDA, [
+          await program.methods
+            .addProgramPolicy(1, {deny: {}}, [SystemProgram.programId])
+            .accounts({
+              multisig: programPolicyMsPDA,
+              policy: programPolicyPDA,
+              payer: creator.publicKey,
+            })
+            .instruction(),
+        ]);
+        const deniedTxState = await squads.createTransaction(programPolicyMsPDA, 1);
+        await expectProgramError(
+          squads.addInstruction(deniedTxState.publicKey, await createTestTransferTransaction(vaultPDA, creator.publicKey)),
+          "ProgramNotAllowed"
+        );
+
+        await executeInternal(programPolicyMsPDA, [
+          await program.methods
+            .changeProgramPolicy({allow: {}}, [SystemProgram.programId])
+            .accounts({
+              multisig: programPolicyMsPDA,
+              policy: programPolicyPDA,
+              payer: creator.publicKey,
+            })
+            .instruction(),
+        ]);
+        // the program policy PDA is passed along with the execution accounts
+        let txState = await createApprovedTransfer(programPolicyMsPDA, [anchor.web3.Keypair.generate().publicKey]);
+        txState = await squads.executeTransaction(txState.publicKey);
+        expect(txState.status).to.have.property("executed");
+      });
+    });
+
+    // legacy accounts are loaded by the test validator from tests/fixtures (see Anchor.toml.example)
+    describe("Multisig migration", function(){
+      const readFixture = (name: string) => JSON.parse(fs.readFileSync(`tests/fixtures/${name}.json`, "utf8"));
+      const fixtureKey = (name: string) => new anchor.web3.PublicKey(readFixture(name).pubkey);
+      const legacyMember = anchor.web3.Keypair.fromSecretKey(
+        Uint8Array.from(readFixture("legacy-member"))
+      );
+      const legacyMsPDA = fixtureKey("legacy-ms");
+      const timeLockMsPDA = fixtureKey("timelock-ms");
+
+      const migrate = (multisig: anchor.web3.PublicKey) => program.methods
+        .migrateMultisig()
+        .accounts({
+          multisig,
+          member: legacyMember.publicKey,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([legacyMember])
+        .rpc();
+
+      const buildExecute = async (txPDA: anchor.web3.PublicKey) => {
+        const executeIx = await squads.buildExecuteTransaction(txPDA, legacyMember.publicKey);
+        const executeTx = await createBlankTransaction(squads.connection, creator.publicKey);
+        executeTx.add(executeIx);
+        return executeTx;
+      };
+
+      this.beforeAll(async function(){
+        const msAccount = await squads.connection.getAccountInfo(legacyMsPDA);
+        if (msAccount === null || !msAccount.owner.equals(program.programId)) {
+          console.log("            legacy fixtures not loaded, skipping");
+          this.skip();
+        }
+        const sig = await squads.connection.requestAirdrop(legacyMember.publicKey, LAMPORTS_PER_SOL);
+        await squads.connection.confirmTransaction(sig);
+      });
+
+      it(`Migrate a legacy multisig with stale keys`, async function(){
+        const startDataLen = (await squads.connection.getAccountInfo(legacyMsPDA)).data.length;
+        await migrate(legacyMsPDA);
+
+        const msAccount = await squads.connection.getAccountInfo(legacyMsPDA);
+        const msState = await program.account.ms.fetch(legacyMsPDA);
+        // the account had room for the removed keys, so it isn't reallocated
+        expect(msAccount.data.length).to.equal(startDataLen);
+        expect(msState.migrated).to.equal(true);
+        expect((msState.keys as any[]).length).to.equal(3);
+        // the stale keys are not read as settings
+        expect(msState.timeLock).to.equal(0);
+        expect(msState.defaultTtl).to.equal(0);
+        expect(msState.memberWeights.length).to.equal(0);
+        expect(msState.memberPermissions.length).to.equal(0);
+        expect(msState.recovery).to.equal(null);
+
+        // migrating again doesn't change the account
+        await migrate(legacyMsPDA);
+        const msAccountAfter = await squads.connection.getAccountInfo(legacyMsPDA);
+        expect(msAccountAfter.data.equals(msAccount.data)).to.equal(true);
+      });
+
+      it(`Execute a legacy ExecuteReady transaction`, async function(){
+        const vaultPDA = squads.getAuthorityPDA(legacyMsPDA, 1);
+        const fundingTx = await createBlankTransaction(squads.connection, creator.publicKey);
+        fundingTx.add(await createTestTransferTransaction(creator.publicKey, vaultPDA, 0.01 * LAMPORTS_PER_SOL));
+        await provider.sendAndConfirm(fundingTx);
+
+        const txPDA = fixtureKey("legacy-tx");
+        let txState = await squads.getTransaction(txPDA);
+        expect(txState.status).to.have.property("executeReady");
+        expect(txState.readyAt.toNumber()).to.equal(0);
+
+        await provider.sendAndConfirm(await buildExecute(txPDA), [legacyMember]);
+        txState = await squads.getTransaction(txPDA);
+        expect(txState.status).to.have.property("executed");
+        const vaultAccount = await squads.connection.getAccountInfo(vaultPDA);
+        expect(vaultAccount.lamports).to.equal(0.01 * LAMPORTS_PER_SOL - 1000);
+      });
+
+      it(`Legacy ExecuteReady transaction without ready_at is held by the time lock`, async function(){
+        // already uses the current layout, so migrating keeps the time lock
+        await migrate(timeLockMsPDA);
+        const msState = await program.account.ms.fetch(timeLockMsPDA);
+        expect(msState.timeLock).to.equal(3600);
+
+        const txPDA = fixtureKey("timelock-tx");
+        await expectProgramError(
+          provider.sendAndConfirm(await buildExecute(txPDA), [legacyMember]),
+          "TimeLockNotReleased"
+        );
+        const txState = await squads.getTransaction(txPDA);
+        expect(txState.status).to.have.property("executeReady");
+      });
+
+      it(`Migrating again keeps the settings of a migrated multisig`, async function(){
+        // the executed legacy transaction was the first, a time lock is set with the second
+        const [txPDA] = getTxPDA(legacyMsPDA, new BN(2, 10), squads.multisigProgramId);
+        const [ixPDA] = getIxPDA(txPDA, new BN(1, 10), squads.multisigProgramId);
+        await program.methods
+          .createTransaction(0)
+          .accounts({
+            multisig: legacyMsPDA,
+            transaction: txPDA,
+            creator: legacyMember.publicKey,
+          })
+          .signers([legacyMember])
+          .rpc();
+        await program.methods
+          .addInstruction(await program.methods.changeTimeLock(60).accounts({multisig: legacyMsPDA}).instruction())
+          .accounts({
+            multisig: legacyMsPDA,
+            transaction: txPDA,
+            instruction: ixPDA,
+            creator: legacyMember.publicKey,
+          })
+          .signers([legacyMember])
+          .rpc();
+        await program.methods
+          .activateTransaction()
+          .accounts({
+            multisig: legacyMsPDA,
+            transaction: txPDA,
+            creator: legacyMember.publicKey,
+          })
+          .signers([legacyMember])
+          .rpc();
+        await voteAs("approveTransaction", legacyMsPDA, txPDA, legacyMember);
+        await provider.sendAndConfirm(await buildExecute(txPDA), [legacyMember]);
+        let msState = await program.account.ms.fetch(legacyMsPDA);
+        expect(msState.timeLock).to.equal(60);
+
+        await migrate(legacyMsPDA);
+        msState = await program.account.ms.fetch(legacyMsPDA);
+        expect(msState.timeLock).to.equal(60);
+      });
     });
 
     describe.skip("Program upgrades", function (){